@@ -0,0 +1,1514 @@
+// Copyright 2013 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::UnknownUnit;
+use crate::approxeq::ApproxEq;
+use crate::box2d::Box2D;
+use crate::box3d::Box3D;
+use crate::num::*;
+use crate::point::{Point2D, Point3D};
+use crate::segment::{LineSegment2D, LineSegment3D};
+use crate::transform2d::Transform2D;
+use crate::transform3d::Transform3D;
+use crate::vector::{Vector2D, Vector3D};
+
+use num_traits::real::Real;
+use num_traits::NumCast;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "bytemuck")]
+use bytemuck::{Zeroable, Pod};
+
+use core::cmp::PartialOrd;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::{Add, Div, Mul};
+
+/// Returns the parameter in `]0, 1[` at which a one dimensional quadratic Bézier
+/// curve has its extremum, if any.
+fn quadratic_extremum<T: Real>(p0: T, p1: T, p2: T) -> Option<T> {
+    // Root of the derivative, a division by zero means there is no extremum and
+    // yields a value that is filtered out.
+    let t = (p0 - p1) / (p0 - p1 - p1 + p2);
+    if t > T::zero() && t < T::one() {
+        Some(t)
+    } else {
+        None
+    }
+}
+
+/// Returns the parameters in `]0, 1[` at which a one dimensional cubic Bézier curve
+/// has local extrema.
+fn cubic_extrema<T: Real>(p0: T, p1: T, p2: T, p3: T) -> [Option<T>; 2] {
+    let zero = T::zero();
+    let one = T::one();
+    let two = one + one;
+    let three = two + one;
+
+    // The derivative divided by three is a * t^2 + b * t + c.
+    let a = p3 - p0 + (p1 - p2) * three;
+    let b = (p0 - p1 - p1 + p2) * two;
+    let c = p1 - p0;
+
+    let discriminant = b * b - a * c * two * two;
+    if discriminant < zero {
+        return [None, None];
+    }
+
+    // Numerically stable form, which also finds the root of the linear case
+    // (a == 0) through c / q.
+    let sqrt = discriminant.sqrt();
+    let q = if b < zero { (sqrt - b) / two } else { -(b + sqrt) / two };
+    let in_range = |t: T| if t > zero && t < one { Some(t) } else { None };
+
+    [in_range(q / a), in_range(c / q)]
+}
+
+/// Returns the number of uniform steps needed for the segments between the points
+/// to stay within `tolerance` of a curve, given an upper bound of the norm of its
+/// second derivative.
+///
+/// Linear interpolation with a step `h` is off by at most `max * h^2 / 8`.
+fn flattening_step_count<T: Real>(max_second_derivative: T, tolerance: T) -> u32 {
+    debug_assert!(tolerance > T::zero());
+    let eight = T::one() + T::one() + T::one() + T::one();
+    let eight = eight + eight;
+    let count = (max_second_derivative / (eight * tolerance)).sqrt().ceil();
+
+    NumCast::from(count).unwrap_or(1).max(1)
+}
+
+fn approx_eq_2d<T: ApproxEq<T>, U>(a: &Point2D<T, U>, b: &Point2D<T, U>, eps: &T) -> bool {
+    a.x.approx_eq_eps(&b.x, eps) && a.y.approx_eq_eps(&b.y, eps)
+}
+
+fn approx_eq_3d<T: ApproxEq<T>, U>(a: &Point3D<T, U>, b: &Point3D<T, U>, eps: &T) -> bool {
+    a.x.approx_eq_eps(&b.x, eps) && a.y.approx_eq_eps(&b.y, eps) && a.z.approx_eq_eps(&b.z, eps)
+}
+
+/// A 2d quadratic Bézier curve segment, with a single control point.
+///
+/// The segment is parametrized with `t` going from `0` at `from` to `1` at `to`.
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Serialize", deserialize = "T: Deserialize<'de>"))
+)]
+pub struct QuadraticBezierSegment<T, U> {
+    pub from: Point2D<T, U>,
+    pub ctrl: Point2D<T, U>,
+    pub to: Point2D<T, U>,
+}
+
+impl<T: Hash, U> Hash for QuadraticBezierSegment<T, U> {
+    fn hash<H: Hasher>(&self, h: &mut H) {
+        self.from.hash(h);
+        self.ctrl.hash(h);
+        self.to.hash(h);
+    }
+}
+
+impl<T: Copy, U> Copy for QuadraticBezierSegment<T, U> {}
+
+impl<T: Clone, U> Clone for QuadraticBezierSegment<T, U> {
+    fn clone(&self) -> Self {
+        Self::new(self.from.clone(), self.ctrl.clone(), self.to.clone())
+    }
+}
+
+impl<T: PartialEq, U> PartialEq for QuadraticBezierSegment<T, U> {
+    fn eq(&self, other: &Self) -> bool {
+        self.from.eq(&other.from) && self.ctrl.eq(&other.ctrl) && self.to.eq(&other.to)
+    }
+}
+
+impl<T: Eq, U> Eq for QuadraticBezierSegment<T, U> {}
+
+impl<T: fmt::Debug, U> fmt::Debug for QuadraticBezierSegment<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("QuadraticBezierSegment")
+            .field(&self.from)
+            .field(&self.ctrl)
+            .field(&self.to)
+            .finish()
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, T, U> arbitrary::Arbitrary<'a> for QuadraticBezierSegment<T, U>
+where
+    T: arbitrary::Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self>
+    {
+        let (from, ctrl, to) = arbitrary::Arbitrary::arbitrary(u)?;
+        Ok(QuadraticBezierSegment { from, ctrl, to })
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: Zeroable, U> Zeroable for QuadraticBezierSegment<T, U> {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: Pod, U: 'static> Pod for QuadraticBezierSegment<T, U> {}
+
+impl<T, U> QuadraticBezierSegment<T, U> {
+    /// Constructor.
+    #[inline]
+    pub const fn new(from: Point2D<T, U>, ctrl: Point2D<T, U>, to: Point2D<T, U>) -> Self {
+        QuadraticBezierSegment { from, ctrl, to }
+    }
+}
+
+impl<T: Copy, U> QuadraticBezierSegment<T, U> {
+    /// Returns the same curve, traversed in the opposite direction.
+    #[inline]
+    pub fn flip(&self) -> Self {
+        QuadraticBezierSegment::new(self.to, self.ctrl, self.from)
+    }
+
+    /// Returns the line segment between the endpoints of the curve.
+    #[inline]
+    pub fn baseline(&self) -> LineSegment2D<T, U> {
+        LineSegment2D::new(self.from, self.to)
+    }
+
+    /// Drop the units, preserving only the numeric value.
+    #[inline]
+    pub fn to_untyped(&self) -> QuadraticBezierSegment<T, UnknownUnit> {
+        self.cast_unit()
+    }
+
+    /// Tag a unitless value with units.
+    #[inline]
+    pub fn from_untyped(c: &QuadraticBezierSegment<T, UnknownUnit>) -> Self {
+        c.cast_unit()
+    }
+
+    /// Cast the unit
+    #[inline]
+    pub fn cast_unit<V>(&self) -> QuadraticBezierSegment<T, V> {
+        QuadraticBezierSegment::new(
+            self.from.cast_unit(),
+            self.ctrl.cast_unit(),
+            self.to.cast_unit(),
+        )
+    }
+}
+
+impl<T, U> QuadraticBezierSegment<T, U>
+where
+    T: Copy + Add<T, Output = T>,
+{
+    /// Translate the curve by a vector.
+    #[inline]
+    #[must_use]
+    pub fn translate(&self, by: Vector2D<T, U>) -> Self {
+        QuadraticBezierSegment::new(self.from + by, self.ctrl + by, self.to + by)
+    }
+}
+
+impl<T, U> QuadraticBezierSegment<T, U>
+where
+    T: Copy + Zero + PartialOrd,
+{
+    /// Returns the smallest box containing the endpoints and the control point.
+    ///
+    /// The curve is always inside of this box, but unlike `bounding_box` it may not
+    /// be the tightest one.
+    #[inline]
+    pub fn fast_bounding_box(&self) -> Box2D<T, U> {
+        Box2D::from_points(&[self.from, self.ctrl, self.to])
+    }
+}
+
+impl<T, U> QuadraticBezierSegment<T, U>
+where
+    T: Copy + Add<Output = T> + Mul<Output = T>,
+{
+    /// Applies the transform to the endpoints and the control point of the curve.
+    ///
+    /// Bézier curves are invariant under affine transforms, so this is the image of
+    /// the curve.
+    #[inline]
+    pub fn transform<Dst>(
+        &self,
+        transform: &Transform2D<T, U, Dst>,
+    ) -> QuadraticBezierSegment<T, Dst> {
+        QuadraticBezierSegment::new(
+            transform.transform_point(self.from),
+            transform.transform_point(self.ctrl),
+            transform.transform_point(self.to),
+        )
+    }
+}
+
+impl<T: Real, U> QuadraticBezierSegment<T, U> {
+    /// Returns the point at parameter `t`, `0` being `from` and `1` being `to`.
+    pub fn sample(&self, t: T) -> Point2D<T, U> {
+        let one = T::one();
+        let mt = one - t;
+
+        (self.from.to_vector() * (mt * mt)
+            + self.ctrl.to_vector() * ((one + one) * mt * t)
+            + self.to.to_vector() * (t * t))
+            .to_point()
+    }
+
+    /// Returns the derivative of the curve at parameter `t`.
+    pub fn derivative(&self, t: T) -> Vector2D<T, U> {
+        let two = T::one() + T::one();
+        ((self.ctrl - self.from) * (T::one() - t) + (self.to - self.ctrl) * t) * two
+    }
+
+    /// Returns the second derivative of the curve, which is constant.
+    #[inline]
+    pub fn second_derivative(&self) -> Vector2D<T, U> {
+        let two = T::one() + T::one();
+        ((self.to - self.ctrl) - (self.ctrl - self.from)) * two
+    }
+
+    /// Splits the curve at parameter `t` using de Casteljau's algorithm.
+    pub fn split(&self, t: T) -> (Self, Self) {
+        let a = self.from.lerp(self.ctrl, t);
+        let b = self.ctrl.lerp(self.to, t);
+        let split_point = a.lerp(b, t);
+        (
+            QuadraticBezierSegment::new(self.from, a, split_point),
+            QuadraticBezierSegment::new(split_point, b, self.to),
+        )
+    }
+
+    /// Returns the cubic Bézier curve describing the same curve.
+    pub fn to_cubic(&self) -> CubicBezierSegment<T, U> {
+        let two_thirds = (T::one() + T::one()) / (T::one() + T::one() + T::one());
+        CubicBezierSegment::new(
+            self.from,
+            self.from.lerp(self.ctrl, two_thirds),
+            self.to.lerp(self.ctrl, two_thirds),
+            self.to,
+        )
+    }
+
+    /// Returns the smallest box containing the curve.
+    pub fn bounding_box(&self) -> Box2D<T, U> {
+        let mut min = self.from.min(self.to);
+        let mut max = self.from.max(self.to);
+
+        let extrema = [
+            quadratic_extremum(self.from.x, self.ctrl.x, self.to.x),
+            quadratic_extremum(self.from.y, self.ctrl.y, self.to.y),
+        ];
+        for &t in extrema.iter().flatten() {
+            let p = self.sample(t);
+            min = min.min(p);
+            max = max.max(p);
+        }
+
+        Box2D::new(min, max)
+    }
+
+    /// Approximates the curve with a sequence of points, such that the line segments
+    /// between consecutive points are never further than `tolerance` from the curve.
+    ///
+    /// The iterator yields the points after `from`, the last one being `to`.
+    /// The tolerance must be positive.
+    pub fn flattened(&self, tolerance: T) -> FlattenedBezier<Self> {
+        let count = flattening_step_count(self.second_derivative().length(), tolerance);
+        FlattenedBezier::new(*self, count)
+    }
+}
+
+impl<T: NumCast + Copy, U> QuadraticBezierSegment<T, U> {
+    /// Cast from one numeric representation to another, preserving the units.
+    #[inline]
+    pub fn cast<NewT: NumCast>(&self) -> QuadraticBezierSegment<NewT, U> {
+        QuadraticBezierSegment::new(self.from.cast(), self.ctrl.cast(), self.to.cast())
+    }
+
+    /// Fallible cast from one numeric representation to another, preserving the units.
+    pub fn try_cast<NewT: NumCast>(&self) -> Option<QuadraticBezierSegment<NewT, U>> {
+        match (self.from.try_cast(), self.ctrl.try_cast(), self.to.try_cast()) {
+            (Some(from), Some(ctrl), Some(to)) => Some(QuadraticBezierSegment::new(from, ctrl, to)),
+            _ => None,
+        }
+    }
+
+    // Convenience functions for common casts
+
+    /// Cast into an `f32` curve.
+    #[inline]
+    pub fn to_f32(&self) -> QuadraticBezierSegment<f32, U> {
+        self.cast()
+    }
+
+    /// Cast into an `f64` curve.
+    #[inline]
+    pub fn to_f64(&self) -> QuadraticBezierSegment<f64, U> {
+        self.cast()
+    }
+}
+
+impl<T: ApproxEq<T>, U> ApproxEq<T> for QuadraticBezierSegment<T, U> {
+    #[inline]
+    fn approx_epsilon() -> T {
+        T::approx_epsilon()
+    }
+
+    #[inline]
+    fn approx_eq_eps(&self, other: &Self, eps: &T) -> bool {
+        approx_eq_2d(&self.from, &other.from, eps)
+            && approx_eq_2d(&self.ctrl, &other.ctrl, eps)
+            && approx_eq_2d(&self.to, &other.to, eps)
+    }
+}
+
+/// A 2d cubic Bézier curve segment, with two control points.
+///
+/// The segment is parametrized with `t` going from `0` at `from` to `1` at `to`.
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Serialize", deserialize = "T: Deserialize<'de>"))
+)]
+pub struct CubicBezierSegment<T, U> {
+    pub from: Point2D<T, U>,
+    pub ctrl1: Point2D<T, U>,
+    pub ctrl2: Point2D<T, U>,
+    pub to: Point2D<T, U>,
+}
+
+impl<T: Hash, U> Hash for CubicBezierSegment<T, U> {
+    fn hash<H: Hasher>(&self, h: &mut H) {
+        self.from.hash(h);
+        self.ctrl1.hash(h);
+        self.ctrl2.hash(h);
+        self.to.hash(h);
+    }
+}
+
+impl<T: Copy, U> Copy for CubicBezierSegment<T, U> {}
+
+impl<T: Clone, U> Clone for CubicBezierSegment<T, U> {
+    fn clone(&self) -> Self {
+        Self::new(self.from.clone(), self.ctrl1.clone(), self.ctrl2.clone(), self.to.clone())
+    }
+}
+
+impl<T: PartialEq, U> PartialEq for CubicBezierSegment<T, U> {
+    fn eq(&self, other: &Self) -> bool {
+        self.from.eq(&other.from)
+            && self.ctrl1.eq(&other.ctrl1)
+            && self.ctrl2.eq(&other.ctrl2)
+            && self.to.eq(&other.to)
+    }
+}
+
+impl<T: Eq, U> Eq for CubicBezierSegment<T, U> {}
+
+impl<T: fmt::Debug, U> fmt::Debug for CubicBezierSegment<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("CubicBezierSegment")
+            .field(&self.from)
+            .field(&self.ctrl1)
+            .field(&self.ctrl2)
+            .field(&self.to)
+            .finish()
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, T, U> arbitrary::Arbitrary<'a> for CubicBezierSegment<T, U>
+where
+    T: arbitrary::Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self>
+    {
+        let (from, ctrl1, ctrl2, to) = arbitrary::Arbitrary::arbitrary(u)?;
+        Ok(CubicBezierSegment { from, ctrl1, ctrl2, to })
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: Zeroable, U> Zeroable for CubicBezierSegment<T, U> {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: Pod, U: 'static> Pod for CubicBezierSegment<T, U> {}
+
+impl<T, U> CubicBezierSegment<T, U> {
+    /// Constructor.
+    #[inline]
+    pub const fn new(
+        from: Point2D<T, U>,
+        ctrl1: Point2D<T, U>,
+        ctrl2: Point2D<T, U>,
+        to: Point2D<T, U>,
+    ) -> Self {
+        CubicBezierSegment { from, ctrl1, ctrl2, to }
+    }
+}
+
+impl<T: Copy, U> CubicBezierSegment<T, U> {
+    /// Returns the same curve, traversed in the opposite direction.
+    #[inline]
+    pub fn flip(&self) -> Self {
+        CubicBezierSegment::new(self.to, self.ctrl2, self.ctrl1, self.from)
+    }
+
+    /// Returns the line segment between the endpoints of the curve.
+    #[inline]
+    pub fn baseline(&self) -> LineSegment2D<T, U> {
+        LineSegment2D::new(self.from, self.to)
+    }
+
+    /// Drop the units, preserving only the numeric value.
+    #[inline]
+    pub fn to_untyped(&self) -> CubicBezierSegment<T, UnknownUnit> {
+        self.cast_unit()
+    }
+
+    /// Tag a unitless value with units.
+    #[inline]
+    pub fn from_untyped(c: &CubicBezierSegment<T, UnknownUnit>) -> Self {
+        c.cast_unit()
+    }
+
+    /// Cast the unit
+    #[inline]
+    pub fn cast_unit<V>(&self) -> CubicBezierSegment<T, V> {
+        CubicBezierSegment::new(
+            self.from.cast_unit(),
+            self.ctrl1.cast_unit(),
+            self.ctrl2.cast_unit(),
+            self.to.cast_unit(),
+        )
+    }
+}
+
+impl<T, U> CubicBezierSegment<T, U>
+where
+    T: Copy + Add<T, Output = T>,
+{
+    /// Translate the curve by a vector.
+    #[inline]
+    #[must_use]
+    pub fn translate(&self, by: Vector2D<T, U>) -> Self {
+        CubicBezierSegment::new(self.from + by, self.ctrl1 + by, self.ctrl2 + by, self.to + by)
+    }
+}
+
+impl<T, U> CubicBezierSegment<T, U>
+where
+    T: Copy + Zero + PartialOrd,
+{
+    /// Returns the smallest box containing the endpoints and the control points.
+    ///
+    /// The curve is always inside of this box, but unlike `bounding_box` it may not
+    /// be the tightest one.
+    #[inline]
+    pub fn fast_bounding_box(&self) -> Box2D<T, U> {
+        Box2D::from_points(&[self.from, self.ctrl1, self.ctrl2, self.to])
+    }
+}
+
+impl<T, U> CubicBezierSegment<T, U>
+where
+    T: Copy + Add<Output = T> + Mul<Output = T>,
+{
+    /// Applies the transform to the endpoints and the control points of the curve.
+    ///
+    /// Bézier curves are invariant under affine transforms, so this is the image of
+    /// the curve.
+    #[inline]
+    pub fn transform<Dst>(
+        &self,
+        transform: &Transform2D<T, U, Dst>,
+    ) -> CubicBezierSegment<T, Dst> {
+        CubicBezierSegment::new(
+            transform.transform_point(self.from),
+            transform.transform_point(self.ctrl1),
+            transform.transform_point(self.ctrl2),
+            transform.transform_point(self.to),
+        )
+    }
+}
+
+impl<T: Real, U> CubicBezierSegment<T, U> {
+    /// Returns the point at parameter `t`, `0` being `from` and `1` being `to`.
+    pub fn sample(&self, t: T) -> Point2D<T, U> {
+        let one = T::one();
+        let three = one + one + one;
+        let mt = one - t;
+
+        (self.from.to_vector() * (mt * mt * mt)
+            + self.ctrl1.to_vector() * (three * mt * mt * t)
+            + self.ctrl2.to_vector() * (three * mt * t * t)
+            + self.to.to_vector() * (t * t * t))
+            .to_point()
+    }
+
+    /// Returns the derivative of the curve at parameter `t`.
+    pub fn derivative(&self, t: T) -> Vector2D<T, U> {
+        let one = T::one();
+        let two = one + one;
+        let mt = one - t;
+
+        ((self.ctrl1 - self.from) * (mt * mt)
+            + (self.ctrl2 - self.ctrl1) * (two * mt * t)
+            + (self.to - self.ctrl2) * (t * t))
+            * (two + one)
+    }
+
+    /// Returns the second derivative of the curve at parameter `t`.
+    pub fn second_derivative(&self, t: T) -> Vector2D<T, U> {
+        let (start, end) = self.second_differences();
+        let six = (T::one() + T::one() + T::one()) * (T::one() + T::one());
+        (start * (T::one() - t) + end * t) * six
+    }
+
+    /// The second derivative at both endpoints, divided by six.
+    fn second_differences(&self) -> (Vector2D<T, U>, Vector2D<T, U>) {
+        (
+            (self.ctrl2 - self.ctrl1) - (self.ctrl1 - self.from),
+            (self.to - self.ctrl2) - (self.ctrl2 - self.ctrl1),
+        )
+    }
+
+    /// Splits the curve at parameter `t` using de Casteljau's algorithm.
+    pub fn split(&self, t: T) -> (Self, Self) {
+        let a = self.from.lerp(self.ctrl1, t);
+        let b = self.ctrl1.lerp(self.ctrl2, t);
+        let c = self.ctrl2.lerp(self.to, t);
+        let ab = a.lerp(b, t);
+        let bc = b.lerp(c, t);
+        let split_point = ab.lerp(bc, t);
+        (
+            CubicBezierSegment::new(self.from, a, ab, split_point),
+            CubicBezierSegment::new(split_point, bc, c, self.to),
+        )
+    }
+
+    /// Returns the smallest box containing the curve.
+    pub fn bounding_box(&self) -> Box2D<T, U> {
+        let mut min = self.from.min(self.to);
+        let mut max = self.from.max(self.to);
+
+        let [x0, x1] = cubic_extrema(self.from.x, self.ctrl1.x, self.ctrl2.x, self.to.x);
+        let [y0, y1] = cubic_extrema(self.from.y, self.ctrl1.y, self.ctrl2.y, self.to.y);
+        for &t in [x0, x1, y0, y1].iter().flatten() {
+            let p = self.sample(t);
+            min = min.min(p);
+            max = max.max(p);
+        }
+
+        Box2D::new(min, max)
+    }
+
+    /// Approximates the curve with a sequence of points, such that the line segments
+    /// between consecutive points are never further than `tolerance` from the curve.
+    ///
+    /// The iterator yields the points after `from`, the last one being `to`.
+    /// The tolerance must be positive.
+    pub fn flattened(&self, tolerance: T) -> FlattenedBezier<Self> {
+        // The second derivative is a linear interpolation, its norm is at most the
+        // largest one at the endpoints.
+        let (start, end) = self.second_differences();
+        let six = (T::one() + T::one() + T::one()) * (T::one() + T::one());
+        let max = start.length().max(end.length()) * six;
+
+        FlattenedBezier::new(*self, flattening_step_count(max, tolerance))
+    }
+}
+
+impl<T: NumCast + Copy, U> CubicBezierSegment<T, U> {
+    /// Cast from one numeric representation to another, preserving the units.
+    #[inline]
+    pub fn cast<NewT: NumCast>(&self) -> CubicBezierSegment<NewT, U> {
+        CubicBezierSegment::new(
+            self.from.cast(),
+            self.ctrl1.cast(),
+            self.ctrl2.cast(),
+            self.to.cast(),
+        )
+    }
+
+    /// Fallible cast from one numeric representation to another, preserving the units.
+    pub fn try_cast<NewT: NumCast>(&self) -> Option<CubicBezierSegment<NewT, U>> {
+        match (
+            self.from.try_cast(),
+            self.ctrl1.try_cast(),
+            self.ctrl2.try_cast(),
+            self.to.try_cast(),
+        ) {
+            (Some(from), Some(ctrl1), Some(ctrl2), Some(to)) => {
+                Some(CubicBezierSegment::new(from, ctrl1, ctrl2, to))
+            }
+            _ => None,
+        }
+    }
+
+    // Convenience functions for common casts
+
+    /// Cast into an `f32` curve.
+    #[inline]
+    pub fn to_f32(&self) -> CubicBezierSegment<f32, U> {
+        self.cast()
+    }
+
+    /// Cast into an `f64` curve.
+    #[inline]
+    pub fn to_f64(&self) -> CubicBezierSegment<f64, U> {
+        self.cast()
+    }
+}
+
+impl<T: ApproxEq<T>, U> ApproxEq<T> for CubicBezierSegment<T, U> {
+    #[inline]
+    fn approx_epsilon() -> T {
+        T::approx_epsilon()
+    }
+
+    #[inline]
+    fn approx_eq_eps(&self, other: &Self, eps: &T) -> bool {
+        approx_eq_2d(&self.from, &other.from, eps)
+            && approx_eq_2d(&self.ctrl1, &other.ctrl1, eps)
+            && approx_eq_2d(&self.ctrl2, &other.ctrl2, eps)
+            && approx_eq_2d(&self.to, &other.to, eps)
+    }
+}
+
+/// A 3d quadratic Bézier curve segment, with a single control point.
+///
+/// The segment is parametrized with `t` going from `0` at `from` to `1` at `to`.
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Serialize", deserialize = "T: Deserialize<'de>"))
+)]
+pub struct QuadraticBezierSegment3D<T, U> {
+    pub from: Point3D<T, U>,
+    pub ctrl: Point3D<T, U>,
+    pub to: Point3D<T, U>,
+}
+
+impl<T: Hash, U> Hash for QuadraticBezierSegment3D<T, U> {
+    fn hash<H: Hasher>(&self, h: &mut H) {
+        self.from.hash(h);
+        self.ctrl.hash(h);
+        self.to.hash(h);
+    }
+}
+
+impl<T: Copy, U> Copy for QuadraticBezierSegment3D<T, U> {}
+
+impl<T: Clone, U> Clone for QuadraticBezierSegment3D<T, U> {
+    fn clone(&self) -> Self {
+        Self::new(self.from.clone(), self.ctrl.clone(), self.to.clone())
+    }
+}
+
+impl<T: PartialEq, U> PartialEq for QuadraticBezierSegment3D<T, U> {
+    fn eq(&self, other: &Self) -> bool {
+        self.from.eq(&other.from) && self.ctrl.eq(&other.ctrl) && self.to.eq(&other.to)
+    }
+}
+
+impl<T: Eq, U> Eq for QuadraticBezierSegment3D<T, U> {}
+
+impl<T: fmt::Debug, U> fmt::Debug for QuadraticBezierSegment3D<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("QuadraticBezierSegment3D")
+            .field(&self.from)
+            .field(&self.ctrl)
+            .field(&self.to)
+            .finish()
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, T, U> arbitrary::Arbitrary<'a> for QuadraticBezierSegment3D<T, U>
+where
+    T: arbitrary::Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self>
+    {
+        let ((x0, y0, z0), (x1, y1, z1), (x2, y2, z2)) = arbitrary::Arbitrary::arbitrary(u)?;
+        Ok(QuadraticBezierSegment3D {
+            from: Point3D::new(x0, y0, z0),
+            ctrl: Point3D::new(x1, y1, z1),
+            to: Point3D::new(x2, y2, z2),
+        })
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: Zeroable, U> Zeroable for QuadraticBezierSegment3D<T, U> {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: Pod, U: 'static> Pod for QuadraticBezierSegment3D<T, U> {}
+
+impl<T, U> QuadraticBezierSegment3D<T, U> {
+    /// Constructor.
+    #[inline]
+    pub const fn new(from: Point3D<T, U>, ctrl: Point3D<T, U>, to: Point3D<T, U>) -> Self {
+        QuadraticBezierSegment3D { from, ctrl, to }
+    }
+}
+
+impl<T: Copy, U> QuadraticBezierSegment3D<T, U> {
+    /// Returns the same curve, traversed in the opposite direction.
+    #[inline]
+    pub fn flip(&self) -> Self {
+        QuadraticBezierSegment3D::new(self.to, self.ctrl, self.from)
+    }
+
+    /// Returns the line segment between the endpoints of the curve.
+    #[inline]
+    pub fn baseline(&self) -> LineSegment3D<T, U> {
+        LineSegment3D::new(self.from, self.to)
+    }
+
+    /// Drop the units, preserving only the numeric value.
+    #[inline]
+    pub fn to_untyped(&self) -> QuadraticBezierSegment3D<T, UnknownUnit> {
+        self.cast_unit()
+    }
+
+    /// Tag a unitless value with units.
+    #[inline]
+    pub fn from_untyped(c: &QuadraticBezierSegment3D<T, UnknownUnit>) -> Self {
+        c.cast_unit()
+    }
+
+    /// Cast the unit
+    #[inline]
+    pub fn cast_unit<V>(&self) -> QuadraticBezierSegment3D<T, V> {
+        QuadraticBezierSegment3D::new(
+            self.from.cast_unit(),
+            self.ctrl.cast_unit(),
+            self.to.cast_unit(),
+        )
+    }
+}
+
+impl<T, U> QuadraticBezierSegment3D<T, U>
+where
+    T: Copy + Add<T, Output = T>,
+{
+    /// Translate the curve by a vector.
+    #[inline]
+    #[must_use]
+    pub fn translate(&self, by: Vector3D<T, U>) -> Self {
+        QuadraticBezierSegment3D::new(self.from + by, self.ctrl + by, self.to + by)
+    }
+}
+
+impl<T, U> QuadraticBezierSegment3D<T, U>
+where
+    T: Copy + Zero + PartialOrd,
+{
+    /// Returns the smallest box containing the endpoints and the control point.
+    ///
+    /// The curve is always inside of this box, but unlike `bounding_box` it may not
+    /// be the tightest one.
+    #[inline]
+    pub fn fast_bounding_box(&self) -> Box3D<T, U> {
+        Box3D::from_points(&[self.from, self.ctrl, self.to])
+    }
+}
+
+impl<T, U> QuadraticBezierSegment3D<T, U>
+where
+    T: Copy + Zero + PartialOrd + Add<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    /// Applies the transform to the endpoints and the control point of the curve,
+    /// returning `None` if any of them ends up behind the projection plane (see
+    /// [`Transform3D::transform_point3d`]).
+    ///
+    /// This is the image of the curve for affine transforms only, perspective
+    /// projections of Bézier curves are rational curves.
+    ///
+    /// [`Transform3D::transform_point3d`]: struct.Transform3D.html#method.transform_point3d
+    #[inline]
+    pub fn transform<Dst>(
+        &self,
+        transform: &Transform3D<T, U, Dst>,
+    ) -> Option<QuadraticBezierSegment3D<T, Dst>> {
+        Some(QuadraticBezierSegment3D::new(
+            transform.transform_point3d(self.from)?,
+            transform.transform_point3d(self.ctrl)?,
+            transform.transform_point3d(self.to)?,
+        ))
+    }
+}
+
+impl<T: Real, U> QuadraticBezierSegment3D<T, U> {
+    /// Returns the point at parameter `t`, `0` being `from` and `1` being `to`.
+    pub fn sample(&self, t: T) -> Point3D<T, U> {
+        let one = T::one();
+        let mt = one - t;
+
+        (self.from.to_vector() * (mt * mt)
+            + self.ctrl.to_vector() * ((one + one) * mt * t)
+            + self.to.to_vector() * (t * t))
+            .to_point()
+    }
+
+    /// Returns the derivative of the curve at parameter `t`.
+    pub fn derivative(&self, t: T) -> Vector3D<T, U> {
+        let two = T::one() + T::one();
+        ((self.ctrl - self.from) * (T::one() - t) + (self.to - self.ctrl) * t) * two
+    }
+
+    /// Returns the second derivative of the curve, which is constant.
+    #[inline]
+    pub fn second_derivative(&self) -> Vector3D<T, U> {
+        let two = T::one() + T::one();
+        ((self.to - self.ctrl) - (self.ctrl - self.from)) * two
+    }
+
+    /// Splits the curve at parameter `t` using de Casteljau's algorithm.
+    pub fn split(&self, t: T) -> (Self, Self) {
+        let a = self.from.lerp(self.ctrl, t);
+        let b = self.ctrl.lerp(self.to, t);
+        let split_point = a.lerp(b, t);
+        (
+            QuadraticBezierSegment3D::new(self.from, a, split_point),
+            QuadraticBezierSegment3D::new(split_point, b, self.to),
+        )
+    }
+
+    /// Returns the cubic Bézier curve describing the same curve.
+    pub fn to_cubic(&self) -> CubicBezierSegment3D<T, U> {
+        let two_thirds = (T::one() + T::one()) / (T::one() + T::one() + T::one());
+        CubicBezierSegment3D::new(
+            self.from,
+            self.from.lerp(self.ctrl, two_thirds),
+            self.to.lerp(self.ctrl, two_thirds),
+            self.to,
+        )
+    }
+
+    /// Returns the smallest box containing the curve.
+    pub fn bounding_box(&self) -> Box3D<T, U> {
+        let mut min = self.from.min(self.to);
+        let mut max = self.from.max(self.to);
+
+        let extrema = [
+            quadratic_extremum(self.from.x, self.ctrl.x, self.to.x),
+            quadratic_extremum(self.from.y, self.ctrl.y, self.to.y),
+            quadratic_extremum(self.from.z, self.ctrl.z, self.to.z),
+        ];
+        for &t in extrema.iter().flatten() {
+            let p = self.sample(t);
+            min = min.min(p);
+            max = max.max(p);
+        }
+
+        Box3D::new(min, max)
+    }
+
+    /// Approximates the curve with a sequence of points, such that the line segments
+    /// between consecutive points are never further than `tolerance` from the curve.
+    ///
+    /// The iterator yields the points after `from`, the last one being `to`.
+    /// The tolerance must be positive.
+    pub fn flattened(&self, tolerance: T) -> FlattenedBezier<Self> {
+        let count = flattening_step_count(self.second_derivative().length(), tolerance);
+        FlattenedBezier::new(*self, count)
+    }
+}
+
+impl<T: NumCast + Copy, U> QuadraticBezierSegment3D<T, U> {
+    /// Cast from one numeric representation to another, preserving the units.
+    #[inline]
+    pub fn cast<NewT: NumCast>(&self) -> QuadraticBezierSegment3D<NewT, U> {
+        QuadraticBezierSegment3D::new(self.from.cast(), self.ctrl.cast(), self.to.cast())
+    }
+
+    /// Fallible cast from one numeric representation to another, preserving the units.
+    pub fn try_cast<NewT: NumCast>(&self) -> Option<QuadraticBezierSegment3D<NewT, U>> {
+        match (self.from.try_cast(), self.ctrl.try_cast(), self.to.try_cast()) {
+            (Some(from), Some(ctrl), Some(to)) => {
+                Some(QuadraticBezierSegment3D::new(from, ctrl, to))
+            }
+            _ => None,
+        }
+    }
+
+    // Convenience functions for common casts
+
+    /// Cast into an `f32` curve.
+    #[inline]
+    pub fn to_f32(&self) -> QuadraticBezierSegment3D<f32, U> {
+        self.cast()
+    }
+
+    /// Cast into an `f64` curve.
+    #[inline]
+    pub fn to_f64(&self) -> QuadraticBezierSegment3D<f64, U> {
+        self.cast()
+    }
+}
+
+impl<T: ApproxEq<T>, U> ApproxEq<T> for QuadraticBezierSegment3D<T, U> {
+    #[inline]
+    fn approx_epsilon() -> T {
+        T::approx_epsilon()
+    }
+
+    #[inline]
+    fn approx_eq_eps(&self, other: &Self, eps: &T) -> bool {
+        approx_eq_3d(&self.from, &other.from, eps)
+            && approx_eq_3d(&self.ctrl, &other.ctrl, eps)
+            && approx_eq_3d(&self.to, &other.to, eps)
+    }
+}
+
+/// A 3d cubic Bézier curve segment, with two control points.
+///
+/// The segment is parametrized with `t` going from `0` at `from` to `1` at `to`.
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Serialize", deserialize = "T: Deserialize<'de>"))
+)]
+pub struct CubicBezierSegment3D<T, U> {
+    pub from: Point3D<T, U>,
+    pub ctrl1: Point3D<T, U>,
+    pub ctrl2: Point3D<T, U>,
+    pub to: Point3D<T, U>,
+}
+
+impl<T: Hash, U> Hash for CubicBezierSegment3D<T, U> {
+    fn hash<H: Hasher>(&self, h: &mut H) {
+        self.from.hash(h);
+        self.ctrl1.hash(h);
+        self.ctrl2.hash(h);
+        self.to.hash(h);
+    }
+}
+
+impl<T: Copy, U> Copy for CubicBezierSegment3D<T, U> {}
+
+impl<T: Clone, U> Clone for CubicBezierSegment3D<T, U> {
+    fn clone(&self) -> Self {
+        Self::new(self.from.clone(), self.ctrl1.clone(), self.ctrl2.clone(), self.to.clone())
+    }
+}
+
+impl<T: PartialEq, U> PartialEq for CubicBezierSegment3D<T, U> {
+    fn eq(&self, other: &Self) -> bool {
+        self.from.eq(&other.from)
+            && self.ctrl1.eq(&other.ctrl1)
+            && self.ctrl2.eq(&other.ctrl2)
+            && self.to.eq(&other.to)
+    }
+}
+
+impl<T: Eq, U> Eq for CubicBezierSegment3D<T, U> {}
+
+impl<T: fmt::Debug, U> fmt::Debug for CubicBezierSegment3D<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("CubicBezierSegment3D")
+            .field(&self.from)
+            .field(&self.ctrl1)
+            .field(&self.ctrl2)
+            .field(&self.to)
+            .finish()
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, T, U> arbitrary::Arbitrary<'a> for CubicBezierSegment3D<T, U>
+where
+    T: arbitrary::Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self>
+    {
+        let ((x0, y0, z0), (x1, y1, z1), (x2, y2, z2), (x3, y3, z3)) =
+            arbitrary::Arbitrary::arbitrary(u)?;
+        Ok(CubicBezierSegment3D {
+            from: Point3D::new(x0, y0, z0),
+            ctrl1: Point3D::new(x1, y1, z1),
+            ctrl2: Point3D::new(x2, y2, z2),
+            to: Point3D::new(x3, y3, z3),
+        })
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: Zeroable, U> Zeroable for CubicBezierSegment3D<T, U> {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: Pod, U: 'static> Pod for CubicBezierSegment3D<T, U> {}
+
+impl<T, U> CubicBezierSegment3D<T, U> {
+    /// Constructor.
+    #[inline]
+    pub const fn new(
+        from: Point3D<T, U>,
+        ctrl1: Point3D<T, U>,
+        ctrl2: Point3D<T, U>,
+        to: Point3D<T, U>,
+    ) -> Self {
+        CubicBezierSegment3D { from, ctrl1, ctrl2, to }
+    }
+}
+
+impl<T: Copy, U> CubicBezierSegment3D<T, U> {
+    /// Returns the same curve, traversed in the opposite direction.
+    #[inline]
+    pub fn flip(&self) -> Self {
+        CubicBezierSegment3D::new(self.to, self.ctrl2, self.ctrl1, self.from)
+    }
+
+    /// Returns the line segment between the endpoints of the curve.
+    #[inline]
+    pub fn baseline(&self) -> LineSegment3D<T, U> {
+        LineSegment3D::new(self.from, self.to)
+    }
+
+    /// Drop the units, preserving only the numeric value.
+    #[inline]
+    pub fn to_untyped(&self) -> CubicBezierSegment3D<T, UnknownUnit> {
+        self.cast_unit()
+    }
+
+    /// Tag a unitless value with units.
+    #[inline]
+    pub fn from_untyped(c: &CubicBezierSegment3D<T, UnknownUnit>) -> Self {
+        c.cast_unit()
+    }
+
+    /// Cast the unit
+    #[inline]
+    pub fn cast_unit<V>(&self) -> CubicBezierSegment3D<T, V> {
+        CubicBezierSegment3D::new(
+            self.from.cast_unit(),
+            self.ctrl1.cast_unit(),
+            self.ctrl2.cast_unit(),
+            self.to.cast_unit(),
+        )
+    }
+}
+
+impl<T, U> CubicBezierSegment3D<T, U>
+where
+    T: Copy + Add<T, Output = T>,
+{
+    /// Translate the curve by a vector.
+    #[inline]
+    #[must_use]
+    pub fn translate(&self, by: Vector3D<T, U>) -> Self {
+        CubicBezierSegment3D::new(self.from + by, self.ctrl1 + by, self.ctrl2 + by, self.to + by)
+    }
+}
+
+impl<T, U> CubicBezierSegment3D<T, U>
+where
+    T: Copy + Zero + PartialOrd,
+{
+    /// Returns the smallest box containing the endpoints and the control points.
+    ///
+    /// The curve is always inside of this box, but unlike `bounding_box` it may not
+    /// be the tightest one.
+    #[inline]
+    pub fn fast_bounding_box(&self) -> Box3D<T, U> {
+        Box3D::from_points(&[self.from, self.ctrl1, self.ctrl2, self.to])
+    }
+}
+
+impl<T, U> CubicBezierSegment3D<T, U>
+where
+    T: Copy + Zero + PartialOrd + Add<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    /// Applies the transform to the endpoints and the control points of the curve,
+    /// returning `None` if any of them ends up behind the projection plane (see
+    /// [`Transform3D::transform_point3d`]).
+    ///
+    /// This is the image of the curve for affine transforms only, perspective
+    /// projections of Bézier curves are rational curves.
+    ///
+    /// [`Transform3D::transform_point3d`]: struct.Transform3D.html#method.transform_point3d
+    #[inline]
+    pub fn transform<Dst>(
+        &self,
+        transform: &Transform3D<T, U, Dst>,
+    ) -> Option<CubicBezierSegment3D<T, Dst>> {
+        Some(CubicBezierSegment3D::new(
+            transform.transform_point3d(self.from)?,
+            transform.transform_point3d(self.ctrl1)?,
+            transform.transform_point3d(self.ctrl2)?,
+            transform.transform_point3d(self.to)?,
+        ))
+    }
+}
+
+impl<T: Real, U> CubicBezierSegment3D<T, U> {
+    /// Returns the point at parameter `t`, `0` being `from` and `1` being `to`.
+    pub fn sample(&self, t: T) -> Point3D<T, U> {
+        let one = T::one();
+        let three = one + one + one;
+        let mt = one - t;
+
+        (self.from.to_vector() * (mt * mt * mt)
+            + self.ctrl1.to_vector() * (three * mt * mt * t)
+            + self.ctrl2.to_vector() * (three * mt * t * t)
+            + self.to.to_vector() * (t * t * t))
+            .to_point()
+    }
+
+    /// Returns the derivative of the curve at parameter `t`.
+    pub fn derivative(&self, t: T) -> Vector3D<T, U> {
+        let one = T::one();
+        let two = one + one;
+        let mt = one - t;
+
+        ((self.ctrl1 - self.from) * (mt * mt)
+            + (self.ctrl2 - self.ctrl1) * (two * mt * t)
+            + (self.to - self.ctrl2) * (t * t))
+            * (two + one)
+    }
+
+    /// Returns the second derivative of the curve at parameter `t`.
+    pub fn second_derivative(&self, t: T) -> Vector3D<T, U> {
+        let (start, end) = self.second_differences();
+        let six = (T::one() + T::one() + T::one()) * (T::one() + T::one());
+        (start * (T::one() - t) + end * t) * six
+    }
+
+    /// The second derivative at both endpoints, divided by six.
+    fn second_differences(&self) -> (Vector3D<T, U>, Vector3D<T, U>) {
+        (
+            (self.ctrl2 - self.ctrl1) - (self.ctrl1 - self.from),
+            (self.to - self.ctrl2) - (self.ctrl2 - self.ctrl1),
+        )
+    }
+
+    /// Splits the curve at parameter `t` using de Casteljau's algorithm.
+    pub fn split(&self, t: T) -> (Self, Self) {
+        let a = self.from.lerp(self.ctrl1, t);
+        let b = self.ctrl1.lerp(self.ctrl2, t);
+        let c = self.ctrl2.lerp(self.to, t);
+        let ab = a.lerp(b, t);
+        let bc = b.lerp(c, t);
+        let split_point = ab.lerp(bc, t);
+        (
+            CubicBezierSegment3D::new(self.from, a, ab, split_point),
+            CubicBezierSegment3D::new(split_point, bc, c, self.to),
+        )
+    }
+
+    /// Returns the smallest box containing the curve.
+    pub fn bounding_box(&self) -> Box3D<T, U> {
+        let mut min = self.from.min(self.to);
+        let mut max = self.from.max(self.to);
+
+        let [x0, x1] = cubic_extrema(self.from.x, self.ctrl1.x, self.ctrl2.x, self.to.x);
+        let [y0, y1] = cubic_extrema(self.from.y, self.ctrl1.y, self.ctrl2.y, self.to.y);
+        let [z0, z1] = cubic_extrema(self.from.z, self.ctrl1.z, self.ctrl2.z, self.to.z);
+        for &t in [x0, x1, y0, y1, z0, z1].iter().flatten() {
+            let p = self.sample(t);
+            min = min.min(p);
+            max = max.max(p);
+        }
+
+        Box3D::new(min, max)
+    }
+
+    /// Approximates the curve with a sequence of points, such that the line segments
+    /// between consecutive points are never further than `tolerance` from the curve.
+    ///
+    /// The iterator yields the points after `from`, the last one being `to`.
+    /// The tolerance must be positive.
+    pub fn flattened(&self, tolerance: T) -> FlattenedBezier<Self> {
+        // The second derivative is a linear interpolation, its norm is at most the
+        // largest one at the endpoints.
+        let (start, end) = self.second_differences();
+        let six = (T::one() + T::one() + T::one()) * (T::one() + T::one());
+        let max = start.length().max(end.length()) * six;
+
+        FlattenedBezier::new(*self, flattening_step_count(max, tolerance))
+    }
+}
+
+impl<T: NumCast + Copy, U> CubicBezierSegment3D<T, U> {
+    /// Cast from one numeric representation to another, preserving the units.
+    #[inline]
+    pub fn cast<NewT: NumCast>(&self) -> CubicBezierSegment3D<NewT, U> {
+        CubicBezierSegment3D::new(
+            self.from.cast(),
+            self.ctrl1.cast(),
+            self.ctrl2.cast(),
+            self.to.cast(),
+        )
+    }
+
+    /// Fallible cast from one numeric representation to another, preserving the units.
+    pub fn try_cast<NewT: NumCast>(&self) -> Option<CubicBezierSegment3D<NewT, U>> {
+        match (
+            self.from.try_cast(),
+            self.ctrl1.try_cast(),
+            self.ctrl2.try_cast(),
+            self.to.try_cast(),
+        ) {
+            (Some(from), Some(ctrl1), Some(ctrl2), Some(to)) => {
+                Some(CubicBezierSegment3D::new(from, ctrl1, ctrl2, to))
+            }
+            _ => None,
+        }
+    }
+
+    // Convenience functions for common casts
+
+    /// Cast into an `f32` curve.
+    #[inline]
+    pub fn to_f32(&self) -> CubicBezierSegment3D<f32, U> {
+        self.cast()
+    }
+
+    /// Cast into an `f64` curve.
+    #[inline]
+    pub fn to_f64(&self) -> CubicBezierSegment3D<f64, U> {
+        self.cast()
+    }
+}
+
+impl<T: ApproxEq<T>, U> ApproxEq<T> for CubicBezierSegment3D<T, U> {
+    #[inline]
+    fn approx_epsilon() -> T {
+        T::approx_epsilon()
+    }
+
+    #[inline]
+    fn approx_eq_eps(&self, other: &Self, eps: &T) -> bool {
+        approx_eq_3d(&self.from, &other.from, eps)
+            && approx_eq_3d(&self.ctrl1, &other.ctrl1, eps)
+            && approx_eq_3d(&self.ctrl2, &other.ctrl2, eps)
+            && approx_eq_3d(&self.to, &other.to, eps)
+    }
+}
+
+/// An iterator over the points approximating a Bézier curve segment, see for
+/// example `CubicBezierSegment::flattened`.
+#[derive(Clone, Debug)]
+pub struct FlattenedBezier<S> {
+    segment: S,
+    count: u32,
+    current: u32,
+}
+
+impl<S> FlattenedBezier<S> {
+    fn new(segment: S, count: u32) -> Self {
+        FlattenedBezier { segment, count, current: 0 }
+    }
+}
+
+macro_rules! impl_flattened_bezier {
+    ($segment:ident, $point:ident) => {
+        impl<T: Real, U> Iterator for FlattenedBezier<$segment<T, U>> {
+            type Item = $point<T, U>;
+
+            fn next(&mut self) -> Option<$point<T, U>> {
+                if self.current >= self.count {
+                    return None;
+                }
+
+                // The last sample is at exactly t = 1, which evaluates to `to`.
+                self.current += 1;
+                let current: T = NumCast::from(self.current).unwrap();
+                let count: T = NumCast::from(self.count).unwrap();
+                Some(self.segment.sample(current / count))
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let remaining = (self.count - self.current) as usize;
+                (remaining, Some(remaining))
+            }
+        }
+
+        impl<T: Real, U> ExactSizeIterator for FlattenedBezier<$segment<T, U>> {}
+    };
+}
+
+impl_flattened_bezier!(QuadraticBezierSegment, Point2D);
+impl_flattened_bezier!(CubicBezierSegment, Point2D);
+impl_flattened_bezier!(QuadraticBezierSegment3D, Point3D);
+impl_flattened_bezier!(CubicBezierSegment3D, Point3D);
+
+#[cfg(test)]
+mod tests {
+    use crate::approxeq::ApproxEq;
+    use crate::default::{
+        Box2D, Box3D, CubicBezierSegment, CubicBezierSegment3D, LineSegment2D, LineSegment3D,
+        Point2D, QuadraticBezierSegment, QuadraticBezierSegment3D, Transform2D, Transform3D,
+    };
+    use crate::{point2, point3, vec2, vec3, Angle};
+
+    fn cubic_from(points: [(f64, f64); 4]) -> CubicBezierSegment<f64> {
+        let [from, ctrl1, ctrl2, to] = points;
+        CubicBezierSegment::new(from.into(), ctrl1.into(), ctrl2.into(), to.into())
+    }
+
+    fn cubic() -> CubicBezierSegment<f64> {
+        cubic_from([(0.0, 0.0), (1.0, 3.0), (4.0, -2.0), (5.0, 1.0)])
+    }
+
+    fn quadratic_3d() -> QuadraticBezierSegment3D<f64> {
+        QuadraticBezierSegment3D::new(point3(0.0, 0.0, 0.0), point3(1.0, 2.0, 3.0), point3(2.0, 0.0, 0.0))
+    }
+
+    fn sampled_bounds(sample: impl Fn(f64) -> Point2D<f64>) -> Box2D<f64> {
+        Box2D::from_points((0..=10000).map(|i| sample(i as f64 / 10000.0)))
+    }
+
+    #[test]
+    fn test_sample_and_derivatives() {
+        let q = QuadraticBezierSegment::new(point2(0.0, 0.0), point2(1.0, 2.0), point2(2.0, 0.0));
+        assert_eq!(q.sample(0.0), q.from);
+        assert_eq!(q.sample(1.0), q.to);
+        assert_eq!(q.sample(0.5), point2(1.0, 1.0));
+        assert_eq!(q.derivative(0.0), vec2(2.0, 4.0));
+        assert_eq!(q.derivative(0.5), vec2(2.0, 0.0));
+        assert_eq!(q.second_derivative(), vec2(0.0, -8.0));
+
+        let c = cubic();
+        assert_eq!(c.sample(0.0), c.from);
+        assert_eq!(c.sample(1.0), c.to);
+        assert_eq!(c.derivative(0.0), (c.ctrl1 - c.from) * 3.0);
+        assert_eq!(c.derivative(1.0), (c.to - c.ctrl2) * 3.0);
+
+        // Compare the derivatives with finite differences.
+        let h = 1e-6;
+        for &t in &[0.1, 0.5, 0.8] {
+            let d = (c.sample(t + h) - c.sample(t - h)) / (2.0 * h);
+            assert!(c.derivative(t).approx_eq_eps(&d, &vec2(1e-6, 1e-6)));
+            let dd = (c.derivative(t + h) - c.derivative(t - h)) / (2.0 * h);
+            assert!(c.second_derivative(t).approx_eq_eps(&dd, &vec2(1e-6, 1e-6)));
+        }
+
+        let elevated = q.to_cubic();
+        for &t in &[0.0, 0.3, 0.5, 1.0] {
+            assert!(elevated.sample(t).approx_eq(&q.sample(t)));
+        }
+    }
+
+    #[test]
+    fn test_split() {
+        let c = cubic();
+        let (a, b) = c.split(0.3);
+        assert_eq!(a.from, c.from);
+        assert_eq!(b.to, c.to);
+        assert_eq!(a.to, b.from);
+        for &t in &[0.0, 0.25, 0.5, 1.0] {
+            assert!(a.sample(t).approx_eq(&c.sample(t * 0.3)));
+            assert!(b.sample(t).approx_eq(&c.sample(0.3 + t * 0.7)));
+        }
+
+        let q = QuadraticBezierSegment::new(point2(0.0, 0.0), point2(1.0, 2.0), point2(2.0, 0.0));
+        let (a, b) = q.split(0.5);
+        assert!(a.sample(0.5).approx_eq(&q.sample(0.25)));
+        assert!(b.sample(0.5).approx_eq(&q.sample(0.75)));
+        assert_eq!(q.flip().sample(0.25), q.sample(0.75));
+    }
+
+    #[test]
+    fn test_bounding_box() {
+        let eps = point2(1e-6, 1e-6);
+
+        let q = QuadraticBezierSegment::new(point2(0.0, 0.0), point2(1.0, 2.0), point2(2.0, 0.0));
+        assert_eq!(q.bounding_box(), Box2D::new(point2(0.0, 0.0), point2(2.0, 1.0)));
+        assert_eq!(q.fast_bounding_box(), Box2D::new(point2(0.0, 0.0), point2(2.0, 2.0)));
+
+        let curves = [
+            cubic(),
+            cubic_from([(0.0, 0.0), (3.0, 1.0), (-1.0, 1.0), (2.0, 0.0)]),
+            // Degenerate cubics, for which the derivative is of a lower degree.
+            cubic_from([(0.0, 0.0), (1.0, 1.0), (2.0, 2.0), (3.0, 3.0)]),
+            cubic_from([(0.0, 0.0), (2.0, 2.0), (2.0, 2.0), (0.0, 0.0)]),
+        ];
+        for c in &curves {
+            let b = c.bounding_box();
+            let sampled = sampled_bounds(|t| c.sample(t));
+            assert!(b.min.approx_eq_eps(&sampled.min, &eps), "{:?} {:?}", b, sampled);
+            assert!(b.max.approx_eq_eps(&sampled.max, &eps), "{:?} {:?}", b, sampled);
+        }
+
+        let c3 = CubicBezierSegment3D::new(
+            point3(0.0, 0.0, 0.0),
+            point3(1.0, 3.0, -2.0),
+            point3(4.0, -2.0, 3.0),
+            point3(5.0, 1.0, 0.0),
+        );
+        let b = c3.bounding_box();
+        let sampled = Box3D::from_points((0..=10000).map(|i| c3.sample(i as f64 / 10000.0)));
+        assert!(b.min.approx_eq_eps(&sampled.min, &point3(1e-6, 1e-6, 1e-6)));
+        assert!(b.max.approx_eq_eps(&sampled.max, &point3(1e-6, 1e-6, 1e-6)));
+        assert!(b.max.z > 0.0 && b.min.z < 0.0);
+    }
+
+    #[test]
+    fn test_flattened() {
+        let c = cubic();
+        let tolerance = 0.01;
+
+        let points: Vec<_> = c.flattened(tolerance).collect();
+        assert!(points.len() > 2);
+        assert_eq!(*points.last().unwrap(), c.to);
+
+        // The points are evenly spaced in t, check the curve between each of them.
+        let n = points.len() as f64;
+        let mut from = c.from;
+        for (i, &to) in points.iter().enumerate() {
+            let segment = LineSegment2D::new(from, to);
+            for j in 0..=100 {
+                let p = c.sample((i as f64 + j as f64 / 100.0) / n);
+                assert!(segment.distance_to_point(p) <= tolerance);
+            }
+            from = to;
+        }
+
+        let line = QuadraticBezierSegment::new(point2(0.0, 0.0), point2(1.0, 1.0), point2(2.0, 2.0));
+        assert_eq!(line.flattened(0.1).collect::<Vec<_>>(), vec![line.to]);
+
+        let q3 = quadratic_3d();
+        let points: Vec<_> = q3.flattened(tolerance).collect();
+        let n = points.len() as f64;
+        let mut from = q3.from;
+        for (i, &to) in points.iter().enumerate() {
+            let segment = LineSegment3D::new(from, to);
+            for j in 0..=100 {
+                let p = q3.sample((i as f64 + j as f64 / 100.0) / n);
+                assert!(segment.distance_to_point(p) <= tolerance);
+            }
+            from = to;
+        }
+        assert_eq!(from, q3.to);
+    }
+
+    #[test]
+    fn test_transform() {
+        let c = cubic();
+        let transform = Transform2D::rotation(Angle::radians(0.5)).then_translate(vec2(1.0, 2.0));
+        let transformed = c.transform(&transform);
+        for &t in &[0.0, 0.3, 0.5, 1.0] {
+            assert!(transformed.sample(t).approx_eq(&transform.transform_point(c.sample(t))));
+        }
+
+        let q3 = quadratic_3d();
+        let transform = Transform3D::scale(2.0, 1.0, 0.5).then_translate(vec3(0.0, 0.0, 1.0));
+        let transformed = q3.transform(&transform).unwrap();
+        assert_eq!(transformed.sample(0.5), transform.transform_point3d(q3.sample(0.5)).unwrap());
+    }
+}
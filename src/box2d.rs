@@ -9,12 +9,16 @@
 
 use super::UnknownUnit;
 use crate::approxord::{max, min};
+use crate::fit::{Alignment, MeetOrSlice, ObjectFit};
+use crate::nine_slice::{AxisTiles, RepeatMode};
 use crate::num::*;
 use crate::point::{point2, Point2D};
 use crate::rect::Rect;
 use crate::scale::Scale;
 use crate::side_offsets::SideOffsets2D;
 use crate::size::Size2D;
+use crate::tile::TileAxis;
+use crate::transform2d::Transform2D;
 use crate::vector::{vec2, Vector2D};
 
 use num_traits::{NumCast, Float};
@@ -241,6 +245,41 @@ where
             max: point2(max(self.max.x, other.max.x), max(self.max.y, other.max.y)),
         }
     }
+
+    /// Returns the parts of this box which are not in the other one, as at most four
+    /// non-overlapping boxes.
+    ///
+    /// The pieces above and below the intersection span the whole width of this
+    /// box, and the pieces on its left and right span the height of the
+    /// intersection. Empty pieces are skipped, so nothing is returned if this box
+    /// is empty or contained in the other one.
+    ///
+    /// ```
+    /// use euclid::default::Box2D;
+    /// use euclid::point2;
+    ///
+    /// let a = Box2D::new(point2(0, 0), point2(10, 10));
+    /// let b = Box2D::new(point2(5, -5), point2(15, 5));
+    /// let pieces: Vec<_> = a.subtract(&b).collect();
+    /// assert_eq!(pieces, [
+    ///     Box2D::new(point2(0, 5), point2(10, 10)),
+    ///     Box2D::new(point2(0, 0), point2(5, 5)),
+    /// ]);
+    /// ```
+    pub fn subtract(&self, other: &Self) -> impl Iterator<Item = Self> {
+        let empty = Box2D::new(self.min, self.min);
+        let pieces = match self.intersection(other) {
+            Some(i) => [
+                Box2D::new(self.min, point2(self.max.x, i.min.y)),
+                Box2D::new(point2(self.min.x, i.max.y), self.max),
+                Box2D::new(point2(self.min.x, i.min.y), point2(i.min.x, i.max.y)),
+                Box2D::new(point2(i.max.x, i.min.y), point2(self.max.x, i.max.y)),
+            ],
+            None => [*self, empty, empty, empty],
+        };
+
+        IntoIterator::into_iter(pieces).filter(|b| !b.is_empty())
+    }
 }
 
 impl<T, U> Box2D<T, U>
@@ -327,6 +366,41 @@ where
             max: self.max + vec2(offsets.right, offsets.bottom),
         }
     }
+
+    /// Splits the box into nine boxes along the inner edges of the side offsets, like
+    /// the CSS `border-image-slice` property.
+    ///
+    /// The boxes are in row-major order from the minimum corner: top left, top, top
+    /// right, left, middle, right, bottom left, bottom and bottom right. The horizontal
+    /// and vertical offsets must not be larger than the original side length.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use euclid::default::{Box2D, SideOffsets2D};
+    /// use euclid::point2;
+    ///
+    /// let b = Box2D::new(point2(0, 0), point2(10, 10));
+    /// let slices = b.nine_slice(SideOffsets2D::new(1, 2, 3, 4));
+    /// assert_eq!(slices[0], Box2D::new(point2(0, 0), point2(4, 1)));
+    /// assert_eq!(slices[4], Box2D::new(point2(4, 1), point2(8, 7)));
+    /// assert_eq!(slices[8], Box2D::new(point2(8, 7), point2(10, 10)));
+    /// ```
+    pub fn nine_slice(&self, offsets: SideOffsets2D<T, U>) -> [Self; 9] {
+        let inner = self.inner_box(offsets);
+        let xs = [self.min.x, inner.min.x, inner.max.x, self.max.x];
+        let ys = [self.min.y, inner.min.y, inner.max.y, self.max.y];
+        let slice = |column: usize, row: usize| Box2D {
+            min: point2(xs[column], ys[row]),
+            max: point2(xs[column + 1], ys[row + 1]),
+        };
+
+        [
+            slice(0, 0), slice(1, 0), slice(2, 0),
+            slice(0, 1), slice(1, 1), slice(2, 1),
+            slice(0, 2), slice(1, 2), slice(2, 2),
+        ]
+    }
 }
 
 impl<T, U> Box2D<T, U>
@@ -606,6 +680,187 @@ impl<T: Float, U> Box2D<T, U> {
     pub fn is_finite(self) -> bool {
         self.min.is_finite() && self.max.is_finite()
     }
+
+    /// Fits content of the given size into this box, like the CSS `object-fit` and
+    /// `object-position` properties.
+    ///
+    /// Returns the box covered by the content, which can extend beyond this box with
+    /// `ObjectFit::Cover` and `ObjectFit::None`, and the transform from the content's
+    /// coordinates, with its origin at the minimum corner, to the ones of this box.
+    ///
+    /// The content size must not be empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use euclid::{Alignment, ObjectFit};
+    /// use euclid::default::{Box2D, Size2D};
+    /// use euclid::{point2, size2};
+    ///
+    /// let b = Box2D::new(point2(0.0, 0.0), point2(100.0, 100.0));
+    /// let image: Size2D<_> = size2(200.0, 100.0);
+    /// let (dest, transform) = b.fit_size(image, ObjectFit::Contain, Alignment::CENTER);
+    /// assert_eq!(dest, Box2D::new(point2(0.0, 25.0), point2(100.0, 75.0)));
+    /// assert_eq!(transform.transform_point(point2(200.0, 100.0)), point2(100.0, 75.0));
+    /// ```
+    pub fn fit_size<Src>(
+        &self,
+        size: Size2D<T, Src>,
+        fit: ObjectFit,
+        alignment: Alignment,
+    ) -> (Self, Transform2D<T, Src, U>) {
+        let available = self.size();
+        let (sx, sy) = fit.scale_factors(
+            (size.width, size.height),
+            (available.width, available.height),
+        );
+        let dest_size = Size2D::new(size.width * sx, size.height * sy);
+        let min = point2(
+            self.min.x + alignment.x.offset(available.width - dest_size.width),
+            self.min.y + alignment.y.offset(available.height - dest_size.height),
+        );
+        let dest = Box2D::from_origin_and_size(min, dest_size);
+        let zero = T::zero();
+        let transform = Transform2D::new(sx, zero, zero, sy, min.x, min.y);
+
+        (dest, transform)
+    }
+
+    /// Fits content of the given size into this box with a uniform scale, like the SVG
+    /// `preserveAspectRatio` attribute.
+    ///
+    /// Returns the box covered by the content and the scale from the content's
+    /// coordinates to the ones of this box. The content is then positioned by
+    /// translating it to the minimum corner of the returned box.
+    ///
+    /// For the SVG `none` value, use `fit_size` with `ObjectFit::Fill`.
+    ///
+    /// The content size must not be empty.
+    pub fn preserve_aspect_ratio<Src>(
+        &self,
+        size: Size2D<T, Src>,
+        alignment: Alignment,
+        meet_or_slice: MeetOrSlice,
+    ) -> (Self, Scale<T, Src, U>) {
+        let (dest, transform) = self.fit_size(size, meet_or_slice.into(), alignment);
+        (dest, Scale::new(transform.m11))
+    }
+
+    /// Maps the nine slices of this box onto the nine slices of a destination box, like
+    /// the CSS `border-image` properties.
+    ///
+    /// Yields pairs of source and destination boxes. The corners are stretched to
+    /// their destination, while the edges and the middle are tiled along the
+    /// horizontal and vertical axes as specified by the repeat modes, with tiles
+    /// clipped by the destination mapping to the matching part of the source. The
+    /// tiles of an edge are scaled like its corners, and the ones of the middle
+    /// like the top and left edges. Empty slices yield nothing.
+    pub fn nine_slice_mapping<Dst>(
+        &self,
+        offsets: SideOffsets2D<T, U>,
+        dest: &Box2D<T, Dst>,
+        dest_offsets: SideOffsets2D<T, Dst>,
+        repeat_x: RepeatMode,
+        repeat_y: RepeatMode,
+    ) -> impl Iterator<Item = (Self, Box2D<T, Dst>)> {
+        let src = self.nine_slice(offsets);
+        let dst = dest.nine_slice(dest_offsets);
+
+        // How much the slices of each side are scaled, falling back to the opposite
+        // side and then to no scaling when a side is empty.
+        let factor = |d: T, s: T| Some(d / s).filter(|f| *f > T::zero() && f.is_finite());
+        let top = factor(dest_offsets.top, offsets.top);
+        let bottom = factor(dest_offsets.bottom, offsets.bottom);
+        let left = factor(dest_offsets.left, offsets.left);
+        let right = factor(dest_offsets.right, offsets.right);
+        let x_factors = [top, top.or(bottom), bottom].map(|f| f.unwrap_or_else(T::one));
+        let y_factors = [left, left.or(right), right].map(|f| f.unwrap_or_else(T::one));
+        let middle = src[4].size();
+
+        (0..9).flat_map(move |i| {
+            let (column, row) = (i % 3, i / 3);
+            let (s, d) = (src[i], dst[i]);
+            let (x_mode, x_tile) = match column {
+                1 => (repeat_x, middle.width * x_factors[row]),
+                _ => (RepeatMode::Stretch, d.width()),
+            };
+            let (y_mode, y_tile) = match row {
+                1 => (repeat_y, middle.height * y_factors[column]),
+                _ => (RepeatMode::Stretch, d.height()),
+            };
+            let xs = AxisTiles::new(x_mode, (s.min.x, s.max.x), (d.min.x, d.max.x), x_tile);
+            let ys = AxisTiles::new(y_mode, (s.min.y, s.max.y), (d.min.y, d.max.y), y_tile);
+
+            (0..ys.count()).flat_map(move |y| {
+                let (src_y, dst_y) = ys.get(y);
+                (0..xs.count()).map(move |x| {
+                    let (src_x, dst_x) = xs.get(x);
+                    (
+                        Box2D::new(point2(src_x.0, src_y.0), point2(src_x.1, src_y.1)),
+                        Box2D::new(point2(dst_x.0, dst_y.0), point2(dst_x.1, dst_y.1)),
+                    )
+                })
+            })
+        })
+        .filter(|(_, d)| !d.is_empty())
+    }
+}
+
+impl<T, U> Box2D<T, U>
+where
+    T: Copy + NumCast + PartialOrd,
+{
+    /// Splits the box into tiles of the given size, starting at its minimum corner.
+    ///
+    /// Yields the coordinates of each tile in the grid of tiles and the part of the
+    /// tile inside of this box, row by row. Adjacent tiles share their edges
+    /// exactly, and the last tiles of each row and column are clipped by the box.
+    /// Nothing is yielded if the tile size isn't positive, and at most `i32::MAX`
+    /// tiles are yielded along each axis.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use euclid::default::{Box2D, Size2D};
+    /// use euclid::point2;
+    ///
+    /// let b = Box2D::new(point2(0, 0), point2(300, 100));
+    /// let tiles: Vec<_> = b.tiles(Size2D::new(256, 256)).collect();
+    /// assert_eq!(tiles, [
+    ///     (point2(0, 0), Box2D::new(point2(0, 0), point2(256, 100))),
+    ///     (point2(1, 0), Box2D::new(point2(256, 0), point2(300, 100))),
+    /// ]);
+    /// ```
+    pub fn tiles(
+        &self,
+        tile_size: Size2D<T, U>,
+    ) -> impl Iterator<Item = (Point2D<i32, UnknownUnit>, Self)> {
+        let x = TileAxis::new(self.min.x, self.max.x, tile_size.width);
+        let y = TileAxis::new(self.min.y, self.max.y, tile_size.height);
+        let columns = x.count();
+
+        (0..y.count()).flat_map(move |row| {
+            let (min_y, max_y) = y.tile(row);
+            (0..columns).map(move |column| {
+                let (min_x, max_x) = x.tile(column);
+                let tile = Box2D::new(point2(min_x, min_y), point2(max_x, max_y));
+                (point2(column, row), tile)
+            })
+        })
+    }
+
+    /// Returns the range of the coordinates of the tiles yielded by `tiles` which
+    /// overlap the given box, as a box whose maximum is exclusive.
+    ///
+    /// The range is empty if the given box doesn't overlap this one.
+    pub fn tile_range_for(&self, tile_size: Size2D<T, U>, rect: &Self) -> Box2D<i32, UnknownUnit> {
+        let x = TileAxis::new(self.min.x, self.max.x, tile_size.width);
+        let y = TileAxis::new(self.min.y, self.max.y, tile_size.height);
+        let (min_x, max_x) = x.range(rect.min.x, rect.max.x);
+        let (min_y, max_y) = y.range(rect.min.y, rect.max.y);
+
+        Box2D::new(point2(min_x, min_y), point2(max_x, max_y))
+    }
 }
 
 impl<T, U> Box2D<T, U>
@@ -900,4 +1155,220 @@ mod tests {
         assert_eq!(b.min, point2(1.0, 2.0));
         assert_eq!(b.size(), size2(5.0, 6.0));
     }
+
+    #[test]
+    fn test_subtract() {
+        let a = Box2D::new(point2(0, 0), point2(10, 10));
+
+        // A hole in the middle gives four pieces around it.
+        let hole = Box2D::new(point2(2, 3), point2(6, 8));
+        let pieces: Vec<_> = a.subtract(&hole).collect();
+        assert_eq!(pieces.len(), 4);
+        assert_eq!(pieces.iter().map(|b| b.area()).sum::<i32>(), 100 - 20);
+        for (i, p) in pieces.iter().enumerate() {
+            assert!(a.contains_box(p));
+            assert!(p.intersection(&hole).is_none());
+            for q in &pieces[i + 1..] {
+                assert!(p.intersection(q).is_none());
+            }
+        }
+
+        // Disjoint and touching boxes leave this one as it is.
+        let far = Box2D::new(point2(20, 20), point2(30, 30));
+        assert_eq!(a.subtract(&far).collect::<Vec<_>>(), [a]);
+        let touching = Box2D::new(point2(10, 0), point2(20, 10));
+        assert_eq!(a.subtract(&touching).collect::<Vec<_>>(), [a]);
+
+        // Covering boxes leave nothing.
+        assert_eq!(a.subtract(&a).count(), 0);
+        assert_eq!(hole.subtract(&a).count(), 0);
+        assert_eq!(Box2D::zero().subtract(&hole).count(), 0);
+
+        // Cutting a side gives a single piece.
+        let right = Box2D::new(point2(7, -5), point2(15, 15));
+        assert_eq!(
+            a.subtract(&right).collect::<Vec<_>>(),
+            [Box2D::new(point2(0, 0), point2(7, 10))]
+        );
+
+        let f = Box2D::new(point2(0.0, 0.0), point2(1.0, 1.0));
+        let half = Box2D::new(point2(0.5, 0.0), point2(2.0, 0.5));
+        assert_eq!(
+            f.subtract(&half).collect::<Vec<_>>(),
+            [
+                Box2D::new(point2(0.0, 0.5), point2(1.0, 1.0)),
+                Box2D::new(point2(0.0, 0.0), point2(0.5, 0.5)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_fit_size() {
+        use crate::default::Size2D;
+        use crate::{Align, Alignment, MeetOrSlice, ObjectFit};
+
+        let b = Box2D::new(point2(10.0, 20.0), point2(110.0, 70.0));
+        let wide: Size2D<f64> = size2(400.0, 100.0);
+        let tall: Size2D<f64> = size2(20.0, 40.0);
+        let fit = |size, fit, alignment| b.fit_size(size, fit, alignment).0;
+
+        assert_eq!(
+            fit(wide, ObjectFit::Contain, Alignment::CENTER),
+            Box2D::new(point2(10.0, 32.5), point2(110.0, 57.5))
+        );
+        assert_eq!(
+            fit(wide, ObjectFit::Contain, Alignment::BOTTOM_RIGHT),
+            Box2D::new(point2(10.0, 45.0), point2(110.0, 70.0))
+        );
+        assert_eq!(
+            fit(wide, ObjectFit::Cover, Alignment::LEFT),
+            Box2D::new(point2(10.0, 20.0), point2(210.0, 70.0))
+        );
+        assert_eq!(
+            fit(wide, ObjectFit::Cover, Alignment::RIGHT),
+            Box2D::new(point2(-90.0, 20.0), point2(110.0, 70.0))
+        );
+        assert_eq!(fit(wide, ObjectFit::Fill, Alignment::TOP_LEFT), b);
+        assert_eq!(
+            fit(tall, ObjectFit::None, Alignment::TOP),
+            Box2D::new(point2(50.0, 20.0), point2(70.0, 60.0))
+        );
+        assert_eq!(
+            fit(tall, ObjectFit::ScaleDown, Alignment::TOP),
+            fit(tall, ObjectFit::None, Alignment::TOP)
+        );
+        assert_eq!(
+            fit(wide, ObjectFit::ScaleDown, Alignment::TOP),
+            fit(wide, ObjectFit::Contain, Alignment::TOP)
+        );
+        assert_eq!(
+            fit(tall, ObjectFit::Contain, Alignment::new(Align::Max, Align::Min)),
+            Box2D::new(point2(85.0, 20.0), point2(110.0, 70.0))
+        );
+
+        // The transform maps the content onto the returned box.
+        for &fit in &[
+            ObjectFit::Contain,
+            ObjectFit::Cover,
+            ObjectFit::Fill,
+            ObjectFit::None,
+            ObjectFit::ScaleDown,
+        ] {
+            let (dest, transform) = b.fit_size(tall, fit, Alignment::BOTTOM);
+            assert_eq!(transform.transform_point(point2(0.0, 0.0)), dest.min);
+            assert_eq!(transform.transform_point(point2(20.0, 40.0)), dest.max);
+        }
+
+        let (dest, scale) = b.preserve_aspect_ratio(wide, Alignment::CENTER, MeetOrSlice::Meet);
+        assert_eq!(dest, fit(wide, ObjectFit::Contain, Alignment::CENTER));
+        assert_eq!(scale.get(), 0.25);
+        let (dest, scale) = b.preserve_aspect_ratio(wide, Alignment::TOP_LEFT, MeetOrSlice::Slice);
+        assert_eq!(dest, Box2D::new(point2(10.0, 20.0), point2(210.0, 70.0)));
+        assert_eq!(scale.get(), 0.5);
+    }
+
+    #[test]
+    fn test_nine_slice() {
+        use crate::RepeatMode;
+
+        let b = Box2D::new(point2(0, 0), point2(10, 20));
+        let slices = b.nine_slice(SideOffsets2D::new(1, 2, 3, 4));
+        assert_eq!(slices[1], Box2D::new(point2(4, 0), point2(8, 1)));
+        assert_eq!(slices[3], Box2D::new(point2(0, 1), point2(4, 17)));
+        assert_eq!(slices[5], Box2D::new(point2(8, 1), point2(10, 17)));
+        assert_eq!(slices[7], Box2D::new(point2(4, 17), point2(8, 20)));
+        assert_eq!(slices.iter().map(|s| s.area()).sum::<i32>(), b.area());
+
+        let src = Box2D::new(point2(0.0, 0.0), point2(30.0, 30.0));
+        let offsets = SideOffsets2D::new_all_same(10.0);
+        let dst = Box2D::new(point2(0.0, 0.0), point2(100.0, 100.0));
+        let dst_offsets = SideOffsets2D::new_all_same(20.0);
+        let mapping = |dst: &Box2D<f64>, mode| {
+            src.nine_slice_mapping(offsets, dst, dst_offsets, mode, mode)
+                .collect::<Vec<_>>()
+        };
+
+        let stretched = mapping(&dst, RepeatMode::Stretch);
+        let expected = src.nine_slice(offsets).iter().copied()
+            .zip(dst.nine_slice(dst_offsets).iter().copied())
+            .collect::<Vec<_>>();
+        assert_eq!(stretched, expected);
+
+        // The middle is 60 long, which holds three tiles of the scaled length 20.
+        for &mode in &[RepeatMode::Repeat, RepeatMode::Round, RepeatMode::Space] {
+            let tiles = mapping(&dst, mode);
+            assert_eq!(tiles.len(), 25);
+            assert_eq!(tiles.iter().map(|(_, d)| d.area()).sum::<f64>(), dst.area());
+            assert!(tiles.iter().all(|(s, d)| d.width() == 20.0 && s.width() == 10.0));
+        }
+
+        // With a middle of 50, repeated tiles are clipped on both sides.
+        let smaller = Box2D::new(point2(0.0, 0.0), point2(90.0, 90.0));
+        let tiles = mapping(&smaller, RepeatMode::Repeat);
+        assert_eq!(tiles.len(), 25);
+        assert_eq!(tiles.iter().map(|(_, d)| d.area()).sum::<f64>(), smaller.area());
+        assert_eq!(
+            tiles[1],
+            (
+                Box2D::new(point2(12.5, 0.0), point2(20.0, 10.0)),
+                Box2D::new(point2(20.0, 0.0), point2(35.0, 20.0)),
+            )
+        );
+
+        // With a middle of 46, rounded tiles are stretched to 23, and spaced ones
+        // leave gaps.
+        let narrow = Box2D::new(point2(0.0, 0.0), point2(86.0, 86.0));
+        let tiles = mapping(&narrow, RepeatMode::Round);
+        assert_eq!(tiles.len(), 16);
+        assert_eq!(tiles[1].1, Box2D::new(point2(20.0, 0.0), point2(43.0, 20.0)));
+        assert_eq!(tiles.iter().map(|(_, d)| d.area()).sum::<f64>(), narrow.area());
+        let tiles = mapping(&narrow, RepeatMode::Space);
+        assert_eq!(tiles.len(), 16);
+        assert_eq!(tiles[1].1, Box2D::new(point2(22.0, 0.0), point2(42.0, 20.0)));
+        assert!(tiles.iter().map(|(_, d)| d.area()).sum::<f64>() < narrow.area());
+    }
+
+    #[test]
+    fn test_tiles() {
+        let b = Box2D::new(point2(-10, 0), point2(15, 20));
+        let tiles: Vec<_> = b.tiles(size2(10, 10)).collect();
+        assert_eq!(tiles.len(), 6);
+        assert_eq!(tiles[0], (point2(0, 0), Box2D::new(point2(-10, 0), point2(0, 10))));
+        assert_eq!(tiles[2], (point2(2, 0), Box2D::new(point2(10, 0), point2(15, 10))));
+        assert_eq!(tiles[5], (point2(2, 1), Box2D::new(point2(10, 10), point2(15, 20))));
+        assert_eq!(tiles.iter().map(|(_, t)| t.area()).sum::<i32>(), b.area());
+
+        let range = b.tile_range_for(size2(10, 10), &Box2D::new(point2(0, 5), point2(1, 10)));
+        assert_eq!(range, Box2D::new(point2(1, 0), point2(2, 1)));
+        let range = b.tile_range_for(size2(10, 10), &Box2D::new(point2(-20, -20), point2(40, 40)));
+        assert_eq!(range, Box2D::new(point2(0, 0), point2(3, 2)));
+        let range = b.tile_range_for(size2(10, 10), &Box2D::new(point2(15, 0), point2(40, 40)));
+        assert!(range.is_empty());
+        assert_eq!(Box2D::zero().tiles(size2(10, 10)).count(), 0);
+        assert_eq!(b.tiles(size2(0, 10)).count(), 0);
+
+        // Float tiles share their edges, and agree with the range of tiles.
+        let f = Box2D::new(point2(0.0, 0.0), point2(1.0, 0.25));
+        let tile_size = size2(0.1, 0.1);
+        let tiles: Vec<_> = f.tiles(tile_size).collect();
+        assert_eq!(tiles.len(), 30);
+        for pair in tiles.windows(2) {
+            if pair[0].0.y == pair[1].0.y {
+                assert_eq!(pair[0].1.max.x, pair[1].1.min.x);
+            }
+        }
+        assert_eq!(tiles[29].1.max, f.max);
+        let rect = Box2D::new(point2(0.3, 0.05), point2(0.7, 0.2));
+        let range = f.tile_range_for(tile_size, &rect);
+        for (p, tile) in &tiles {
+            assert_eq!(range.contains(*p), tile.intersects(&rect), "{:?}", p);
+        }
+
+        // Tiles past i32::MAX are dropped.
+        let huge = Box2D::new(point2(0.0, 0.0), point2(1e12, 10.0));
+        let mut tiles = huge.tiles(size2(256.0, 256.0));
+        assert_eq!(tiles.next().unwrap().1.max, point2(256.0, 10.0));
+        let range = huge.tile_range_for(size2(256.0, 256.0), &huge);
+        assert_eq!(range.max, point2(i32::MAX, 1));
+    }
 }
@@ -13,6 +13,7 @@ use crate::num::*;
 use crate::point::{point3, Point3D};
 use crate::scale::Scale;
 use crate::size::Size3D;
+use crate::tile::TileAxis;
 use crate::vector::Vector3D;
 
 use num_traits::{NumCast, Float};
@@ -216,6 +217,33 @@ where
             ),
         )
     }
+
+    /// Returns the parts of this box which are not in the other one, as at most six
+    /// non-overlapping boxes.
+    ///
+    /// The pieces in front of and behind the intersection along the z axis span
+    /// the whole of this box in x and y, the pieces above and below it span the
+    /// depth of the intersection, and the pieces on its left and right span both
+    /// its height and depth. Empty pieces are skipped.
+    pub fn subtract(&self, other: &Self) -> impl Iterator<Item = Self> {
+        let empty = Box3D::new(self.min, self.min);
+        let pieces = match self.intersection(other) {
+            Some(i) => {
+                let (a, b) = (self.min, self.max);
+                [
+                    Box3D::new(a, point3(b.x, b.y, i.min.z)),
+                    Box3D::new(point3(a.x, a.y, i.max.z), b),
+                    Box3D::new(point3(a.x, a.y, i.min.z), point3(b.x, i.min.y, i.max.z)),
+                    Box3D::new(point3(a.x, i.max.y, i.min.z), point3(b.x, b.y, i.max.z)),
+                    Box3D::new(point3(a.x, i.min.y, i.min.z), point3(i.min.x, i.max.y, i.max.z)),
+                    Box3D::new(point3(i.max.x, i.min.y, i.min.z), point3(b.x, i.max.y, i.max.z)),
+                ]
+            }
+            None => [*self, empty, empty, empty, empty, empty],
+        };
+
+        IntoIterator::into_iter(pieces).filter(|b| !b.is_empty())
+    }
 }
 
 impl<T, U> Box3D<T, U>
@@ -593,6 +621,58 @@ impl<T: Float, U> Box3D<T, U> {
     }
 }
 
+impl<T, U> Box3D<T, U>
+where
+    T: Copy + NumCast + PartialOrd,
+{
+    /// Splits the box into tiles of the given size, starting at its minimum corner.
+    ///
+    /// Yields the coordinates of each tile in the grid of tiles and the part of the
+    /// tile inside of this box, in x, then y, then z order. Adjacent tiles share
+    /// their faces exactly, and the last tiles along each axis are clipped by the
+    /// box. Nothing is yielded if the tile size isn't positive, and at most
+    /// `i32::MAX` tiles are yielded along each axis.
+    pub fn tiles(
+        &self,
+        tile_size: Size3D<T, U>,
+    ) -> impl Iterator<Item = (Point3D<i32, UnknownUnit>, Self)> {
+        let x = TileAxis::new(self.min.x, self.max.x, tile_size.width);
+        let y = TileAxis::new(self.min.y, self.max.y, tile_size.height);
+        let z = TileAxis::new(self.min.z, self.max.z, tile_size.depth);
+        let (columns, rows) = (x.count(), y.count());
+
+        (0..z.count()).flat_map(move |layer| {
+            let (min_z, max_z) = z.tile(layer);
+            (0..rows).flat_map(move |row| {
+                let (min_y, max_y) = y.tile(row);
+                (0..columns).map(move |column| {
+                    let (min_x, max_x) = x.tile(column);
+                    let tile = Box3D::new(
+                        point3(min_x, min_y, min_z),
+                        point3(max_x, max_y, max_z),
+                    );
+                    (point3(column, row, layer), tile)
+                })
+            })
+        })
+    }
+
+    /// Returns the range of the coordinates of the tiles yielded by `tiles` which
+    /// overlap the given box, as a box whose maximum is exclusive.
+    ///
+    /// The range is empty if the given box doesn't overlap this one.
+    pub fn tile_range_for(&self, tile_size: Size3D<T, U>, rect: &Self) -> Box3D<i32, UnknownUnit> {
+        let x = TileAxis::new(self.min.x, self.max.x, tile_size.width);
+        let y = TileAxis::new(self.min.y, self.max.y, tile_size.height);
+        let z = TileAxis::new(self.min.z, self.max.z, tile_size.depth);
+        let (min_x, max_x) = x.range(rect.min.x, rect.max.x);
+        let (min_y, max_y) = y.range(rect.min.y, rect.max.y);
+        let (min_z, max_z) = z.range(rect.min.z, rect.max.z);
+
+        Box3D::new(point3(min_x, min_y, min_z), point3(max_x, max_y, max_z))
+    }
+}
+
 impl<T, U> Box3D<T, U>
 where
     T: Round,
@@ -928,4 +1008,54 @@ mod tests {
         assert!(Box3D { min: point3(1.0, -2.0, 1.0), max: point3(0.0, NAN, 5.0) }.is_empty());
         assert!(Box3D { min: point3(1.0, -2.0, 1.0), max: point3(0.0, 1.0, NAN) }.is_empty());
     }
+
+    #[test]
+    fn test_subtract() {
+        let a = Box3D::new(point3(0, 0, 0), point3(10, 10, 10));
+
+        let hole = Box3D::new(point3(2, 3, 4), point3(6, 8, 9));
+        let pieces: Vec<_> = a.subtract(&hole).collect();
+        assert_eq!(pieces.len(), 6);
+        assert_eq!(pieces.iter().map(|b| b.volume()).sum::<i32>(), 1000 - 4 * 5 * 5);
+        for (i, p) in pieces.iter().enumerate() {
+            assert!(a.contains_box(p));
+            assert!(p.intersection(&hole).is_none());
+            for q in &pieces[i + 1..] {
+                assert!(p.intersection(q).is_none());
+            }
+        }
+
+        let far = Box3D::new(point3(20, 0, 0), point3(30, 10, 10));
+        assert_eq!(a.subtract(&far).collect::<Vec<_>>(), [a]);
+        assert_eq!(a.subtract(&a).count(), 0);
+
+        let front = Box3D::new(point3(-1, -1, -1), point3(11, 11, 3));
+        assert_eq!(
+            a.subtract(&front).collect::<Vec<_>>(),
+            [Box3D::new(point3(0, 0, 3), point3(10, 10, 10))]
+        );
+    }
+
+    #[test]
+    fn test_tiles() {
+        let b = Box3D::new(point3(0, 0, 0), point3(20, 10, 15));
+        let tiles: Vec<_> = b.tiles(size3(8, 8, 8)).collect();
+        assert_eq!(tiles.len(), 12);
+        assert_eq!(
+            tiles[0],
+            (point3(0, 0, 0), Box3D::new(point3(0, 0, 0), point3(8, 8, 8)))
+        );
+        assert_eq!(
+            tiles[11],
+            (point3(2, 1, 1), Box3D::new(point3(16, 8, 8), point3(20, 10, 15)))
+        );
+        assert_eq!(tiles.iter().map(|(_, t)| t.volume()).sum::<i32>(), b.volume());
+
+        let rect = Box3D::new(point3(8, 7, 8), point3(9, 9, 9));
+        let range = b.tile_range_for(size3(8, 8, 8), &rect);
+        assert_eq!(range, Box3D::new(point3(1, 0, 1), point3(2, 2, 2)));
+        for (p, tile) in &tiles {
+            assert_eq!(range.contains(*p), tile.intersects(&rect));
+        }
+    }
 }
@@ -0,0 +1,418 @@
+// Copyright 2013 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::UnknownUnit;
+use crate::approxeq::ApproxEq;
+use crate::box2d::Box2D;
+use crate::num::*;
+use crate::point::Point2D;
+use crate::ray::Ray2D;
+use crate::vector::{vec2, Vector2D};
+
+use num_traits::real::Real;
+use num_traits::NumCast;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "bytemuck")]
+use bytemuck::{Zeroable, Pod};
+
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::{Add, Mul, Sub};
+
+/// A circle, represented by its center and its radius.
+///
+/// Circles are closed: the points on the circle itself are considered to be inside.
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Serialize", deserialize = "T: Deserialize<'de>"))
+)]
+pub struct Circle<T, U> {
+    pub center: Point2D<T, U>,
+    pub radius: T,
+}
+
+impl<T: Hash, U> Hash for Circle<T, U> {
+    fn hash<H: Hasher>(&self, h: &mut H) {
+        self.center.hash(h);
+        self.radius.hash(h);
+    }
+}
+
+impl<T: Copy, U> Copy for Circle<T, U> {}
+
+impl<T: Clone, U> Clone for Circle<T, U> {
+    fn clone(&self) -> Self {
+        Self::new(self.center.clone(), self.radius.clone())
+    }
+}
+
+impl<T: PartialEq, U> PartialEq for Circle<T, U> {
+    fn eq(&self, other: &Self) -> bool {
+        self.center.eq(&other.center) && self.radius.eq(&other.radius)
+    }
+}
+
+impl<T: Eq, U> Eq for Circle<T, U> {}
+
+impl<T: fmt::Debug, U> fmt::Debug for Circle<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Circle")
+            .field(&self.center)
+            .field(&self.radius)
+            .finish()
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, T, U> arbitrary::Arbitrary<'a> for Circle<T, U>
+where
+    T: arbitrary::Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self>
+    {
+        let (center, radius) = arbitrary::Arbitrary::arbitrary(u)?;
+        Ok(Circle { center, radius })
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: Zeroable, U> Zeroable for Circle<T, U> {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: Pod, U: 'static> Pod for Circle<T, U> {}
+
+impl<T, U> Circle<T, U> {
+    /// Constructor.
+    #[inline]
+    pub const fn new(center: Point2D<T, U>, radius: T) -> Self {
+        Circle { center, radius }
+    }
+}
+
+impl<T: Copy, U> Circle<T, U> {
+    /// Drop the units, preserving only the numeric value.
+    #[inline]
+    pub fn to_untyped(&self) -> Circle<T, UnknownUnit> {
+        Circle::new(self.center.to_untyped(), self.radius)
+    }
+
+    /// Tag a unitless value with units.
+    #[inline]
+    pub fn from_untyped(c: &Circle<T, UnknownUnit>) -> Self {
+        Circle::new(Point2D::from_untyped(c.center), c.radius)
+    }
+
+    /// Cast the unit
+    #[inline]
+    pub fn cast_unit<V>(&self) -> Circle<T, V> {
+        Circle::new(self.center.cast_unit(), self.radius)
+    }
+}
+
+impl<T, U> Circle<T, U>
+where
+    T: Copy + Zero + PartialOrd + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    /// Returns `true` if the point is inside of the circle or on its boundary.
+    #[inline]
+    pub fn contains(&self, p: Point2D<T, U>) -> bool {
+        (p - self.center).square_length() <= self.radius * self.radius
+    }
+
+    /// Returns `true` if the two circles overlap or touch.
+    #[inline]
+    pub fn intersects(&self, other: &Self) -> bool {
+        let r = self.radius + other.radius;
+        (other.center - self.center).square_length() <= r * r
+    }
+
+    /// Returns `true` if the circle and the box overlap or touch.
+    ///
+    /// Empty boxes never intersect.
+    pub fn intersects_box(&self, b: &Box2D<T, U>) -> bool {
+        if b.is_empty() {
+            return false;
+        }
+
+        self.contains(self.center.clamp(b.min, b.max))
+    }
+
+    /// Returns the smallest box containing the circle.
+    #[inline]
+    pub fn bounding_box(&self) -> Box2D<T, U> {
+        let r = vec2(self.radius, self.radius);
+        Box2D::new(self.center - r, self.center + r)
+    }
+}
+
+impl<T: Real, U> Circle<T, U> {
+    /// Computes the parameter range `(t_enter, t_exit)` over which the ray is inside
+    /// of the circle, if the ray hits it.
+    ///
+    /// `t_enter` is zero if the origin of the ray is inside of the circle.
+    pub fn intersect_ray(&self, ray: &Ray2D<T, U>) -> Option<(T, T)> {
+        let zero = T::zero();
+        let a = ray.direction.square_length();
+        if a == zero {
+            return None;
+        }
+
+        let oc = ray.origin - self.center;
+        let b = ray.direction.dot(oc);
+        let c = oc.square_length() - self.radius * self.radius;
+        let discriminant = b * b - a * c;
+        if discriminant < zero {
+            return None;
+        }
+
+        let sqrt = discriminant.sqrt();
+        let t_exit = (-b + sqrt) / a;
+        if t_exit < zero {
+            return None;
+        }
+
+        Some((((-b - sqrt) / a).max(zero), t_exit))
+    }
+}
+
+impl<T: Real + ApproxEq<T>, U> Circle<T, U> {
+    /// Returns the smallest circle containing all of the points, or `None` if there
+    /// are no points.
+    ///
+    /// This uses the iterative form of Welzl's algorithm, which runs in expected linear
+    /// time if the points are in a random order. Inputs in a pathological order (for
+    /// example sorted along a convex curve) should be shuffled first.
+    pub fn minimal_enclosing(points: &[Point2D<T, U>]) -> Option<Self> {
+        let (first, rest) = points.split_first()?;
+        let mut circle = Circle::new(*first, T::zero());
+
+        for (i, &p) in rest.iter().enumerate() {
+            if circle.contains_approx(p) {
+                continue;
+            }
+
+            // p is on the boundary of the minimal circle of points[..i + 2].
+            circle = Circle::new(p, T::zero());
+            for (j, &q) in points[..i + 1].iter().enumerate() {
+                if circle.contains_approx(q) {
+                    continue;
+                }
+
+                // p and q are on the boundary.
+                circle = Circle::from_diameter(p, q);
+                for &r in &points[..j] {
+                    if !circle.contains_approx(r) {
+                        circle = Circle::from_boundary_points(p, q, r);
+                    }
+                }
+            }
+        }
+
+        Some(circle)
+    }
+
+    /// Like `contains`, with some tolerance for the points computed to be on the circle.
+    fn contains_approx(&self, p: Point2D<T, U>) -> bool {
+        let eps = T::approx_epsilon();
+        (p - self.center).length() <= self.radius + eps * self.radius.max(T::one())
+    }
+
+    /// The smallest circle that has both points on its boundary.
+    fn from_diameter(a: Point2D<T, U>, b: Point2D<T, U>) -> Self {
+        let center = a.lerp(b, T::one() / (T::one() + T::one()));
+        Circle::new(center, (a - center).length().max((b - center).length()))
+    }
+
+    /// The smallest circle that has all three points on its boundary, or the smallest
+    /// one containing them if they are aligned.
+    fn from_boundary_points(a: Point2D<T, U>, b: Point2D<T, U>, c: Point2D<T, U>) -> Self {
+        let u = b - a;
+        let v = c - a;
+        let d = (u.x * v.y - u.y * v.x) * (T::one() + T::one());
+
+        let scale = u.square_length().max(v.square_length());
+        if d.abs() <= T::epsilon() * scale {
+            // Aligned points, the two furthest apart points define the circle.
+            let candidates = [(a, b), (a, c), (b, c)];
+            let mut best = (a, b);
+            for &(p, q) in &candidates[1..] {
+                if (q - p).square_length() > (best.1 - best.0).square_length() {
+                    best = (p, q);
+                }
+            }
+            return Circle::from_diameter(best.0, best.1);
+        }
+
+        let (uu, vv) = (u.square_length(), v.square_length());
+        let offset: Vector2D<T, U> = vec2((v.y * uu - u.y * vv) / d, (u.x * vv - v.x * uu) / d);
+        let center = a + offset;
+        let radius = offset
+            .length()
+            .max((b - center).length())
+            .max((c - center).length());
+
+        Circle::new(center, radius)
+    }
+}
+
+impl<T: NumCast + Copy, U> Circle<T, U> {
+    /// Cast from one numeric representation to another, preserving the units.
+    #[inline]
+    pub fn cast<NewT: NumCast>(&self) -> Circle<NewT, U> {
+        Circle::new(self.center.cast(), NumCast::from(self.radius).unwrap())
+    }
+
+    /// Fallible cast from one numeric representation to another, preserving the units.
+    pub fn try_cast<NewT: NumCast>(&self) -> Option<Circle<NewT, U>> {
+        match (self.center.try_cast(), NumCast::from(self.radius)) {
+            (Some(center), Some(radius)) => Some(Circle::new(center, radius)),
+            _ => None,
+        }
+    }
+
+    // Convenience functions for common casts
+
+    /// Cast into an `f32` circle.
+    #[inline]
+    pub fn to_f32(&self) -> Circle<f32, U> {
+        self.cast()
+    }
+
+    /// Cast into an `f64` circle.
+    #[inline]
+    pub fn to_f64(&self) -> Circle<f64, U> {
+        self.cast()
+    }
+}
+
+impl<T: ApproxEq<T>, U> ApproxEq<T> for Circle<T, U> {
+    #[inline]
+    fn approx_epsilon() -> T {
+        T::approx_epsilon()
+    }
+
+    #[inline]
+    fn approx_eq_eps(&self, other: &Self, eps: &T) -> bool {
+        self.center.x.approx_eq_eps(&other.center.x, eps)
+            && self.center.y.approx_eq_eps(&other.center.y, eps)
+            && self.radius.approx_eq_eps(&other.radius, eps)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::approxeq::ApproxEq;
+    use crate::default::{Box2D, Circle, Point2D, Ray2D};
+    use crate::{point2, vec2};
+
+    #[test]
+    fn test_contains_and_intersects() {
+        let c = Circle::new(point2(1.0, 1.0), 2.0);
+        assert!(c.contains(point2(1.0, 1.0)));
+        assert!(c.contains(point2(3.0, 1.0)));
+        assert!(!c.contains(point2(3.0, 3.0)));
+
+        assert!(c.intersects(&Circle::new(point2(5.0, 1.0), 2.0)));
+        assert!(!c.intersects(&Circle::new(point2(5.0, 1.0), 1.5)));
+
+        assert_eq!(c.bounding_box(), Box2D::new(point2(-1.0, -1.0), point2(3.0, 3.0)));
+
+        assert!(c.intersects_box(&Box2D::new(point2(2.0, 2.0), point2(4.0, 4.0))));
+        assert!(c.intersects_box(&Box2D::new(point2(-5.0, -5.0), point2(5.0, 5.0))));
+        // The box overlaps the bounding box but not the circle.
+        assert!(!c.intersects_box(&Box2D::new(point2(2.5, 2.5), point2(4.0, 4.0))));
+        assert!(!c.intersects_box(&Box2D::new(point2(2.0, 2.0), point2(1.0, 1.0))));
+        assert!(!c.intersects_box(&Box2D::new(c.center, c.center)));
+    }
+
+    #[test]
+    fn test_intersect_ray() {
+        let c = Circle::new(point2(5.0, 0.0), 2.0);
+        assert_eq!(c.intersect_ray(&Ray2D::new(point2(0.0, 0.0), vec2(1.0, 0.0))), Some((3.0, 7.0)));
+        assert_eq!(c.intersect_ray(&Ray2D::new(point2(0.0, 0.0), vec2(2.0, 0.0))), Some((1.5, 3.5)));
+        assert_eq!(c.intersect_ray(&Ray2D::new(point2(5.0, 0.0), vec2(0.0, 1.0))), Some((0.0, 2.0)));
+        assert_eq!(c.intersect_ray(&Ray2D::new(point2(0.0, 0.0), vec2(-1.0, 0.0))), None);
+        assert_eq!(c.intersect_ray(&Ray2D::new(point2(0.0, 3.0), vec2(1.0, 0.0))), None);
+        let (t0, t1) = c.intersect_ray(&Ray2D::new(point2(0.0, 2.0), vec2(1.0, 0.0))).unwrap();
+        assert_eq!((t0, t1), (5.0, 5.0));
+    }
+
+    fn check_minimal_enclosing(points: &[Point2D<f64>]) {
+        let circle = Circle::minimal_enclosing(points).unwrap();
+        for p in points {
+            assert!((*p - circle.center).length() <= circle.radius + 1e-9);
+        }
+
+        // Compare with the brute force solution.
+        let mut best: Option<Circle<f64>> = None;
+        let mut consider = |c: Circle<f64>| {
+            if points.iter().all(|p| (*p - c.center).length() <= c.radius + 1e-9)
+                && best.map(|b| b.radius).unwrap_or(f64::INFINITY) > c.radius
+            {
+                best = Some(c);
+            }
+        };
+        for (i, &a) in points.iter().enumerate() {
+            consider(Circle::new(a, 0.0));
+            for (j, &b) in points[..i].iter().enumerate() {
+                consider(Circle::from_diameter(a, b));
+                for &c in &points[..j] {
+                    consider(Circle::from_boundary_points(a, b, c));
+                }
+            }
+        }
+
+        let best = best.unwrap();
+        assert!(circle.radius.approx_eq(&best.radius), "{:?} {:?}", circle, best);
+        assert!(circle.center.approx_eq(&best.center), "{:?} {:?}", circle, best);
+    }
+
+    #[test]
+    fn test_minimal_enclosing() {
+        assert_eq!(Circle::<f64>::minimal_enclosing(&[]), None);
+        assert_eq!(
+            Circle::minimal_enclosing(&[point2(1.0, 2.0)]),
+            Some(Circle::new(point2(1.0, 2.0), 0.0))
+        );
+
+        check_minimal_enclosing(&[point2(0.0, 0.0), point2(2.0, 0.0)]);
+        check_minimal_enclosing(&[point2(0.0, 0.0), point2(2.0, 0.0), point2(1.0, 0.5)]);
+        // Obtuse triangle: the circle is defined by the longest edge.
+        check_minimal_enclosing(&[point2(0.0, 0.0), point2(4.0, 0.0), point2(1.0, 1.0)]);
+        // Acute triangle.
+        check_minimal_enclosing(&[point2(0.0, 0.0), point2(4.0, 0.0), point2(2.0, 3.0)]);
+        // Aligned and duplicate points.
+        check_minimal_enclosing(&[
+            point2(0.0, 0.0),
+            point2(1.0, 1.0),
+            point2(3.0, 3.0),
+            point2(1.0, 1.0),
+            point2(-2.0, -2.0),
+        ]);
+        // Points on a circle.
+        let points: Vec<_> = (0..12)
+            .map(|i| {
+                let a = i as f64 * 0.5;
+                point2(3.0 + 2.0 * a.cos(), -1.0 + 2.0 * a.sin())
+            })
+            .collect();
+        check_minimal_enclosing(&points);
+        // Pseudo-random points.
+        let mut seed = 12345u32;
+        let mut next = || {
+            seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+            (seed >> 8) as f64 / (1 << 24) as f64 * 10.0
+        };
+        let points: Vec<_> = (0..30).map(|_| point2(next(), next())).collect();
+        check_minimal_enclosing(&points);
+    }
+}
@@ -0,0 +1,489 @@
+// Copyright 2013 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::UnknownUnit;
+use crate::approxeq::ApproxEq;
+use crate::angle::Angle;
+use crate::homogen::HomogeneousVector;
+use crate::rotation::Rotation3D;
+use crate::transform2d::Transform2D;
+use crate::transform3d::Transform3D;
+use crate::vector::{Vector2D, Vector3D};
+
+use num_traits::real::Real;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use core::fmt;
+use core::marker::PhantomData;
+
+/// The components of a 3d transform, as computed by [`Transform3D::decompose`].
+///
+/// The decomposition follows the [CSS Transforms Level 2] specification: in row-vector
+/// notation the transform is `Scale * Skew * Rotation * Translation * Perspective`,
+/// i.e. points are first scaled, then skewed, rotated, translated and finally projected.
+///
+/// [`Transform3D::decompose`]: struct.Transform3D.html#method.decompose
+/// [CSS Transforms Level 2]: https://drafts.csswg.org/css-transforms-2/#decomposing-a-3d-matrix
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Serialize", deserialize = "T: Deserialize<'de>"))
+)]
+pub struct Decomposed3D<T, Src, Dst> {
+    /// The scale factors along each axis.
+    ///
+    /// A reflection is represented by negating all three scale factors.
+    pub scale: Vector3D<T, UnknownUnit>,
+    /// The shear factors, respectively `XY` in `x`, `XZ` in `y` and `YZ` in `z`.
+    pub skew: Vector3D<T, UnknownUnit>,
+    pub rotation: Rotation3D<T, Src, Dst>,
+    pub translation: Vector3D<T, Dst>,
+    /// The last column of the matrix, `(m14, m24, m34, m44)` for a transform that
+    /// only has a perspective component.
+    pub perspective: HomogeneousVector<T, UnknownUnit>,
+}
+
+impl<T: Copy, Src, Dst> Copy for Decomposed3D<T, Src, Dst> {}
+
+impl<T: Clone, Src, Dst> Clone for Decomposed3D<T, Src, Dst> {
+    fn clone(&self) -> Self {
+        Decomposed3D {
+            scale: self.scale.clone(),
+            skew: self.skew.clone(),
+            rotation: self.rotation.clone(),
+            translation: self.translation.clone(),
+            perspective: self.perspective.clone(),
+        }
+    }
+}
+
+impl<T: PartialEq, Src, Dst> PartialEq for Decomposed3D<T, Src, Dst> {
+    fn eq(&self, other: &Self) -> bool {
+        self.scale == other.scale
+            && self.skew == other.skew
+            && self.rotation == other.rotation
+            && self.translation == other.translation
+            && self.perspective == other.perspective
+    }
+}
+
+impl<T: fmt::Debug, Src, Dst> fmt::Debug for Decomposed3D<T, Src, Dst> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Decomposed3D")
+            .field("scale", &self.scale)
+            .field("skew", &self.skew)
+            .field("rotation", &self.rotation)
+            .field("translation", &self.translation)
+            .field("perspective", &self.perspective)
+            .finish()
+    }
+}
+
+impl<T: Real, Src, Dst> Decomposed3D<T, Src, Dst> {
+    /// The decomposition of the identity transform.
+    pub fn identity() -> Self {
+        let zero = T::zero();
+        let one = T::one();
+        Decomposed3D {
+            scale: Vector3D::new(one, one, one),
+            skew: Vector3D::zero(),
+            rotation: Rotation3D::identity(),
+            translation: Vector3D::zero(),
+            perspective: HomogeneousVector::new(zero, zero, zero, one),
+        }
+    }
+}
+
+impl<T: Real + ApproxEq<T>, Src, Dst> Decomposed3D<T, Src, Dst> {
+    /// Interpolates between two decompositions, as described in the
+    /// [CSS Transforms Level 2] specification.
+    ///
+    /// The rotations are interpolated with a spherical linear interpolation and all
+    /// of the other components are interpolated linearly.
+    ///
+    /// [CSS Transforms Level 2]: https://drafts.csswg.org/css-transforms-2/#interpolation-of-decomposed-3d-matrix-values
+    pub fn lerp(&self, other: &Self, t: T) -> Self {
+        let one_t = T::one() - t;
+        let (p1, p2) = (self.perspective, other.perspective);
+        Decomposed3D {
+            scale: self.scale.lerp(other.scale, t),
+            skew: self.skew.lerp(other.skew, t),
+            rotation: self.rotation.normalize().slerp(&other.rotation.normalize(), t),
+            translation: self.translation.lerp(other.translation, t),
+            perspective: HomogeneousVector::new(
+                p1.x * one_t + p2.x * t,
+                p1.y * one_t + p2.y * t,
+                p1.z * one_t + p2.z * t,
+                p1.w * one_t + p2.w * t,
+            ),
+        }
+    }
+
+    /// Builds the transform described by this decomposition.
+    ///
+    /// This is the inverse of [`Transform3D::decompose`], up to a normalization of the
+    /// matrix: the recomposed transform always has `m44` equal to one.
+    ///
+    /// [`Transform3D::decompose`]: struct.Transform3D.html#method.decompose
+    pub fn recompose(&self) -> Transform3D<T, Src, Dst> {
+        let zero = T::zero();
+        let one = T::one();
+
+        let p = self.perspective;
+        let perspective = Transform3D::<T, Dst, Dst>::new(
+            one, zero, zero, p.x,
+            zero, one, zero, p.y,
+            zero, zero, one, p.z,
+            zero, zero, zero, p.w,
+        );
+
+        let skew = Transform3D::<T, Src, Src>::new(
+            one, zero, zero, zero,
+            self.skew.x, one, zero, zero,
+            self.skew.y, self.skew.z, one, zero,
+            zero, zero, zero, one,
+        );
+
+        skew.then(&self.rotation.to_transform())
+            .then_translate(self.translation)
+            .then(&perspective)
+            .pre_scale(self.scale.x, self.scale.y, self.scale.z)
+    }
+}
+
+/// The components of a 2d transform, as computed by [`Transform2D::decompose`].
+///
+/// In row-vector notation the transform is `Scale * Skew * Rotation * Translation`,
+/// i.e. points are first scaled, then skewed along the x axis, rotated and finally
+/// translated.
+///
+/// [`Transform2D::decompose`]: struct.Transform2D.html#method.decompose
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Serialize", deserialize = "T: Deserialize<'de>"))
+)]
+pub struct Decomposed2D<T, Src, Dst> {
+    pub translation: Vector2D<T, Dst>,
+    pub angle: Angle<T>,
+    /// The scale factors along each axis.
+    ///
+    /// `scale.x` is never negative: a reflection is represented by a negative `scale.y`.
+    pub scale: Vector2D<T, UnknownUnit>,
+    /// The shear factor, applied after scaling: `x' = x + skew * y`.
+    ///
+    /// This is the tangent of the skew angle along the x axis.
+    pub skew: T,
+    #[doc(hidden)]
+    pub _unit: PhantomData<Src>,
+}
+
+impl<T: Copy, Src, Dst> Copy for Decomposed2D<T, Src, Dst> {}
+
+impl<T: Clone, Src, Dst> Clone for Decomposed2D<T, Src, Dst> {
+    fn clone(&self) -> Self {
+        Decomposed2D {
+            translation: self.translation.clone(),
+            angle: self.angle.clone(),
+            scale: self.scale.clone(),
+            skew: self.skew.clone(),
+            _unit: PhantomData,
+        }
+    }
+}
+
+impl<T: PartialEq, Src, Dst> PartialEq for Decomposed2D<T, Src, Dst> {
+    fn eq(&self, other: &Self) -> bool {
+        self.translation == other.translation
+            && self.angle == other.angle
+            && self.scale == other.scale
+            && self.skew == other.skew
+    }
+}
+
+impl<T: fmt::Debug, Src, Dst> fmt::Debug for Decomposed2D<T, Src, Dst> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Decomposed2D")
+            .field("translation", &self.translation)
+            .field("angle", &self.angle)
+            .field("scale", &self.scale)
+            .field("skew", &self.skew)
+            .finish()
+    }
+}
+
+impl<T, Src, Dst> Decomposed2D<T, Src, Dst> {
+    /// Constructor.
+    #[inline]
+    pub const fn new(
+        translation: Vector2D<T, Dst>,
+        angle: Angle<T>,
+        scale: Vector2D<T, UnknownUnit>,
+        skew: T,
+    ) -> Self {
+        Decomposed2D {
+            translation,
+            angle,
+            scale,
+            skew,
+            _unit: PhantomData,
+        }
+    }
+}
+
+impl<T: Real, Src, Dst> Decomposed2D<T, Src, Dst> {
+    /// The decomposition of the identity transform.
+    pub fn identity() -> Self {
+        let one = T::one();
+        Decomposed2D::new(
+            Vector2D::zero(),
+            Angle::radians(T::zero()),
+            Vector2D::new(one, one),
+            T::zero(),
+        )
+    }
+
+    /// Builds the transform described by this decomposition.
+    ///
+    /// Equivalent to [`Transform2D::from_decomposed`].
+    ///
+    /// [`Transform2D::from_decomposed`]: struct.Transform2D.html#method.from_decomposed
+    #[inline]
+    pub fn recompose(&self) -> Transform2D<T, Src, Dst> {
+        Transform2D::from_decomposed(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::approxeq::ApproxEq;
+    use crate::default::{Rotation3D, Transform2D, Transform3D};
+    use crate::{vec2, vec3, Angle};
+    use core::f64::consts::{FRAC_PI_2, PI};
+
+    type Mf64 = Transform3D<f64>;
+    type M2f64 = Transform2D<f64>;
+
+    fn check_round_trip_2d(m: &M2f64) {
+        let decomposed = m.decompose().unwrap();
+        let recomposed = M2f64::from_decomposed(&decomposed);
+        assert!(
+            recomposed.approx_eq_eps(m, &1e-9),
+            "{:?} -> {:?} -> {:?}",
+            m,
+            decomposed,
+            recomposed
+        );
+    }
+
+    fn check_round_trip(m: &Mf64) {
+        let decomposed = m.decompose().unwrap();
+        let recomposed = decomposed.recompose();
+        // The decomposition normalizes the matrix so that m44 is one.
+        let normalized = m.mul_s(1.0 / m.m44);
+        assert!(
+            recomposed.approx_eq_eps(&normalized, &1e-9),
+            "{:?} -> {:?} -> {:?}",
+            m,
+            decomposed,
+            recomposed
+        );
+    }
+
+    #[test]
+    fn test_decompose_identity() {
+        let d = Mf64::identity().decompose().unwrap();
+        assert_eq!(d.scale, vec3(1.0, 1.0, 1.0));
+        assert_eq!(d.skew, vec3(0.0, 0.0, 0.0));
+        assert_eq!(d.translation, vec3(0.0, 0.0, 0.0));
+        assert!(d.rotation.approx_eq(&Rotation3D::identity()));
+        assert_eq!(d.recompose(), Mf64::identity());
+    }
+
+    #[test]
+    fn test_decompose_components() {
+        let d = Mf64::translation(1.0, 2.0, 3.0).decompose().unwrap();
+        assert_eq!(d.translation, vec3(1.0, 2.0, 3.0));
+        assert_eq!(d.scale, vec3(1.0, 1.0, 1.0));
+
+        let d = Mf64::scale(2.0, 3.0, 4.0).decompose().unwrap();
+        assert_eq!(d.scale, vec3(2.0, 3.0, 4.0));
+        assert_eq!(d.translation, vec3(0.0, 0.0, 0.0));
+
+        let rotation = Rotation3D::around_axis(vec3(1.0, 2.0, 3.0), Angle::radians(0.7));
+        let d = rotation.to_transform().decompose().unwrap();
+        assert!(d.rotation.approx_eq(&rotation));
+        assert!(d.scale.approx_eq(&vec3(1.0, 1.0, 1.0)));
+
+        let d = Mf64::skew(Angle::radians(0.5), Angle::radians(0.0)).decompose().unwrap();
+        assert!(d.skew.x.approx_eq(&0.5f64.tan()));
+        assert!(d.skew.y.approx_eq(&0.0));
+        assert!(d.skew.z.approx_eq(&0.0));
+
+        // Half turns have a zero real part.
+        let rotation = Rotation3D::around_axis(vec3(1.0, -1.0, 0.0), Angle::radians(PI));
+        let d = rotation.to_transform().decompose().unwrap();
+        assert!(d.rotation.approx_eq(&rotation));
+
+        let d = Mf64::perspective(100.0).decompose().unwrap();
+        assert!(d.perspective.z.approx_eq(&-0.01));
+        assert!(d.perspective.w.approx_eq(&1.0));
+    }
+
+    #[test]
+    fn test_decompose_reflection() {
+        let d = Mf64::scale(-1.0, 1.0, 1.0).decompose().unwrap();
+        assert!(d.scale.approx_eq(&vec3(-1.0, -1.0, -1.0)));
+        check_round_trip(&Mf64::scale(-1.0, 1.0, 1.0));
+        check_round_trip(&Mf64::scale(1.0, -2.0, 1.0).then_rotate(0.0, 1.0, 0.0, Angle::radians(0.4)));
+    }
+
+    #[test]
+    fn test_decompose_round_trip() {
+        let matrices = [
+            Mf64::identity(),
+            Mf64::translation(10.0, -20.0, 30.0),
+            Mf64::scale(2.0, 0.5, 3.0),
+            Mf64::rotation(0.0, 0.0, 1.0, Angle::radians(1.0)),
+            Mf64::rotation(1.0, 1.0, 1.0, Angle::radians(-2.0)),
+            Mf64::skew(Angle::radians(0.3), Angle::radians(-0.6)),
+            Mf64::perspective(500.0),
+            Mf64::scale(1.5, 2.0, 0.5)
+                .then(&Mf64::skew(Angle::radians(0.2), Angle::radians(0.1)))
+                .then_rotate(1.0, -2.0, 0.5, Angle::radians(0.9))
+                .then_translate(vec3(4.0, 5.0, -6.0))
+                .then(&Mf64::perspective(200.0)),
+            Mf64::new(
+                1.0, 2.0, 3.0, 0.0,
+                -1.0, 4.0, 0.5, 0.0,
+                0.2, 0.3, 2.0, 0.0,
+                7.0, 8.0, 9.0, 1.0,
+            ),
+            // Not normalized, with perspective and translation.
+            Mf64::new(
+                2.0, 0.0, 0.0, 0.01,
+                0.0, 2.0, 0.0, 0.02,
+                0.0, 0.0, 2.0, -0.01,
+                10.0, 20.0, 30.0, 2.0,
+            ),
+        ];
+
+        for m in &matrices {
+            check_round_trip(m);
+        }
+    }
+
+    #[test]
+    fn test_lerp() {
+        let a = Mf64::scale(2.0, 2.0, 2.0).then_translate(vec3(10.0, 0.0, 0.0)).decompose().unwrap();
+        let b = Mf64::rotation(0.0, 0.0, 1.0, Angle::radians(1.0)).decompose().unwrap();
+        let mid = a.lerp(&b, 0.5);
+        assert!(mid.scale.approx_eq(&vec3(1.5, 1.5, 1.5)));
+        assert!(mid.translation.approx_eq(&vec3(5.0, 0.0, 0.0)));
+        assert!(mid
+            .rotation
+            .approx_eq(&Rotation3D::around_z(Angle::radians(0.5))));
+
+        assert_eq!(a.lerp(&b, 0.0).recompose(), a.recompose());
+        assert!(a.lerp(&b, 1.0).recompose().approx_eq(&b.recompose()));
+    }
+
+    #[test]
+    fn test_decompose_singular() {
+        assert!(Mf64::scale(1.0, 0.0, 1.0).decompose().is_none());
+        let mut m = Mf64::identity();
+        m.m44 = 0.0;
+        assert!(m.decompose().is_none());
+    }
+
+    #[test]
+    fn test_decompose_2d_components() {
+        let d = M2f64::identity().decompose().unwrap();
+        assert_eq!(d, super::Decomposed2D::identity());
+        assert_eq!(d.recompose(), M2f64::identity());
+
+        let d = M2f64::translation(3.0, -4.0).decompose().unwrap();
+        assert_eq!(d.translation, vec2(3.0, -4.0));
+        assert_eq!(d.scale, vec2(1.0, 1.0));
+
+        let d = M2f64::scale(2.0, 0.5).decompose().unwrap();
+        assert_eq!(d.scale, vec2(2.0, 0.5));
+        assert_eq!(d.angle, Angle::radians(0.0));
+
+        let d = M2f64::rotation(Angle::radians(-2.5)).decompose().unwrap();
+        assert!(d.angle.get().approx_eq(&-2.5));
+        assert!(d.scale.approx_eq(&vec2(1.0, 1.0)));
+        assert!(d.skew.approx_eq(&0.0));
+
+        // x' = x + 0.5 * y
+        let d = M2f64::new(1.0, 0.0, 0.5, 1.0, 0.0, 0.0).decompose().unwrap();
+        assert!(d.skew.approx_eq(&0.5));
+        assert!(d.scale.approx_eq(&vec2(1.0, 1.0)));
+        assert_eq!(
+            M2f64::from_decomposed(&d).transform_point(crate::point2(0.0, 2.0)),
+            crate::point2(1.0, 2.0)
+        );
+    }
+
+    #[test]
+    fn test_decompose_2d_reflection() {
+        // Reflections are represented with a negative y scale.
+        let d = M2f64::scale(1.0, -1.0).decompose().unwrap();
+        assert_eq!(d.scale, vec2(1.0, -1.0));
+        assert_eq!(d.angle, Angle::radians(0.0));
+
+        // Flipping the x axis is equivalent to flipping the y axis and rotating by PI.
+        let d = M2f64::scale(-2.0, 3.0).decompose().unwrap();
+        assert!(d.scale.approx_eq(&vec2(2.0, -3.0)));
+        assert!(d.angle.get().approx_eq(&PI));
+
+        // Flipping both axes is a rotation.
+        let d = M2f64::scale(-1.0, -1.0).decompose().unwrap();
+        assert!(d.scale.approx_eq(&vec2(1.0, 1.0)));
+        assert!(d.angle.get().approx_eq(&PI));
+
+        check_round_trip_2d(&M2f64::scale(-1.0, 1.0));
+        check_round_trip_2d(
+            &M2f64::scale(1.0, -2.0)
+                .then_rotate(Angle::radians(FRAC_PI_2))
+                .then_translate(vec2(1.0, 2.0)),
+        );
+    }
+
+    #[test]
+    fn test_decompose_2d_round_trip() {
+        let matrices = [
+            M2f64::identity(),
+            M2f64::translation(10.0, -20.0),
+            M2f64::scale(2.0, 0.5),
+            M2f64::rotation(Angle::radians(1.0)),
+            M2f64::rotation(Angle::radians(PI)),
+            M2f64::scale(1.5, 3.0)
+                .then(&M2f64::new(1.0, 0.0, -0.7, 1.0, 0.0, 0.0))
+                .then_rotate(Angle::radians(-0.9))
+                .then_translate(vec2(4.0, 5.0)),
+            M2f64::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0),
+            M2f64::new(-1.0, 0.5, 2.0, -3.0, 0.0, 1.0),
+        ];
+
+        for m in &matrices {
+            check_round_trip_2d(m);
+        }
+    }
+
+    #[test]
+    fn test_decompose_2d_singular() {
+        assert!(M2f64::scale(0.0, 1.0).decompose().is_none());
+        assert!(M2f64::scale(1.0, 0.0).decompose().is_none());
+        assert!(M2f64::new(1.0, 2.0, 2.0, 4.0, 0.0, 0.0).decompose().is_none());
+    }
+}
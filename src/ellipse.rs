@@ -0,0 +1,973 @@
+// Copyright 2013 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::UnknownUnit;
+use crate::angle::Angle;
+use crate::approxeq::ApproxEq;
+use crate::box2d::Box2D;
+use crate::point::{point2, Point2D};
+use crate::transform2d::Transform2D;
+use crate::vector::{vec2, Vector2D};
+
+use num_traits::real::Real;
+use num_traits::{FloatConst, NumCast};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "bytemuck")]
+use bytemuck::{Zeroable, Pod};
+
+use core::fmt;
+use core::hash::{Hash, Hasher};
+
+/// An ellipse, represented by its center, its radii along its own axes and the
+/// rotation of these axes relative to the x axis.
+///
+/// The point of the ellipse at angle `a` is the point of the axis-aligned ellipse
+/// at `center + (radii.x * cos(a), radii.y * sin(a))`, rotated by `x_rotation`
+/// around the center.
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Serialize", deserialize = "T: Deserialize<'de>"))
+)]
+pub struct Ellipse<T, U> {
+    pub center: Point2D<T, U>,
+    pub radii: Vector2D<T, U>,
+    pub x_rotation: Angle<T>,
+}
+
+impl<T: Hash, U> Hash for Ellipse<T, U> {
+    fn hash<H: Hasher>(&self, h: &mut H) {
+        self.center.hash(h);
+        self.radii.hash(h);
+        self.x_rotation.hash(h);
+    }
+}
+
+impl<T: Copy, U> Copy for Ellipse<T, U> {}
+
+impl<T: Clone, U> Clone for Ellipse<T, U> {
+    fn clone(&self) -> Self {
+        Ellipse {
+            center: self.center.clone(),
+            radii: self.radii.clone(),
+            x_rotation: self.x_rotation.clone(),
+        }
+    }
+}
+
+impl<T: PartialEq, U> PartialEq for Ellipse<T, U> {
+    fn eq(&self, other: &Self) -> bool {
+        self.center.eq(&other.center)
+            && self.radii.eq(&other.radii)
+            && self.x_rotation.eq(&other.x_rotation)
+    }
+}
+
+impl<T: Eq, U> Eq for Ellipse<T, U> {}
+
+impl<T: fmt::Debug, U> fmt::Debug for Ellipse<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Ellipse")
+            .field(&self.center)
+            .field(&self.radii)
+            .field(&self.x_rotation)
+            .finish()
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, T, U> arbitrary::Arbitrary<'a> for Ellipse<T, U>
+where
+    T: arbitrary::Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self>
+    {
+        let (center, radii, x_rotation) = arbitrary::Arbitrary::arbitrary(u)?;
+        Ok(Ellipse { center, radii, x_rotation })
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: Zeroable, U> Zeroable for Ellipse<T, U> {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: Pod, U: 'static> Pod for Ellipse<T, U> {}
+
+impl<T, U> Ellipse<T, U> {
+    /// Constructor.
+    #[inline]
+    pub const fn new(center: Point2D<T, U>, radii: Vector2D<T, U>, x_rotation: Angle<T>) -> Self {
+        Ellipse { center, radii, x_rotation }
+    }
+}
+
+impl<T: Copy, U> Ellipse<T, U> {
+    /// Drop the units, preserving only the numeric value.
+    #[inline]
+    pub fn to_untyped(&self) -> Ellipse<T, UnknownUnit> {
+        Ellipse::new(self.center.to_untyped(), self.radii.to_untyped(), self.x_rotation)
+    }
+
+    /// Tag a unitless value with units.
+    #[inline]
+    pub fn from_untyped(e: &Ellipse<T, UnknownUnit>) -> Self {
+        Ellipse::new(Point2D::from_untyped(e.center), Vector2D::from_untyped(e.radii), e.x_rotation)
+    }
+
+    /// Cast the unit
+    #[inline]
+    pub fn cast_unit<V>(&self) -> Ellipse<T, V> {
+        Ellipse::new(self.center.cast_unit(), self.radii.cast_unit(), self.x_rotation)
+    }
+}
+
+impl<T: Real, U> Ellipse<T, U> {
+    /// Returns the point of the ellipse at a given angle.
+    pub fn point_at_angle(&self, angle: Angle<T>) -> Point2D<T, U> {
+        let (sin, cos) = self.x_rotation.sin_cos();
+        let (s, c) = angle.sin_cos();
+        let x = self.radii.x * c;
+        let y = self.radii.y * s;
+
+        point2(self.center.x + x * cos - y * sin, self.center.y + x * sin + y * cos)
+    }
+
+    /// Returns the angle at which the ellipse passes through a point, assuming the
+    /// point is on the ellipse.
+    fn angle_of(&self, p: Point2D<T, U>) -> Angle<T> {
+        let (sin, cos) = self.x_rotation.sin_cos();
+        let d = p - self.center;
+        let x = cos * d.x + sin * d.y;
+        let y = cos * d.y - sin * d.x;
+
+        Angle::radians((y * self.radii.x).atan2(x * self.radii.y))
+    }
+
+    /// Returns `true` if the point is inside of the ellipse or on its boundary.
+    pub fn contains(&self, p: Point2D<T, U>) -> bool {
+        let (sin, cos) = self.x_rotation.sin_cos();
+        let d = p - self.center;
+        let x = (cos * d.x + sin * d.y) / self.radii.x;
+        let y = (cos * d.y - sin * d.x) / self.radii.y;
+
+        x * x + y * y <= T::one()
+    }
+
+    /// Returns the smallest box containing the ellipse.
+    pub fn bounding_box(&self) -> Box2D<T, U> {
+        let (sin, cos) = self.x_rotation.sin_cos();
+        let (rx, ry) = (self.radii.x, self.radii.y);
+        let half = vec2((rx * cos).hypot(ry * sin), (rx * sin).hypot(ry * cos));
+
+        Box2D::new(self.center - half, self.center + half)
+    }
+
+    /// Applies the transform to the ellipse.
+    ///
+    /// The affine image of an ellipse is an ellipse, the result's `radii.x` is its
+    /// major radius and `x_rotation` the direction of its major axis.
+    pub fn transform<Dst>(&self, transform: &Transform2D<T, U, Dst>) -> Ellipse<T, Dst> {
+        let (sin, cos) = self.x_rotation.sin_cos();
+        let u = transform.transform_vector(vec2(self.radii.x * cos, self.radii.x * sin));
+        let v = transform.transform_vector(vec2(-self.radii.y * sin, self.radii.y * cos));
+
+        // The eigen decomposition of the symmetric matrix [u v] * [u v]^T gives the
+        // squared radii and the directions of the axes of the new ellipse.
+        let two = T::one() + T::one();
+        let a = u.x * u.x + v.x * v.x;
+        let b = u.x * u.y + v.x * v.y;
+        let c = u.y * u.y + v.y * v.y;
+        let mean = (a + c) / two;
+        let d = ((a - c) / two).hypot(b);
+
+        Ellipse::new(
+            transform.transform_point(self.center),
+            vec2((mean + d).sqrt(), (mean - d).max(T::zero()).sqrt()),
+            Angle::radians((b * two).atan2(a - c) / two),
+        )
+    }
+}
+
+impl<T: Real + FloatConst, U> Ellipse<T, U> {
+    /// Returns the arc going once around the ellipse, starting at angle zero.
+    #[inline]
+    pub fn to_arc(&self) -> Arc<T, U> {
+        Arc::new(self.center, self.radii, Angle::zero(), Angle::two_pi(), self.x_rotation)
+    }
+
+    /// Approximates the ellipse with a sequence of points.
+    ///
+    /// See `Arc::flattened`.
+    #[inline]
+    pub fn flattened(&self, tolerance: T) -> FlattenedArc<T, U> {
+        self.to_arc().flattened(tolerance)
+    }
+}
+
+impl<T: NumCast + Copy, U> Ellipse<T, U> {
+    /// Cast from one numeric representation to another, preserving the units.
+    #[inline]
+    pub fn cast<NewT: NumCast>(&self) -> Ellipse<NewT, U> {
+        Ellipse::new(self.center.cast(), self.radii.cast(), self.x_rotation.cast())
+    }
+
+    /// Fallible cast from one numeric representation to another, preserving the units.
+    pub fn try_cast<NewT: NumCast>(&self) -> Option<Ellipse<NewT, U>> {
+        match (self.center.try_cast(), self.radii.try_cast(), self.x_rotation.try_cast()) {
+            (Some(center), Some(radii), Some(x_rotation)) => {
+                Some(Ellipse::new(center, radii, x_rotation))
+            }
+            _ => None,
+        }
+    }
+
+    // Convenience functions for common casts
+
+    /// Cast into an `f32` ellipse.
+    #[inline]
+    pub fn to_f32(&self) -> Ellipse<f32, U> {
+        self.cast()
+    }
+
+    /// Cast into an `f64` ellipse.
+    #[inline]
+    pub fn to_f64(&self) -> Ellipse<f64, U> {
+        self.cast()
+    }
+}
+
+impl<T: ApproxEq<T>, U> ApproxEq<T> for Ellipse<T, U> {
+    #[inline]
+    fn approx_epsilon() -> T {
+        T::approx_epsilon()
+    }
+
+    #[inline]
+    fn approx_eq_eps(&self, other: &Self, eps: &T) -> bool {
+        self.center.x.approx_eq_eps(&other.center.x, eps)
+            && self.center.y.approx_eq_eps(&other.center.y, eps)
+            && self.radii.x.approx_eq_eps(&other.radii.x, eps)
+            && self.radii.y.approx_eq_eps(&other.radii.y, eps)
+            && self.x_rotation.approx_eq_eps(&other.x_rotation, eps)
+    }
+}
+
+/// An elliptical arc, represented in center parameterization.
+///
+/// The arc goes from `start_angle` to `start_angle + sweep_angle` on the ellipse
+/// described by `center`, `radii` and `x_rotation` (see `Ellipse`). A positive sweep
+/// goes in the direction of increasing angles.
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Serialize", deserialize = "T: Deserialize<'de>"))
+)]
+pub struct Arc<T, U> {
+    pub center: Point2D<T, U>,
+    pub radii: Vector2D<T, U>,
+    pub start_angle: Angle<T>,
+    pub sweep_angle: Angle<T>,
+    pub x_rotation: Angle<T>,
+}
+
+impl<T: Hash, U> Hash for Arc<T, U> {
+    fn hash<H: Hasher>(&self, h: &mut H) {
+        self.center.hash(h);
+        self.radii.hash(h);
+        self.start_angle.hash(h);
+        self.sweep_angle.hash(h);
+        self.x_rotation.hash(h);
+    }
+}
+
+impl<T: Copy, U> Copy for Arc<T, U> {}
+
+impl<T: Clone, U> Clone for Arc<T, U> {
+    fn clone(&self) -> Self {
+        Arc {
+            center: self.center.clone(),
+            radii: self.radii.clone(),
+            start_angle: self.start_angle.clone(),
+            sweep_angle: self.sweep_angle.clone(),
+            x_rotation: self.x_rotation.clone(),
+        }
+    }
+}
+
+impl<T: PartialEq, U> PartialEq for Arc<T, U> {
+    fn eq(&self, other: &Self) -> bool {
+        self.center.eq(&other.center)
+            && self.radii.eq(&other.radii)
+            && self.start_angle.eq(&other.start_angle)
+            && self.sweep_angle.eq(&other.sweep_angle)
+            && self.x_rotation.eq(&other.x_rotation)
+    }
+}
+
+impl<T: Eq, U> Eq for Arc<T, U> {}
+
+impl<T: fmt::Debug, U> fmt::Debug for Arc<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Arc")
+            .field(&self.center)
+            .field(&self.radii)
+            .field(&self.start_angle)
+            .field(&self.sweep_angle)
+            .field(&self.x_rotation)
+            .finish()
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, T, U> arbitrary::Arbitrary<'a> for Arc<T, U>
+where
+    T: arbitrary::Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self>
+    {
+        let (center, radii, start_angle, sweep_angle, x_rotation) =
+            arbitrary::Arbitrary::arbitrary(u)?;
+        Ok(Arc { center, radii, start_angle, sweep_angle, x_rotation })
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: Zeroable, U> Zeroable for Arc<T, U> {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: Pod, U: 'static> Pod for Arc<T, U> {}
+
+impl<T, U> Arc<T, U> {
+    /// Constructor.
+    #[inline]
+    pub const fn new(
+        center: Point2D<T, U>,
+        radii: Vector2D<T, U>,
+        start_angle: Angle<T>,
+        sweep_angle: Angle<T>,
+        x_rotation: Angle<T>,
+    ) -> Self {
+        Arc { center, radii, start_angle, sweep_angle, x_rotation }
+    }
+}
+
+impl<T: Copy, U> Arc<T, U> {
+    /// Returns the ellipse this arc is a part of.
+    #[inline]
+    pub fn to_ellipse(&self) -> Ellipse<T, U> {
+        Ellipse::new(self.center, self.radii, self.x_rotation)
+    }
+
+    /// Drop the units, preserving only the numeric value.
+    #[inline]
+    pub fn to_untyped(&self) -> Arc<T, UnknownUnit> {
+        Arc::new(
+            self.center.to_untyped(),
+            self.radii.to_untyped(),
+            self.start_angle,
+            self.sweep_angle,
+            self.x_rotation,
+        )
+    }
+
+    /// Tag a unitless value with units.
+    #[inline]
+    pub fn from_untyped(a: &Arc<T, UnknownUnit>) -> Self {
+        Arc::new(
+            Point2D::from_untyped(a.center),
+            Vector2D::from_untyped(a.radii),
+            a.start_angle,
+            a.sweep_angle,
+            a.x_rotation,
+        )
+    }
+
+    /// Cast the unit
+    #[inline]
+    pub fn cast_unit<V>(&self) -> Arc<T, V> {
+        Arc::new(
+            self.center.cast_unit(),
+            self.radii.cast_unit(),
+            self.start_angle,
+            self.sweep_angle,
+            self.x_rotation,
+        )
+    }
+}
+
+impl<T: Real, U> Arc<T, U> {
+    /// Returns the angle at which the arc ends.
+    #[inline]
+    pub fn end_angle(&self) -> Angle<T> {
+        self.start_angle + self.sweep_angle
+    }
+
+    /// Returns the first point of the arc.
+    #[inline]
+    pub fn from(&self) -> Point2D<T, U> {
+        self.to_ellipse().point_at_angle(self.start_angle)
+    }
+
+    /// Returns the last point of the arc.
+    #[inline]
+    pub fn to(&self) -> Point2D<T, U> {
+        self.to_ellipse().point_at_angle(self.end_angle())
+    }
+
+    /// Sample the arc at t (expecting t between zero and one).
+    #[inline]
+    pub fn sample(&self, t: T) -> Point2D<T, U> {
+        self.to_ellipse().point_at_angle(self.start_angle + self.sweep_angle * t)
+    }
+
+    /// Returns the same arc, traversed in the opposite direction.
+    #[inline]
+    pub fn flip(&self) -> Self {
+        Arc::new(self.center, self.radii, self.end_angle(), -self.sweep_angle, self.x_rotation)
+    }
+
+    /// Applies the transform to the arc.
+    ///
+    /// The arc keeps going through the transformed points, but as the ellipse axes
+    /// are recomputed (see `Ellipse::transform`) its angles generally change.
+    pub fn transform<Dst>(&self, transform: &Transform2D<T, U, Dst>) -> Arc<T, Dst> {
+        let ellipse = self.to_ellipse().transform(transform);
+        let start_angle = ellipse.angle_of(transform.transform_point(self.from()));
+        let sweep_angle = if transform.determinant() < T::zero() {
+            -self.sweep_angle
+        } else {
+            self.sweep_angle
+        };
+
+        Arc::new(ellipse.center, ellipse.radii, start_angle, sweep_angle, ellipse.x_rotation)
+    }
+}
+
+impl<T: Real + FloatConst, U> Arc<T, U> {
+    /// Converts an arc in SVG endpoint parameterization to center parameterization.
+    ///
+    /// This follows the SVG specification: the radii are made positive and scaled up
+    /// if they are too small for the ellipse to reach both endpoints. Returns `None`
+    /// if the endpoints are equal (the arc is omitted) or if a radius is zero (the arc
+    /// is a straight line).
+    pub fn from_svg_arc(arc: &SvgArc<T, U>) -> Option<Self> {
+        let zero = T::zero();
+        let one = T::one();
+        let two = one + one;
+
+        let rx = arc.radii.x.abs();
+        let ry = arc.radii.y.abs();
+        if arc.from == arc.to || rx == zero || ry == zero {
+            return None;
+        }
+
+        // The half chord, in the coordinate system of the ellipse axes.
+        let (sin, cos) = arc.x_rotation.sin_cos();
+        let half = (arc.from - arc.to) / two;
+        let x1 = cos * half.x + sin * half.y;
+        let y1 = cos * half.y - sin * half.x;
+
+        let lambda = (x1 * x1) / (rx * rx) + (y1 * y1) / (ry * ry);
+        let (rx, ry) = if lambda > one {
+            let s = lambda.sqrt();
+            (rx * s, ry * s)
+        } else {
+            (rx, ry)
+        };
+
+        let (rx2, ry2) = (rx * rx, ry * ry);
+        let den = rx2 * y1 * y1 + ry2 * x1 * x1;
+        let coef = ((rx2 * ry2 - den) / den).max(zero).sqrt();
+        let coef = if arc.large_arc == arc.sweep { -coef } else { coef };
+        let cx1 = coef * rx * y1 / ry;
+        let cy1 = -coef * ry * x1 / rx;
+
+        let mid = arc.from.lerp(arc.to, one / two);
+        let center = point2(mid.x + cos * cx1 - sin * cy1, mid.y + sin * cx1 + cos * cy1);
+
+        let start_angle = Angle::radians(((y1 - cy1) / ry).atan2((x1 - cx1) / rx));
+        let end_angle = Angle::radians(((-y1 - cy1) / ry).atan2((-x1 - cx1) / rx));
+        let sweep_angle = (end_angle - start_angle).positive();
+        let sweep_angle = if !arc.sweep && sweep_angle.radians > zero {
+            sweep_angle - Angle::two_pi()
+        } else {
+            sweep_angle
+        };
+
+        Some(Arc::new(center, vec2(rx, ry), start_angle, sweep_angle, arc.x_rotation))
+    }
+
+    /// Converts the arc to SVG endpoint parameterization.
+    pub fn to_svg_arc(&self) -> SvgArc<T, U> {
+        SvgArc::new(
+            self.from(),
+            self.to(),
+            self.radii,
+            self.x_rotation,
+            self.sweep_angle.radians.abs() > T::PI(),
+            self.sweep_angle.radians > T::zero(),
+        )
+    }
+
+    /// Returns `true` if the arc goes through the point of its ellipse at this angle.
+    fn contains_angle(&self, angle: Angle<T>) -> bool {
+        let sweep = self.sweep_angle.radians;
+        let offset = if sweep < T::zero() {
+            self.start_angle - angle
+        } else {
+            angle - self.start_angle
+        };
+
+        offset.positive().radians <= sweep.abs()
+    }
+
+    /// Returns the smallest box containing the arc.
+    ///
+    /// Besides the endpoints, the extremities of the box can only be the points where
+    /// the tangent of the ellipse is horizontal or vertical.
+    pub fn bounding_box(&self) -> Box2D<T, U> {
+        let (sin, cos) = self.x_rotation.sin_cos();
+        let (rx, ry) = (self.radii.x, self.radii.y);
+        let vertical_tangent = (-ry * sin).atan2(rx * cos);
+        let horizontal_tangent = (ry * cos).atan2(rx * sin);
+
+        let from = self.from();
+        let to = self.to();
+        let mut min = from.min(to);
+        let mut max = from.max(to);
+
+        let ellipse = self.to_ellipse();
+        for &a in &[vertical_tangent, horizontal_tangent] {
+            for &angle in &[Angle::radians(a), Angle::radians(a + T::PI())] {
+                if self.contains_angle(angle) {
+                    let p = ellipse.point_at_angle(angle);
+                    min = min.min(p);
+                    max = max.max(p);
+                }
+            }
+        }
+
+        Box2D::new(min, max)
+    }
+
+    /// Approximates the arc with a sequence of points, such that the segments between
+    /// consecutive points are never further than `tolerance` from the arc.
+    ///
+    /// The iterator yields the points after `from()`, the last one being `to()`.
+    /// The tolerance must be positive.
+    pub fn flattened(&self, tolerance: T) -> FlattenedArc<T, U> {
+        debug_assert!(tolerance > T::zero());
+        let one = T::one();
+        let two = one + one;
+
+        // A chord spanning the angle `step` on a circle of radius `r` is at most
+        // `r * (1 - cos(step / 2))` away from it. Stretching the circle into the
+        // ellipse moves points by at most the largest radius.
+        let radius = self.radii.x.abs().max(self.radii.y.abs());
+        let step = (one - tolerance / radius).max(-one).acos() * two;
+        let count = (self.sweep_angle.radians.abs() / step).ceil();
+
+        FlattenedArc {
+            arc: *self,
+            count: NumCast::from(count).unwrap_or(1).max(1),
+            current: 0,
+        }
+    }
+}
+
+impl<T: NumCast + Copy, U> Arc<T, U> {
+    /// Cast from one numeric representation to another, preserving the units.
+    #[inline]
+    pub fn cast<NewT: NumCast>(&self) -> Arc<NewT, U> {
+        Arc::new(
+            self.center.cast(),
+            self.radii.cast(),
+            self.start_angle.cast(),
+            self.sweep_angle.cast(),
+            self.x_rotation.cast(),
+        )
+    }
+
+    /// Fallible cast from one numeric representation to another, preserving the units.
+    pub fn try_cast<NewT: NumCast>(&self) -> Option<Arc<NewT, U>> {
+        match (
+            self.center.try_cast(),
+            self.radii.try_cast(),
+            self.start_angle.try_cast(),
+            self.sweep_angle.try_cast(),
+            self.x_rotation.try_cast(),
+        ) {
+            (Some(center), Some(radii), Some(start), Some(sweep), Some(x_rotation)) => {
+                Some(Arc::new(center, radii, start, sweep, x_rotation))
+            }
+            _ => None,
+        }
+    }
+
+    // Convenience functions for common casts
+
+    /// Cast into an `f32` arc.
+    #[inline]
+    pub fn to_f32(&self) -> Arc<f32, U> {
+        self.cast()
+    }
+
+    /// Cast into an `f64` arc.
+    #[inline]
+    pub fn to_f64(&self) -> Arc<f64, U> {
+        self.cast()
+    }
+}
+
+impl<T: ApproxEq<T>, U> ApproxEq<T> for Arc<T, U> {
+    #[inline]
+    fn approx_epsilon() -> T {
+        T::approx_epsilon()
+    }
+
+    #[inline]
+    fn approx_eq_eps(&self, other: &Self, eps: &T) -> bool {
+        self.center.x.approx_eq_eps(&other.center.x, eps)
+            && self.center.y.approx_eq_eps(&other.center.y, eps)
+            && self.radii.x.approx_eq_eps(&other.radii.x, eps)
+            && self.radii.y.approx_eq_eps(&other.radii.y, eps)
+            && self.start_angle.approx_eq_eps(&other.start_angle, eps)
+            && self.sweep_angle.approx_eq_eps(&other.sweep_angle, eps)
+            && self.x_rotation.approx_eq_eps(&other.x_rotation, eps)
+    }
+}
+
+/// An elliptical arc, represented in SVG endpoint parameterization.
+///
+/// Among the four arcs of the given ellipse going from `from` to `to`, `large_arc`
+/// selects one sweeping more than 180 degrees and `sweep` one going in the direction
+/// of increasing angles.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Serialize", deserialize = "T: Deserialize<'de>"))
+)]
+pub struct SvgArc<T, U> {
+    pub from: Point2D<T, U>,
+    pub to: Point2D<T, U>,
+    pub radii: Vector2D<T, U>,
+    pub x_rotation: Angle<T>,
+    pub large_arc: bool,
+    pub sweep: bool,
+}
+
+impl<T: Hash, U> Hash for SvgArc<T, U> {
+    fn hash<H: Hasher>(&self, h: &mut H) {
+        self.from.hash(h);
+        self.to.hash(h);
+        self.radii.hash(h);
+        self.x_rotation.hash(h);
+        self.large_arc.hash(h);
+        self.sweep.hash(h);
+    }
+}
+
+impl<T: Copy, U> Copy for SvgArc<T, U> {}
+
+impl<T: Clone, U> Clone for SvgArc<T, U> {
+    fn clone(&self) -> Self {
+        SvgArc {
+            from: self.from.clone(),
+            to: self.to.clone(),
+            radii: self.radii.clone(),
+            x_rotation: self.x_rotation.clone(),
+            large_arc: self.large_arc,
+            sweep: self.sweep,
+        }
+    }
+}
+
+impl<T: PartialEq, U> PartialEq for SvgArc<T, U> {
+    fn eq(&self, other: &Self) -> bool {
+        self.from.eq(&other.from)
+            && self.to.eq(&other.to)
+            && self.radii.eq(&other.radii)
+            && self.x_rotation.eq(&other.x_rotation)
+            && self.large_arc == other.large_arc
+            && self.sweep == other.sweep
+    }
+}
+
+impl<T: Eq, U> Eq for SvgArc<T, U> {}
+
+impl<T: fmt::Debug, U> fmt::Debug for SvgArc<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("SvgArc")
+            .field(&self.from)
+            .field(&self.to)
+            .field(&self.radii)
+            .field(&self.x_rotation)
+            .field(&self.large_arc)
+            .field(&self.sweep)
+            .finish()
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, T, U> arbitrary::Arbitrary<'a> for SvgArc<T, U>
+where
+    T: arbitrary::Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self>
+    {
+        let (from, to, radii, x_rotation, large_arc, sweep) = arbitrary::Arbitrary::arbitrary(u)?;
+        Ok(SvgArc { from, to, radii, x_rotation, large_arc, sweep })
+    }
+}
+
+impl<T, U> SvgArc<T, U> {
+    /// Constructor.
+    #[inline]
+    pub const fn new(
+        from: Point2D<T, U>,
+        to: Point2D<T, U>,
+        radii: Vector2D<T, U>,
+        x_rotation: Angle<T>,
+        large_arc: bool,
+        sweep: bool,
+    ) -> Self {
+        SvgArc { from, to, radii, x_rotation, large_arc, sweep }
+    }
+}
+
+impl<T: Copy, U> SvgArc<T, U> {
+    /// Drop the units, preserving only the numeric value.
+    #[inline]
+    pub fn to_untyped(&self) -> SvgArc<T, UnknownUnit> {
+        self.cast_unit()
+    }
+
+    /// Tag a unitless value with units.
+    #[inline]
+    pub fn from_untyped(a: &SvgArc<T, UnknownUnit>) -> Self {
+        a.cast_unit()
+    }
+
+    /// Cast the unit
+    #[inline]
+    pub fn cast_unit<V>(&self) -> SvgArc<T, V> {
+        SvgArc::new(
+            self.from.cast_unit(),
+            self.to.cast_unit(),
+            self.radii.cast_unit(),
+            self.x_rotation,
+            self.large_arc,
+            self.sweep,
+        )
+    }
+}
+
+impl<T: Real + FloatConst, U> SvgArc<T, U> {
+    /// Converts the arc to center parameterization.
+    ///
+    /// See `Arc::from_svg_arc`.
+    #[inline]
+    pub fn to_arc(&self) -> Option<Arc<T, U>> {
+        Arc::from_svg_arc(self)
+    }
+}
+
+/// An iterator over the points approximating an arc, see `Arc::flattened`.
+pub struct FlattenedArc<T, U> {
+    arc: Arc<T, U>,
+    count: u32,
+    current: u32,
+}
+
+impl<T: Copy, U> Clone for FlattenedArc<T, U> {
+    fn clone(&self) -> Self {
+        FlattenedArc {
+            arc: self.arc,
+            count: self.count,
+            current: self.current,
+        }
+    }
+}
+
+impl<T: Real, U> Iterator for FlattenedArc<T, U> {
+    type Item = Point2D<T, U>;
+
+    fn next(&mut self) -> Option<Point2D<T, U>> {
+        if self.current >= self.count {
+            return None;
+        }
+
+        self.current += 1;
+        if self.current == self.count {
+            // Land exactly on the endpoint.
+            return Some(self.arc.to());
+        }
+
+        let current: T = NumCast::from(self.current).unwrap();
+        let count: T = NumCast::from(self.count).unwrap();
+        Some(self.arc.sample(current / count))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.count - self.current) as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T: Real, U> ExactSizeIterator for FlattenedArc<T, U> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::approxeq::ApproxEq;
+    use crate::default::{Arc, Box2D, Ellipse, LineSegment2D, Point2D, SvgArc, Transform2D, Vector2D};
+    use crate::{point2, vec2, Angle};
+    use core::f64::consts::{FRAC_PI_2, PI};
+
+    fn assert_points_eq(a: Point2D<f64>, b: Point2D<f64>) {
+        assert!(a.approx_eq_eps(&b, &point2(1e-9, 1e-9)), "{:?} != {:?}", a, b);
+    }
+
+    fn arc(center: Point2D<f64>, radii: Vector2D<f64>, start: f64, sweep: f64, rotation: f64) -> Arc<f64> {
+        let angle = Angle::radians;
+        Arc::new(center, radii, angle(start), angle(sweep), angle(rotation))
+    }
+
+    fn sampled_bounds(arc: &Arc<f64>) -> Box2D<f64> {
+        Box2D::from_points((0..=10000).map(|i| arc.sample(i as f64 / 10000.0)))
+    }
+
+    #[test]
+    fn test_ellipse() {
+        let e = Ellipse::new(point2(1.0, 2.0), vec2(2.0, 1.0), Angle::radians(FRAC_PI_2));
+        assert_points_eq(e.point_at_angle(Angle::zero()), point2(1.0, 4.0));
+        assert_points_eq(e.point_at_angle(Angle::radians(FRAC_PI_2)), point2(0.0, 2.0));
+
+        assert!(e.contains(point2(1.0, 3.9)));
+        assert!(!e.contains(point2(2.1, 2.0)));
+
+        let b = e.bounding_box();
+        assert!(b.min.approx_eq(&point2(0.0, 0.0)));
+        assert!(b.max.approx_eq(&point2(2.0, 4.0)));
+
+        let e = Ellipse::new(point2(0.0, 0.0), vec2(3.0, 1.0), Angle::radians(0.4));
+        let b = e.bounding_box();
+        let sampled = sampled_bounds(&e.to_arc());
+        assert!(b.contains_box(&sampled));
+        assert!(b.min.approx_eq_eps(&sampled.min, &point2(1e-6, 1e-6)));
+        assert!(b.max.approx_eq_eps(&sampled.max, &point2(1e-6, 1e-6)));
+    }
+
+    #[test]
+    fn test_svg_arc_round_trip() {
+        let from = point2(1.0, 1.0);
+        let to = point2(3.0, 2.0);
+        for &(large_arc, sweep) in &[(false, false), (false, true), (true, false), (true, true)] {
+            let svg = SvgArc::new(from, to, vec2(3.0, 2.0), Angle::radians(0.3), large_arc, sweep);
+            let arc = svg.to_arc().unwrap();
+
+            assert_points_eq(arc.from(), from);
+            assert_points_eq(arc.to(), to);
+            assert_eq!(arc.sweep_angle.radians > 0.0, sweep);
+            assert_eq!(arc.sweep_angle.radians.abs() > PI, large_arc);
+
+            let back = arc.to_svg_arc();
+            assert_eq!((back.large_arc, back.sweep), (large_arc, sweep));
+            assert_points_eq(back.from, from);
+            assert_points_eq(back.to, to);
+        }
+    }
+
+    #[test]
+    fn test_svg_arc_radii_correction() {
+        // The radii are too small to reach the other endpoint, they are scaled up until
+        // the chord is a diameter.
+        let origin = point2(0.0, 0.0);
+        let svg = SvgArc::new(origin, point2(4.0, 0.0), vec2(1.0, -1.0), Angle::zero(), false, true);
+        let arc = svg.to_arc().unwrap();
+        assert_points_eq(arc.center, point2(2.0, 0.0));
+        assert!(arc.radii.approx_eq(&vec2(2.0, 2.0)));
+        assert!(arc.sweep_angle.radians.approx_eq(&PI));
+
+        let degenerate = SvgArc::new(origin, origin, vec2(1.0, 1.0), Angle::zero(), false, true);
+        assert!(degenerate.to_arc().is_none());
+        let line = SvgArc::new(origin, point2(1.0, 0.0), vec2(0.0, 1.0), Angle::zero(), false, true);
+        assert!(line.to_arc().is_none());
+    }
+
+    #[test]
+    fn test_arc_bounding_box() {
+        let quarter = arc(point2(0.0, 0.0), vec2(1.0, 1.0), 0.0, FRAC_PI_2, 0.0);
+        let b = quarter.bounding_box();
+        assert!(b.min.approx_eq(&point2(0.0, 0.0)));
+        assert!(b.max.approx_eq(&point2(1.0, 1.0)));
+
+        for &(start, sweep) in &[(0.2, 2.5), (-1.0, -4.0), (3.0, 1.0), (1.0, -0.5), (0.0, 7.0)] {
+            let arc = arc(point2(1.0, -2.0), vec2(3.0, 1.5), start, sweep, 0.7);
+            let b = arc.bounding_box();
+            let sampled = sampled_bounds(&arc);
+            assert!(b.min.approx_eq_eps(&sampled.min, &point2(1e-6, 1e-6)), "{:?} {:?}", b, sampled);
+            assert!(b.max.approx_eq_eps(&sampled.max, &point2(1e-6, 1e-6)), "{:?} {:?}", b, sampled);
+        }
+    }
+
+    #[test]
+    fn test_flattened() {
+        let arc = arc(point2(1.0, 2.0), vec2(10.0, 4.0), 0.5, -4.0, 0.3);
+        let tolerance = 0.01;
+
+        let points: Vec<_> = arc.flattened(tolerance).collect();
+        assert!(points.len() > 2);
+        assert_eq!(*points.last().unwrap(), arc.to());
+
+        // The points are evenly spaced in angle, check the arc between each of them.
+        let n = points.len() as f64;
+        let mut from = arc.from();
+        for (i, &to) in points.iter().enumerate() {
+            let segment = LineSegment2D::new(from, to);
+            for j in 0..=100 {
+                let p = arc.sample((i as f64 + j as f64 / 100.0) / n);
+                assert!(segment.distance_to_point(p) <= tolerance);
+            }
+            from = to;
+        }
+
+        let e = Ellipse::new(point2(0.0, 0.0), vec2(1.0, 1.0), Angle::zero());
+        assert_eq!(e.flattened(10.0).count(), 1);
+        assert!(e.flattened(0.001).count() > 50);
+    }
+
+    #[test]
+    fn test_transform() {
+        let arc = arc(point2(1.0, 2.0), vec2(3.0, 1.0), 0.5, 2.0, 0.3);
+        let transforms = [
+            Transform2D::scale(2.0, 0.5)
+                .then_rotate(Angle::radians(1.0))
+                .then_translate(vec2(3.0, -1.0)),
+            Transform2D::scale(-1.0, 1.0),
+            Transform2D::new(1.0, 0.5, 0.3, 2.0, 0.0, 1.0),
+        ];
+
+        for transform in &transforms {
+            let transformed = arc.transform(transform);
+            assert!(transformed.radii.x >= transformed.radii.y);
+            for i in 0..=10 {
+                let t = i as f64 / 10.0;
+                assert_points_eq(transformed.sample(t), transform.transform_point(arc.sample(t)));
+            }
+        }
+
+        let e: Ellipse<f64> = Ellipse::new(point2(0.0, 0.0), vec2(1.0, 1.0), Angle::zero());
+        let t = e.transform(&Transform2D::scale(1.0, 3.0));
+        assert!(t.radii.approx_eq(&vec2(3.0, 1.0)));
+        assert!(t.x_rotation.radians.abs().approx_eq(&FRAC_PI_2));
+    }
+}
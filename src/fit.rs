@@ -0,0 +1,139 @@
+// Copyright 2013 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Parameters for fitting content of a given size into a box, like the `object-fit` and
+//! `object-position` properties of CSS and the `preserveAspectRatio` attribute of SVG.
+
+use num_traits::Float;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// How content is resized to fit into a box, like the CSS `object-fit` property.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ObjectFit {
+    /// Scales the content uniformly so that it fits entirely inside of the box.
+    Contain,
+    /// Scales the content uniformly so that it covers the entire box.
+    Cover,
+    /// Stretches the content to the size of the box, ignoring its aspect ratio.
+    Fill,
+    /// Keeps the size of the content.
+    None,
+    /// Behaves like `None` or `Contain`, whichever results in smaller content.
+    ScaleDown,
+}
+
+impl ObjectFit {
+    /// Returns the horizontal and vertical scale factors to apply to content of the
+    /// given width and height to fit it into a box of the given width and height.
+    pub(crate) fn scale_factors<T: Float>(self, content: (T, T), container: (T, T)) -> (T, T) {
+        let sx = container.0 / content.0;
+        let sy = container.1 / content.1;
+        let s = match self {
+            ObjectFit::Contain => sx.min(sy),
+            ObjectFit::Cover => sx.max(sy),
+            ObjectFit::Fill => return (sx, sy),
+            ObjectFit::None => T::one(),
+            ObjectFit::ScaleDown => sx.min(sy).min(T::one()),
+        };
+        (s, s)
+    }
+}
+
+/// The SVG `meet` and `slice` keywords of the `preserveAspectRatio` attribute.
+///
+/// The SVG `none` value corresponds to `ObjectFit::Fill`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum MeetOrSlice {
+    /// Scales the content so that it is entirely visible, like `ObjectFit::Contain`.
+    Meet,
+    /// Scales the content so that it covers the entire box, like `ObjectFit::Cover`.
+    Slice,
+}
+
+impl From<MeetOrSlice> for ObjectFit {
+    fn from(m: MeetOrSlice) -> Self {
+        match m {
+            MeetOrSlice::Meet => ObjectFit::Contain,
+            MeetOrSlice::Slice => ObjectFit::Cover,
+        }
+    }
+}
+
+/// Where content is placed along one axis of a box.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Align {
+    /// Aligns the content with the minimum edge of the box.
+    Min,
+    /// Centers the content in the box.
+    Mid,
+    /// Aligns the content with the maximum edge of the box.
+    Max,
+}
+
+impl Align {
+    /// Returns the offset of the content from the minimum edge of the box, given the
+    /// difference between the length of the box and the length of the content.
+    pub(crate) fn offset<T: Float>(self, free: T) -> T {
+        match self {
+            Align::Min => T::zero(),
+            Align::Mid => free / (T::one() + T::one()),
+            Align::Max => free,
+        }
+    }
+}
+
+/// Where content is placed in a box, like the anchors of the SVG `preserveAspectRatio`
+/// attribute.
+///
+/// The y axis points down, so that `TOP_LEFT` aligns the content with the minimum
+/// corner of the box.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Alignment {
+    pub x: Align,
+    pub y: Align,
+}
+
+impl Alignment {
+    /// `xMinYMin`
+    pub const TOP_LEFT: Self = Alignment::new(Align::Min, Align::Min);
+    /// `xMidYMin`
+    pub const TOP: Self = Alignment::new(Align::Mid, Align::Min);
+    /// `xMaxYMin`
+    pub const TOP_RIGHT: Self = Alignment::new(Align::Max, Align::Min);
+    /// `xMinYMid`
+    pub const LEFT: Self = Alignment::new(Align::Min, Align::Mid);
+    /// `xMidYMid`
+    pub const CENTER: Self = Alignment::new(Align::Mid, Align::Mid);
+    /// `xMaxYMid`
+    pub const RIGHT: Self = Alignment::new(Align::Max, Align::Mid);
+    /// `xMinYMax`
+    pub const BOTTOM_LEFT: Self = Alignment::new(Align::Min, Align::Max);
+    /// `xMidYMax`
+    pub const BOTTOM: Self = Alignment::new(Align::Mid, Align::Max);
+    /// `xMaxYMax`
+    pub const BOTTOM_RIGHT: Self = Alignment::new(Align::Max, Align::Max);
+
+    /// Constructor taking the alignment along each axis.
+    #[inline]
+    pub const fn new(x: Align, y: Align) -> Self {
+        Alignment { x, y }
+    }
+}
+
+impl Default for Alignment {
+    /// Centers the content, which is the default of both CSS and SVG.
+    fn default() -> Self {
+        Alignment::CENTER
+    }
+}
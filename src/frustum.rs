@@ -0,0 +1,436 @@
+// Copyright 2013 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::UnknownUnit;
+use crate::box3d::Box3D;
+use crate::plane::Plane3D;
+use crate::point::{point3, Point3D};
+use crate::transform3d::{DepthRange, Transform3D};
+use crate::vector::{vec3, Vector3D};
+
+use num_traits::real::Real;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "bytemuck")]
+use bytemuck::{Zeroable, Pod};
+
+use core::fmt;
+use core::hash::{Hash, Hasher};
+
+/// The result of testing whether a shape is inside of a [`Frustum`].
+///
+/// [`Frustum`]: struct.Frustum.html
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Containment {
+    /// The shape is entirely outside.
+    Outside,
+    /// The shape may be partially inside.
+    Intersects,
+    /// The shape is entirely inside.
+    Inside,
+}
+
+/// A convex volume bounded by six planes, typically the volume that is visible
+/// through a camera.
+///
+/// The normals of the planes point towards the inside of the frustum. The planes are
+/// stored in the following order: left, right, bottom, top, near and far, where near
+/// and far correspond to the lower and upper bounds of the depth range (they are
+/// swapped for projections with a reversed depth).
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Serialize", deserialize = "T: Deserialize<'de>"))
+)]
+pub struct Frustum<T, U> {
+    pub planes: [Plane3D<T, U>; 6],
+}
+
+impl<T: Hash, U> Hash for Frustum<T, U> {
+    fn hash<H: Hasher>(&self, h: &mut H) {
+        self.planes.hash(h);
+    }
+}
+
+impl<T: Copy, U> Copy for Frustum<T, U> {}
+
+impl<T: Clone, U> Clone for Frustum<T, U> {
+    fn clone(&self) -> Self {
+        Self::new(self.planes.clone())
+    }
+}
+
+impl<T: PartialEq, U> PartialEq for Frustum<T, U> {
+    fn eq(&self, other: &Self) -> bool {
+        self.planes.eq(&other.planes)
+    }
+}
+
+impl<T: Eq, U> Eq for Frustum<T, U> {}
+
+impl<T: fmt::Debug, U> fmt::Debug for Frustum<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Frustum").field(&self.planes).finish()
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, T, U> arbitrary::Arbitrary<'a> for Frustum<T, U>
+where
+    T: arbitrary::Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self>
+    {
+        let planes = arbitrary::Arbitrary::arbitrary(u)?;
+        Ok(Frustum { planes })
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: Zeroable, U> Zeroable for Frustum<T, U> {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: Pod, U: 'static> Pod for Frustum<T, U> {}
+
+impl<T, U> Frustum<T, U> {
+    /// Constructor, taking the planes in left, right, bottom, top, near, far order.
+    #[inline]
+    pub const fn new(planes: [Plane3D<T, U>; 6]) -> Self {
+        Frustum { planes }
+    }
+}
+
+impl<T: Copy, U> Frustum<T, U> {
+    /// Drop the units, preserving only the numeric value.
+    #[inline]
+    pub fn to_untyped(&self) -> Frustum<T, UnknownUnit> {
+        self.cast_unit()
+    }
+
+    /// Tag a unitless value with units.
+    #[inline]
+    pub fn from_untyped(f: &Frustum<T, UnknownUnit>) -> Self {
+        f.cast_unit()
+    }
+
+    /// Cast the unit
+    #[inline]
+    pub fn cast_unit<V>(&self) -> Frustum<T, V> {
+        let p = &self.planes;
+        Frustum::new([
+            p[0].cast_unit(),
+            p[1].cast_unit(),
+            p[2].cast_unit(),
+            p[3].cast_unit(),
+            p[4].cast_unit(),
+            p[5].cast_unit(),
+        ])
+    }
+}
+
+impl<T: Real, U> Frustum<T, U> {
+    /// Extracts the frustum of a view-projection transform, that is the volume of the
+    /// source space that is mapped to the clip volume.
+    ///
+    /// `depth_range` must be the depth range of the projection (see [`DepthRange`]).
+    /// The planes are normalized, except for planes at infinity which are kept as is.
+    ///
+    /// [`DepthRange`]: enum.DepthRange.html
+    pub fn from_transform<Dst>(transform: &Transform3D<T, U, Dst>, depth_range: DepthRange) -> Self {
+        // See "Fast Extraction of Viewing Frustum Planes from the World-View-Projection
+        // Matrix" by Gil Gribb and Klaus Hartmann.
+        let m = transform;
+        let x = [m.m11, m.m21, m.m31, m.m41];
+        let y = [m.m12, m.m22, m.m32, m.m42];
+        let z = [m.m13, m.m23, m.m33, m.m43];
+        let w = [m.m14, m.m24, m.m34, m.m44];
+
+        let plane = |a: [T; 4], sign: T, b: [T; 4]| {
+            let plane = Plane3D::new(
+                vec3(a[0] + sign * b[0], a[1] + sign * b[1], a[2] + sign * b[2]),
+                a[3] + sign * b[3],
+            );
+            plane.try_normalize().unwrap_or(plane)
+        };
+
+        let zero = T::zero();
+        let one = T::one();
+        let near = match depth_range {
+            DepthRange::ZeroToOne => plane(z, zero, z),
+            DepthRange::NegativeOneToOne => plane(w, one, z),
+        };
+
+        Frustum::new([
+            plane(w, one, x),
+            plane(w, -one, x),
+            plane(w, one, y),
+            plane(w, -one, y),
+            near,
+            plane(w, -one, z),
+        ])
+    }
+
+    /// Returns `true` if the point is inside of the frustum or on its boundary.
+    pub fn contains_point(&self, p: Point3D<T, U>) -> bool {
+        self.planes
+            .iter()
+            .all(|plane| plane.signed_distance_to_point(p) >= T::zero())
+    }
+
+    /// Tests whether a box is inside of the frustum.
+    ///
+    /// The test is conservative: boxes that are close to the frustum without touching
+    /// it can be reported as intersecting, but visible boxes are never reported as
+    /// outside. Empty boxes are always outside.
+    pub fn classify_box(&self, b: &Box3D<T, U>) -> Containment {
+        if b.is_empty() {
+            return Containment::Outside;
+        }
+
+        let mut result = Containment::Inside;
+        for plane in &self.planes {
+            let n = plane.normal;
+            // The corners of the box that are the furthest along the normal in the
+            // positive and negative directions.
+            let pick = |positive: bool| {
+                point3(
+                    if (n.x >= T::zero()) == positive { b.max.x } else { b.min.x },
+                    if (n.y >= T::zero()) == positive { b.max.y } else { b.min.y },
+                    if (n.z >= T::zero()) == positive { b.max.z } else { b.min.z },
+                )
+            };
+
+            if plane.signed_distance_to_point(pick(true)) < T::zero() {
+                return Containment::Outside;
+            }
+            if plane.signed_distance_to_point(pick(false)) < T::zero() {
+                result = Containment::Intersects;
+            }
+        }
+
+        result
+    }
+
+    /// Tests whether a sphere is inside of the frustum.
+    ///
+    /// Like [`classify_box`](#method.classify_box), the test is conservative.
+    pub fn classify_sphere(&self, center: Point3D<T, U>, radius: T) -> Containment {
+        let mut result = Containment::Inside;
+        for plane in &self.planes {
+            let d = plane.signed_distance_to_point(center);
+            if d < -radius {
+                return Containment::Outside;
+            }
+            if d < radius {
+                result = Containment::Intersects;
+            }
+        }
+
+        result
+    }
+
+    /// Returns the eight corners of the frustum.
+    ///
+    /// The bits of the index of each corner select its planes: the first bit selects
+    /// right over left, the second one top over bottom and the third one far over near.
+    ///
+    /// Returns `None` if some of the corners are not defined, for example if the far
+    /// plane is at infinity.
+    pub fn corners(&self) -> Option<[Point3D<T, U>; 8]> {
+        let mut corners = [Point3D::origin(); 8];
+        for (i, corner) in corners.iter_mut().enumerate() {
+            *corner = intersect_planes(
+                &self.planes[i & 1],
+                &self.planes[2 + ((i >> 1) & 1)],
+                &self.planes[4 + ((i >> 2) & 1)],
+            )?;
+        }
+
+        Some(corners)
+    }
+}
+
+/// Returns the point at the intersection of three planes, if any.
+fn intersect_planes<T: Real, U>(
+    a: &Plane3D<T, U>,
+    b: &Plane3D<T, U>,
+    c: &Plane3D<T, U>,
+) -> Option<Point3D<T, U>> {
+    let bc = b.normal.cross(c.normal);
+    let denom = a.normal.dot(bc);
+    if denom.abs() <= T::epsilon() {
+        return None;
+    }
+
+    let v: Vector3D<T, U> = bc * a.offset
+        + c.normal.cross(a.normal) * b.offset
+        + a.normal.cross(b.normal) * c.offset;
+    Some((v / -denom).to_point())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Containment;
+    use crate::approxeq::ApproxEq;
+    use crate::default::{Box3D, Frustum, Transform3D};
+    use crate::{point3, vec3, Angle, DepthRange, Handedness};
+    use core::f64::consts::FRAC_PI_2;
+
+    fn camera(
+        eye: [f64; 3],
+        target: [f64; 3],
+        handedness: Handedness,
+        depth_range: DepthRange,
+    ) -> Transform3D<f64> {
+        let view = Transform3D::look_at(
+            point3(eye[0], eye[1], eye[2]),
+            point3(target[0], target[1], target[2]),
+            vec3(0.0, 1.0, 0.0),
+            handedness,
+        );
+        let projection = Transform3D::perspective_fov(
+            Angle::radians(FRAC_PI_2),
+            1.0,
+            1.0,
+            100.0,
+            handedness,
+            depth_range,
+        );
+        view.then(&projection)
+    }
+
+    #[test]
+    fn test_from_transform() {
+        let range = DepthRange::NegativeOneToOne;
+        let m = camera([0.0; 3], [0.0, 0.0, -1.0], Handedness::RightHanded, range);
+        let f = Frustum::from_transform(&m, range);
+
+        assert!(f.contains_point(point3(0.0, 0.0, -50.0)));
+        assert!(f.contains_point(point3(0.0, 0.0, -1.001)));
+        assert!(f.contains_point(point3(9.0, -9.0, -10.0)));
+        assert!(!f.contains_point(point3(0.0, 0.0, -0.5)));
+        assert!(!f.contains_point(point3(0.0, 0.0, -101.0)));
+        assert!(!f.contains_point(point3(11.0, 0.0, -10.0)));
+        assert!(!f.contains_point(point3(0.0, 0.0, 10.0)));
+
+        for plane in &f.planes {
+            assert!(plane.normal.length().approx_eq(&1.0));
+        }
+        assert!(f.planes[4].offset.approx_eq(&-1.0));
+        assert!(f.planes[5].offset.approx_eq(&100.0));
+    }
+
+    #[test]
+    fn test_corners() {
+        for &handedness in &[Handedness::RightHanded, Handedness::LeftHanded] {
+            for &range in &[DepthRange::ZeroToOne, DepthRange::NegativeOneToOne] {
+                let m = camera([0.0; 3], [0.0, 0.0, 1.0], handedness, range);
+                let corners = Frustum::from_transform(&m, range).corners().unwrap();
+                for (i, corner) in corners.iter().enumerate() {
+                    let far = if i & 4 == 0 { 1.0 } else { 100.0 };
+                    let x = if i & 1 == 0 { -far } else { far };
+                    let y = if i & 2 == 0 { -far } else { far };
+                    // The camera looks towards positive z and x points to the left
+                    // in right handed view spaces.
+                    let x = if handedness == Handedness::RightHanded { -x } else { x };
+                    assert!(corner.approx_eq_eps(&point3(x, y, far), &point3(1e-6, 1e-6, 1e-6)), "{} {:?}", i, corner);
+                }
+            }
+        }
+
+        let infinite = Transform3D::perspective_fov_infinite(
+            Angle::radians(FRAC_PI_2),
+            1.0,
+            1.0,
+            Handedness::RightHanded,
+            DepthRange::ZeroToOne,
+        );
+        assert!(Frustum::from_transform(&infinite, DepthRange::ZeroToOne).corners().is_none());
+    }
+
+    #[test]
+    fn test_classify_box() {
+        let cameras = [
+            ([0.0, 0.0, 0.0], [0.0, 0.0, -1.0]),
+            ([10.0, 5.0, -20.0], [-3.0, 2.0, 7.0]),
+            ([-4.0, 30.0, 2.0], [1.0, 0.0, 1.0]),
+            ([2.0, -1.0, 40.0], [0.0, 0.0, 0.0]),
+        ];
+        let boxes = [
+            Box3D::new(point3(-1.0, -1.0, -1.0), point3(1.0, 1.0, 1.0)),
+            Box3D::new(point3(-5.0, -2.0, -30.0), point3(5.0, 2.0, -10.0)),
+            Box3D::new(point3(-200.0, -200.0, -200.0), point3(200.0, 200.0, 200.0)),
+            Box3D::new(point3(3.0, 0.0, 0.5), point3(4.0, 0.5, 2.0)),
+            Box3D::new(point3(20.0, 1.0, 0.0), point3(21.0, 30.0, 3.0)),
+            Box3D::new(point3(0.0, 0.0, 30.0), point3(0.1, 0.1, 30.1)),
+        ];
+
+        for &(eye, target) in &cameras {
+            for &handedness in &[Handedness::RightHanded, Handedness::LeftHanded] {
+                for &range in &[DepthRange::ZeroToOne, DepthRange::NegativeOneToOne] {
+                    let m = camera(eye, target, handedness, range);
+                    let f = Frustum::from_transform(&m, range);
+                    for b in &boxes {
+                        // Sample points of the box to check that the classification is
+                        // consistent with contains_point.
+                        let mut inside = 0;
+                        let n = 8;
+                        for i in 0..=n {
+                            for j in 0..=n {
+                                for k in 0..=n {
+                                    let t = |i: usize| i as f64 / n as f64;
+                                    let p = point3(
+                                        b.min.x + (b.max.x - b.min.x) * t(i),
+                                        b.min.y + (b.max.y - b.min.y) * t(j),
+                                        b.min.z + (b.max.z - b.min.z) * t(k),
+                                    );
+                                    if f.contains_point(p) {
+                                        inside += 1;
+                                    }
+                                }
+                            }
+                        }
+                        let total = (n + 1) * (n + 1) * (n + 1);
+
+                        match f.classify_box(b) {
+                            Containment::Outside => assert_eq!(inside, 0, "{:?} {:?}", eye, b),
+                            Containment::Inside => assert_eq!(inside, total, "{:?} {:?}", eye, b),
+                            Containment::Intersects => assert!(inside < total, "{:?} {:?}", eye, b),
+                        }
+                    }
+                }
+            }
+        }
+
+        let range = DepthRange::ZeroToOne;
+        let m = camera([0.0; 3], [0.0, 0.0, -1.0], Handedness::RightHanded, range);
+        let f = Frustum::from_transform(&m, range);
+        let inside = Box3D::new(point3(-1.0, -1.0, -20.0), point3(1.0, 1.0, -10.0));
+        let straddling = Box3D::new(point3(-1.0, -1.0, -20.0), point3(30.0, 1.0, -10.0));
+        let behind = Box3D::new(point3(-1.0, -1.0, 1.0), point3(1.0, 1.0, 2.0));
+        assert_eq!(f.classify_box(&inside), Containment::Inside);
+        assert_eq!(f.classify_box(&straddling), Containment::Intersects);
+        assert_eq!(f.classify_box(&behind), Containment::Outside);
+        assert_eq!(f.classify_box(&Box3D::zero()), Containment::Outside);
+    }
+
+    #[test]
+    fn test_classify_sphere() {
+        let range = DepthRange::ZeroToOne;
+        let m = camera([0.0; 3], [0.0, 0.0, -1.0], Handedness::RightHanded, range);
+        let f = Frustum::from_transform(&m, range);
+        assert_eq!(f.classify_sphere(point3(0.0, 0.0, -50.0), 10.0), Containment::Inside);
+        assert_eq!(f.classify_sphere(point3(0.0, 0.0, -50.0), 60.0), Containment::Intersects);
+        assert_eq!(f.classify_sphere(point3(0.0, 0.0, 0.0), 2.0), Containment::Intersects);
+        assert_eq!(f.classify_sphere(point3(0.0, 0.0, 3.0), 2.0), Containment::Outside);
+        assert_eq!(f.classify_sphere(point3(30.0, 0.0, -10.0), 5.0), Containment::Outside);
+    }
+}
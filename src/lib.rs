@@ -40,23 +40,52 @@
 //!
 #![deny(unconditional_recursion)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 pub use crate::angle::Angle;
 pub use crate::box2d::Box2D;
+pub use crate::decomposition::{Decomposed2D, Decomposed3D};
+pub use crate::fit::{Align, Alignment, MeetOrSlice, ObjectFit};
 pub use crate::homogen::HomogeneousVector;
 pub use crate::length::Length;
+#[cfg(feature = "alloc")]
+pub use crate::multi_polygon::{BooleanOp, MultiPolygon2D};
+pub use crate::nine_slice::RepeatMode;
+pub use crate::plane::Plane3D;
 pub use crate::point::{point2, point3, Point2D, Point3D};
+#[cfg(feature = "alloc")]
+pub use crate::polygon::{FillRule, Orientation, Polygon2D};
 pub use crate::scale::Scale;
 pub use crate::transform2d::Transform2D;
-pub use crate::transform3d::Transform3D;
+pub use crate::transform3d::{ClippedQuad, DepthRange, Handedness, Transform3D};
 pub use crate::vector::{bvec2, bvec3, BoolVector2D, BoolVector3D};
 pub use crate::vector::{vec2, vec3, Vector2D, Vector3D};
 
+pub use crate::bezier::{
+    CubicBezierSegment, CubicBezierSegment3D, FlattenedBezier, QuadraticBezierSegment,
+    QuadraticBezierSegment3D,
+};
 pub use crate::box3d::{box3d, Box3D};
+pub use crate::circle::Circle;
+pub use crate::ellipse::{Arc, Ellipse, FlattenedArc, SvgArc};
+pub use crate::frustum::{Containment, Frustum};
+pub use crate::line::Line2D;
+pub use crate::logical::{
+    Direction, LogicalPoint, LogicalRect, LogicalSides, LogicalSize, WritingMode,
+};
+pub use crate::oriented_box::{OrientedBox2D, OrientedBox3D};
+pub use crate::ray::{Ray2D, Ray3D};
 pub use crate::rect::{rect, Rect};
+#[cfg(feature = "alloc")]
+pub use crate::region::Region;
 pub use crate::rigid::RigidTransform3D;
-pub use crate::rotation::{Rotation2D, Rotation3D};
+pub use crate::rotation::{EulerOrder, Rotation2D, Rotation3D};
+pub use crate::rounded_rect::RoundedRect;
+pub use crate::segment::{LineSegment2D, LineSegment3D};
 pub use crate::side_offsets::SideOffsets2D;
 pub use crate::size::{size2, size3, Size2D, Size3D};
+pub use crate::sphere::Sphere;
 pub use crate::translation::{Translation2D, Translation3D};
 pub use crate::trig::Trig;
 
@@ -66,18 +95,40 @@ mod macros;
 mod angle;
 pub mod approxeq;
 pub mod approxord;
+mod bezier;
 mod box2d;
 mod box3d;
+mod circle;
+mod decomposition;
+mod ellipse;
+mod fit;
+mod frustum;
 mod homogen;
 mod length;
+mod line;
+mod logical;
+#[cfg(feature = "alloc")]
+mod multi_polygon;
+mod nine_slice;
+mod oriented_box;
 pub mod num;
+mod plane;
 mod point;
+#[cfg(feature = "alloc")]
+mod polygon;
+mod ray;
 mod rect;
+#[cfg(feature = "alloc")]
+mod region;
 mod rigid;
 mod rotation;
+mod rounded_rect;
 mod scale;
+mod segment;
 mod side_offsets;
 mod size;
+mod sphere;
+mod tile;
 mod transform2d;
 mod transform3d;
 mod translation;
@@ -93,17 +144,28 @@ pub mod default {
 
     use super::UnknownUnit;
     pub type Length<T> = super::Length<T, UnknownUnit>;
+    #[cfg(feature = "alloc")]
+    pub type MultiPolygon2D<T> = super::MultiPolygon2D<T, UnknownUnit>;
     pub type Point2D<T> = super::Point2D<T, UnknownUnit>;
     pub type Point3D<T> = super::Point3D<T, UnknownUnit>;
+    #[cfg(feature = "alloc")]
+    pub type Polygon2D<T> = super::Polygon2D<T, UnknownUnit>;
     pub type Vector2D<T> = super::Vector2D<T, UnknownUnit>;
     pub type Vector3D<T> = super::Vector3D<T, UnknownUnit>;
     pub type HomogeneousVector<T> = super::HomogeneousVector<T, UnknownUnit>;
     pub type Size2D<T> = super::Size2D<T, UnknownUnit>;
     pub type Size3D<T> = super::Size3D<T, UnknownUnit>;
     pub type Rect<T> = super::Rect<T, UnknownUnit>;
+    #[cfg(feature = "alloc")]
+    pub type Region<T> = super::Region<T, UnknownUnit>;
     pub type Box2D<T> = super::Box2D<T, UnknownUnit>;
+    pub type RoundedRect<T> = super::RoundedRect<T, UnknownUnit>;
     pub type Box3D<T> = super::Box3D<T, UnknownUnit>;
     pub type SideOffsets2D<T> = super::SideOffsets2D<T, UnknownUnit>;
+    pub type LogicalPoint<T> = super::LogicalPoint<T, UnknownUnit>;
+    pub type LogicalSize<T> = super::LogicalSize<T, UnknownUnit>;
+    pub type LogicalRect<T> = super::LogicalRect<T, UnknownUnit>;
+    pub type LogicalSides<T> = super::LogicalSides<T, UnknownUnit>;
     pub type Transform2D<T> = super::Transform2D<T, UnknownUnit, UnknownUnit>;
     pub type Transform3D<T> = super::Transform3D<T, UnknownUnit, UnknownUnit>;
     pub type Rotation2D<T> = super::Rotation2D<T, UnknownUnit, UnknownUnit>;
@@ -112,4 +174,24 @@ pub mod default {
     pub type Translation3D<T> = super::Translation3D<T, UnknownUnit, UnknownUnit>;
     pub type Scale<T> = super::Scale<T, UnknownUnit, UnknownUnit>;
     pub type RigidTransform3D<T> = super::RigidTransform3D<T, UnknownUnit, UnknownUnit>;
+    pub type LineSegment2D<T> = super::LineSegment2D<T, UnknownUnit>;
+    pub type LineSegment3D<T> = super::LineSegment3D<T, UnknownUnit>;
+    pub type QuadraticBezierSegment<T> = super::QuadraticBezierSegment<T, UnknownUnit>;
+    pub type CubicBezierSegment<T> = super::CubicBezierSegment<T, UnknownUnit>;
+    pub type QuadraticBezierSegment3D<T> = super::QuadraticBezierSegment3D<T, UnknownUnit>;
+    pub type CubicBezierSegment3D<T> = super::CubicBezierSegment3D<T, UnknownUnit>;
+    pub type Line2D<T> = super::Line2D<T, UnknownUnit>;
+    pub type Ray2D<T> = super::Ray2D<T, UnknownUnit>;
+    pub type Ray3D<T> = super::Ray3D<T, UnknownUnit>;
+    pub type Plane3D<T> = super::Plane3D<T, UnknownUnit>;
+    pub type Frustum<T> = super::Frustum<T, UnknownUnit>;
+    pub type OrientedBox2D<T> = super::OrientedBox2D<T, UnknownUnit>;
+    pub type OrientedBox3D<T> = super::OrientedBox3D<T, UnknownUnit>;
+    pub type Circle<T> = super::Circle<T, UnknownUnit>;
+    pub type Sphere<T> = super::Sphere<T, UnknownUnit>;
+    pub type Ellipse<T> = super::Ellipse<T, UnknownUnit>;
+    pub type Arc<T> = super::Arc<T, UnknownUnit>;
+    pub type SvgArc<T> = super::SvgArc<T, UnknownUnit>;
+    pub type Decomposed2D<T> = super::Decomposed2D<T, UnknownUnit, UnknownUnit>;
+    pub type Decomposed3D<T> = super::Decomposed3D<T, UnknownUnit, UnknownUnit>;
 }
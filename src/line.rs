@@ -0,0 +1,246 @@
+// Copyright 2013 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::UnknownUnit;
+use crate::approxeq::ApproxEq;
+use crate::point::Point2D;
+use crate::segment::LineSegment2D;
+use crate::transform2d::Transform2D;
+use crate::vector::Vector2D;
+
+use num_traits::real::Real;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "bytemuck")]
+use bytemuck::{Zeroable, Pod};
+
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::{Add, Mul, Sub};
+
+/// An infinite 2d line, represented by a point on the line and a direction vector.
+///
+/// The direction vector does not need to be normalized, however a line with
+/// a zero-length direction vector is degenerate.
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Serialize", deserialize = "T: Deserialize<'de>"))
+)]
+pub struct Line2D<T, U> {
+    pub point: Point2D<T, U>,
+    pub vector: Vector2D<T, U>,
+}
+
+impl<T: Hash, U> Hash for Line2D<T, U> {
+    fn hash<H: Hasher>(&self, h: &mut H) {
+        self.point.hash(h);
+        self.vector.hash(h);
+    }
+}
+
+impl<T: Copy, U> Copy for Line2D<T, U> {}
+
+impl<T: Clone, U> Clone for Line2D<T, U> {
+    fn clone(&self) -> Self {
+        Self::new(self.point.clone(), self.vector.clone())
+    }
+}
+
+impl<T: PartialEq, U> PartialEq for Line2D<T, U> {
+    fn eq(&self, other: &Self) -> bool {
+        self.point.eq(&other.point) && self.vector.eq(&other.vector)
+    }
+}
+
+impl<T: Eq, U> Eq for Line2D<T, U> {}
+
+impl<T: fmt::Debug, U> fmt::Debug for Line2D<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Line2D")
+            .field(&self.point)
+            .field(&self.vector)
+            .finish()
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, T, U> arbitrary::Arbitrary<'a> for Line2D<T, U>
+where
+    T: arbitrary::Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self>
+    {
+        let (point, vector) = arbitrary::Arbitrary::arbitrary(u)?;
+        Ok(Line2D {
+            point,
+            vector,
+        })
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: Zeroable, U> Zeroable for Line2D<T, U> {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: Pod, U: 'static> Pod for Line2D<T, U> {}
+
+impl<T, U> Line2D<T, U> {
+    /// Constructor.
+    #[inline]
+    pub const fn new(point: Point2D<T, U>, vector: Vector2D<T, U>) -> Self {
+        Line2D { point, vector }
+    }
+}
+
+impl<T: Copy, U> Line2D<T, U> {
+    /// Drop the units, preserving only the numeric value.
+    #[inline]
+    pub fn to_untyped(&self) -> Line2D<T, UnknownUnit> {
+        Line2D::new(self.point.to_untyped(), self.vector.to_untyped())
+    }
+
+    /// Tag a unitless value with units.
+    #[inline]
+    pub fn from_untyped(l: &Line2D<T, UnknownUnit>) -> Self {
+        Line2D::new(Point2D::from_untyped(l.point), Vector2D::from_untyped(l.vector))
+    }
+
+    /// Cast the unit
+    #[inline]
+    pub fn cast_unit<V>(&self) -> Line2D<T, V> {
+        Line2D::new(self.point.cast_unit(), self.vector.cast_unit())
+    }
+}
+
+impl<T, U> Line2D<T, U>
+where
+    T: Copy + Add<Output = T> + Mul<Output = T>,
+{
+    /// Returns the point of the line at parameter `t`, that is `point + vector * t`.
+    #[inline]
+    pub fn sample(&self, t: T) -> Point2D<T, U> {
+        self.point + self.vector * t
+    }
+
+    /// Applies the transform to the line.
+    #[inline]
+    pub fn transform<Dst>(&self, transform: &Transform2D<T, U, Dst>) -> Line2D<T, Dst> {
+        Line2D::new(
+            transform.transform_point(self.point),
+            transform.transform_vector(self.vector),
+        )
+    }
+}
+
+impl<T: Real, U> Line2D<T, U> {
+    /// Returns the signed distance between the line and a point.
+    ///
+    /// The distance is positive for points on the left side of the line, that is
+    /// in the direction of the vector rotated by 90 degrees counter-clockwise (in
+    /// a y-up coordinate system), and negative on the other side.
+    #[inline]
+    pub fn signed_distance_to_point(&self, p: Point2D<T, U>) -> T {
+        self.vector.cross(p - self.point) / self.vector.length()
+    }
+
+    /// Returns the distance between the line and a point.
+    #[inline]
+    pub fn distance_to_point(&self, p: Point2D<T, U>) -> T {
+        self.signed_distance_to_point(p).abs()
+    }
+
+    /// Returns the point of the line that is closest to `p`.
+    #[inline]
+    pub fn closest_point(&self, p: Point2D<T, U>) -> Point2D<T, U> {
+        let t = (p - self.point).dot(self.vector) / self.vector.square_length();
+        self.sample(t)
+    }
+
+    /// Computes the intersection of two lines, returning the parameter of the
+    /// intersection point on `self` and on `other` respectively.
+    ///
+    /// Returns `None` if the lines are parallel.
+    pub fn intersection_t(&self, other: &Self) -> Option<(T, T)> {
+        let denom = self.vector.cross(other.vector);
+        if denom == T::zero() {
+            return None;
+        }
+
+        let v = other.point - self.point;
+        Some((v.cross(other.vector) / denom, v.cross(self.vector) / denom))
+    }
+
+    /// Computes the intersection point of two lines.
+    ///
+    /// Returns `None` if the lines are parallel.
+    #[inline]
+    pub fn intersection(&self, other: &Self) -> Option<Point2D<T, U>> {
+        self.intersection_t(other).map(|(t, _)| self.sample(t))
+    }
+}
+
+impl<T: ApproxEq<T> + Real, U> Line2D<T, U> {
+    /// Returns `true` if the two lines have approximately the same direction,
+    /// ignoring orientation.
+    #[inline]
+    pub fn is_parallel(&self, other: &Self) -> bool {
+        let cross = self.vector.cross(other.vector) / (self.vector.length() * other.vector.length());
+        cross.approx_eq(&T::zero())
+    }
+}
+
+impl<T, U> LineSegment2D<T, U>
+where
+    T: Copy + Sub<Output = T>,
+{
+    /// Returns the infinite line going through both endpoints of the segment.
+    #[inline]
+    pub fn to_line(&self) -> Line2D<T, U> {
+        Line2D::new(self.from, self.to_vector())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::default::{Line2D, LineSegment2D};
+    use crate::{point2, vec2};
+
+    #[test]
+    fn test_signed_distance() {
+        let l = Line2D::new(point2(1.0, 1.0), vec2(2.0, 0.0));
+        assert_eq!(l.signed_distance_to_point(point2(5.0, 4.0)), 3.0);
+        assert_eq!(l.signed_distance_to_point(point2(-5.0, -1.0)), -2.0);
+        assert_eq!(l.distance_to_point(point2(-5.0, -1.0)), 2.0);
+        assert_eq!(l.signed_distance_to_point(point2(0.0, 1.0)), 0.0);
+        assert_eq!(l.closest_point(point2(3.0, 7.0)), point2(3.0, 1.0));
+    }
+
+    #[test]
+    fn test_intersection() {
+        let a = Line2D::new(point2(0.0, 0.0), vec2(1.0, 1.0));
+        let b = Line2D::new(point2(4.0, 0.0), vec2(-1.0, 1.0));
+        assert_eq!(a.intersection(&b), Some(point2(2.0, 2.0)));
+        assert_eq!(a.intersection_t(&b), Some((2.0, 2.0)));
+
+        let c = Line2D::new(point2(0.0, 1.0), vec2(-2.0, -2.0));
+        assert!(a.is_parallel(&c));
+        assert_eq!(a.intersection(&c), None);
+        assert!(!a.is_parallel(&b));
+    }
+
+    #[test]
+    fn test_segment_to_line() {
+        let s = LineSegment2D::new(point2(1.0, 2.0), point2(3.0, 5.0));
+        let l = s.to_line();
+        assert_eq!(l.point, s.from);
+        assert_eq!(l.sample(1.0), s.to);
+    }
+}
@@ -0,0 +1,812 @@
+// Copyright 2013 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Geometry in the inline and block directions of a writing mode, which correspond to the
+//! logical properties of CSS.
+
+use crate::num::Zero;
+use crate::point::{point2, Point2D};
+use crate::rect::Rect;
+use crate::side_offsets::SideOffsets2D;
+use crate::size::{size2, Size2D};
+use core::cmp::{Eq, PartialEq};
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::ops::{Add, Sub};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "bytemuck")]
+use bytemuck::{Zeroable, Pod};
+
+/// The direction of the text inside of a line, like the CSS `direction` property.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Direction {
+    Ltr,
+    Rtl,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+enum Flow {
+    HorizontalTb,
+    VerticalRl,
+    VerticalLr,
+    SidewaysRl,
+    SidewaysLr,
+}
+
+/// How the inline and block directions map to the physical axes, from the values of
+/// the CSS `writing-mode` and `direction` properties.
+///
+/// The physical y axis points down, so that the top of the screen has the smallest y
+/// coordinate.
+///
+/// ```
+/// use euclid::{Direction, WritingMode};
+///
+/// let mode = WritingMode::VERTICAL_RL.with_direction(Direction::Rtl);
+/// assert!(mode.is_vertical());
+/// assert_eq!(mode.direction(), Direction::Rtl);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WritingMode {
+    flow: Flow,
+    direction: Direction,
+}
+
+impl WritingMode {
+    /// Lines go from top to bottom, and text from left to right.
+    pub const HORIZONTAL_TB: Self = WritingMode::new(Flow::HorizontalTb);
+    /// Lines go from right to left, and text from top to bottom.
+    pub const VERTICAL_RL: Self = WritingMode::new(Flow::VerticalRl);
+    /// Lines go from left to right, and text from top to bottom.
+    pub const VERTICAL_LR: Self = WritingMode::new(Flow::VerticalLr);
+    /// Like `VERTICAL_RL`, with all of the glyphs set sideways.
+    pub const SIDEWAYS_RL: Self = WritingMode::new(Flow::SidewaysRl);
+    /// Lines go from left to right, and text from bottom to top, with all of the
+    /// glyphs set sideways.
+    pub const SIDEWAYS_LR: Self = WritingMode::new(Flow::SidewaysLr);
+
+    const fn new(flow: Flow) -> Self {
+        WritingMode {
+            flow,
+            direction: Direction::Ltr,
+        }
+    }
+
+    /// Returns the same writing mode with another direction.
+    ///
+    /// Right to left text goes in the opposite direction along the lines, so for
+    /// example from bottom to top in `VERTICAL_RL`.
+    #[inline]
+    pub const fn with_direction(self, direction: Direction) -> Self {
+        WritingMode {
+            flow: self.flow,
+            direction,
+        }
+    }
+
+    /// Returns the direction of the text inside of a line.
+    #[inline]
+    pub fn direction(self) -> Direction {
+        self.direction
+    }
+
+    /// Returns `true` if the lines are vertical, so that the inline axis is the
+    /// physical y axis.
+    #[inline]
+    pub fn is_vertical(self) -> bool {
+        self.flow != Flow::HorizontalTb
+    }
+
+    /// Returns `true` if the glyphs are set sideways.
+    #[inline]
+    pub fn is_sideways(self) -> bool {
+        matches!(self.flow, Flow::SidewaysRl | Flow::SidewaysLr)
+    }
+
+    /// Returns `true` if the inline direction goes towards decreasing physical
+    /// coordinates, from right to left or from bottom to top.
+    #[inline]
+    pub fn is_inline_reversed(self) -> bool {
+        // Sideways-lr text goes up even in the left-to-right direction.
+        (self.flow == Flow::SidewaysLr) != (self.direction == Direction::Rtl)
+    }
+
+    /// Returns `true` if the block direction goes towards decreasing physical
+    /// coordinates, which is the case when lines go from right to left.
+    #[inline]
+    pub fn is_block_reversed(self) -> bool {
+        matches!(self.flow, Flow::VerticalRl | Flow::SidewaysRl)
+    }
+
+    /// Returns the extents of a physical size in the inline and block directions.
+    fn logical_extents<T: Copy, U>(self, size: Size2D<T, U>) -> (T, T) {
+        if self.is_vertical() {
+            (size.height, size.width)
+        } else {
+            (size.width, size.height)
+        }
+    }
+}
+
+impl Default for WritingMode {
+    fn default() -> Self {
+        WritingMode::HORIZONTAL_TB
+    }
+}
+
+/// Flips a coordinate along an axis going in the reversed direction, which is its
+/// own inverse.
+fn flip<T: Copy + Sub<Output = T>>(reversed: bool, extent: T, x: T) -> T {
+    if reversed {
+        extent - x
+    } else {
+        x
+    }
+}
+
+/// A point in inline and block coordinates, from the start corner of a container.
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Serialize", deserialize = "T: Deserialize<'de>"))
+)]
+pub struct LogicalPoint<T, U> {
+    pub i: T,
+    pub b: T,
+    #[doc(hidden)]
+    pub _unit: PhantomData<U>,
+}
+
+impl<T: Copy, U> Copy for LogicalPoint<T, U> {}
+
+impl<T: Clone, U> Clone for LogicalPoint<T, U> {
+    fn clone(&self) -> Self {
+        LogicalPoint::new(self.i.clone(), self.b.clone())
+    }
+}
+
+impl<T: PartialEq, U> PartialEq for LogicalPoint<T, U> {
+    fn eq(&self, other: &Self) -> bool {
+        self.i == other.i && self.b == other.b
+    }
+}
+
+impl<T: Eq, U> Eq for LogicalPoint<T, U> {}
+
+impl<T: Hash, U> Hash for LogicalPoint<T, U> {
+    fn hash<H: Hasher>(&self, h: &mut H) {
+        self.i.hash(h);
+        self.b.hash(h);
+    }
+}
+
+impl<T: fmt::Debug, U> fmt::Debug for LogicalPoint<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("LogicalPoint")
+            .field(&self.i)
+            .field(&self.b)
+            .finish()
+    }
+}
+
+impl<T: Default, U> Default for LogicalPoint<T, U> {
+    fn default() -> Self {
+        LogicalPoint::new(Default::default(), Default::default())
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, T, U> arbitrary::Arbitrary<'a> for LogicalPoint<T, U>
+where
+    T: arbitrary::Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self>
+    {
+        let (i, b) = arbitrary::Arbitrary::arbitrary(u)?;
+        Ok(LogicalPoint::new(i, b))
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: Zeroable, U> Zeroable for LogicalPoint<T, U> {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: Pod, U: 'static> Pod for LogicalPoint<T, U> {}
+
+impl<T, U> LogicalPoint<T, U> {
+    /// Constructor taking the inline and block coordinates.
+    #[inline]
+    pub const fn new(i: T, b: T) -> Self {
+        LogicalPoint {
+            i,
+            b,
+            _unit: PhantomData,
+        }
+    }
+}
+
+impl<T: Zero, U> LogicalPoint<T, U> {
+    /// Constructor, setting all components to zero.
+    #[inline]
+    pub fn zero() -> Self {
+        LogicalPoint::new(Zero::zero(), Zero::zero())
+    }
+}
+
+impl<T: Copy + Sub<Output = T>, U> LogicalPoint<T, U> {
+    /// Converts to a physical point in a container of the given size.
+    pub fn to_physical(&self, mode: WritingMode, container_size: Size2D<T, U>) -> Point2D<T, U> {
+        let (inline_extent, block_extent) = mode.logical_extents(container_size);
+        let i = flip(mode.is_inline_reversed(), inline_extent, self.i);
+        let b = flip(mode.is_block_reversed(), block_extent, self.b);
+        if mode.is_vertical() {
+            point2(b, i)
+        } else {
+            point2(i, b)
+        }
+    }
+
+    /// Converts from a physical point in a container of the given size.
+    pub fn from_physical(
+        mode: WritingMode,
+        p: Point2D<T, U>,
+        container_size: Size2D<T, U>,
+    ) -> Self {
+        let (inline_extent, block_extent) = mode.logical_extents(container_size);
+        let (i, b) = mode.logical_extents(p.to_vector().to_size());
+        LogicalPoint::new(
+            flip(mode.is_inline_reversed(), inline_extent, i),
+            flip(mode.is_block_reversed(), block_extent, b),
+        )
+    }
+}
+
+/// A size in the inline and block directions.
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Serialize", deserialize = "T: Deserialize<'de>"))
+)]
+pub struct LogicalSize<T, U> {
+    pub inline: T,
+    pub block: T,
+    #[doc(hidden)]
+    pub _unit: PhantomData<U>,
+}
+
+impl<T: Copy, U> Copy for LogicalSize<T, U> {}
+
+impl<T: Clone, U> Clone for LogicalSize<T, U> {
+    fn clone(&self) -> Self {
+        LogicalSize::new(self.inline.clone(), self.block.clone())
+    }
+}
+
+impl<T: PartialEq, U> PartialEq for LogicalSize<T, U> {
+    fn eq(&self, other: &Self) -> bool {
+        self.inline == other.inline && self.block == other.block
+    }
+}
+
+impl<T: Eq, U> Eq for LogicalSize<T, U> {}
+
+impl<T: Hash, U> Hash for LogicalSize<T, U> {
+    fn hash<H: Hasher>(&self, h: &mut H) {
+        self.inline.hash(h);
+        self.block.hash(h);
+    }
+}
+
+impl<T: fmt::Debug, U> fmt::Debug for LogicalSize<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("LogicalSize")
+            .field(&self.inline)
+            .field(&self.block)
+            .finish()
+    }
+}
+
+impl<T: Default, U> Default for LogicalSize<T, U> {
+    fn default() -> Self {
+        LogicalSize::new(Default::default(), Default::default())
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, T, U> arbitrary::Arbitrary<'a> for LogicalSize<T, U>
+where
+    T: arbitrary::Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self>
+    {
+        let (inline, block) = arbitrary::Arbitrary::arbitrary(u)?;
+        Ok(LogicalSize::new(inline, block))
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: Zeroable, U> Zeroable for LogicalSize<T, U> {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: Pod, U: 'static> Pod for LogicalSize<T, U> {}
+
+impl<T, U> LogicalSize<T, U> {
+    /// Constructor taking the inline and block extents.
+    #[inline]
+    pub const fn new(inline: T, block: T) -> Self {
+        LogicalSize {
+            inline,
+            block,
+            _unit: PhantomData,
+        }
+    }
+}
+
+impl<T: Zero, U> LogicalSize<T, U> {
+    /// Constructor, setting all components to zero.
+    #[inline]
+    pub fn zero() -> Self {
+        LogicalSize::new(Zero::zero(), Zero::zero())
+    }
+}
+
+impl<T: Copy, U> LogicalSize<T, U> {
+    /// Converts to a physical size.
+    ///
+    /// Unlike positions, sizes do not depend on the size of the container.
+    #[inline]
+    pub fn to_physical(&self, mode: WritingMode) -> Size2D<T, U> {
+        if mode.is_vertical() {
+            size2(self.block, self.inline)
+        } else {
+            size2(self.inline, self.block)
+        }
+    }
+
+    /// Converts from a physical size.
+    #[inline]
+    pub fn from_physical(mode: WritingMode, size: Size2D<T, U>) -> Self {
+        let (inline, block) = mode.logical_extents(size);
+        LogicalSize::new(inline, block)
+    }
+}
+
+/// A rectangle in inline and block coordinates, represented by its start corner
+/// and its size.
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Serialize", deserialize = "T: Deserialize<'de>"))
+)]
+pub struct LogicalRect<T, U> {
+    pub start: LogicalPoint<T, U>,
+    pub size: LogicalSize<T, U>,
+}
+
+impl<T: Copy, U> Copy for LogicalRect<T, U> {}
+
+impl<T: Clone, U> Clone for LogicalRect<T, U> {
+    fn clone(&self) -> Self {
+        LogicalRect::new(self.start.clone(), self.size.clone())
+    }
+}
+
+impl<T: PartialEq, U> PartialEq for LogicalRect<T, U> {
+    fn eq(&self, other: &Self) -> bool {
+        self.start == other.start && self.size == other.size
+    }
+}
+
+impl<T: Eq, U> Eq for LogicalRect<T, U> {}
+
+impl<T: Hash, U> Hash for LogicalRect<T, U> {
+    fn hash<H: Hasher>(&self, h: &mut H) {
+        self.start.hash(h);
+        self.size.hash(h);
+    }
+}
+
+impl<T: fmt::Debug, U> fmt::Debug for LogicalRect<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("LogicalRect")
+            .field(&self.start)
+            .field(&self.size)
+            .finish()
+    }
+}
+
+impl<T: Default, U> Default for LogicalRect<T, U> {
+    fn default() -> Self {
+        LogicalRect::new(Default::default(), Default::default())
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, T, U> arbitrary::Arbitrary<'a> for LogicalRect<T, U>
+where
+    T: arbitrary::Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self>
+    {
+        let (start, size) = arbitrary::Arbitrary::arbitrary(u)?;
+        Ok(LogicalRect::new(start, size))
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: Zeroable, U> Zeroable for LogicalRect<T, U> {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: Pod, U: 'static> Pod for LogicalRect<T, U> {}
+
+impl<T, U> LogicalRect<T, U> {
+    /// Constructor.
+    #[inline]
+    pub const fn new(start: LogicalPoint<T, U>, size: LogicalSize<T, U>) -> Self {
+        LogicalRect { start, size }
+    }
+}
+
+impl<T: Zero, U> LogicalRect<T, U> {
+    /// Constructor, setting all sides to zero.
+    #[inline]
+    pub fn zero() -> Self {
+        LogicalRect::new(LogicalPoint::zero(), LogicalSize::zero())
+    }
+}
+
+impl<T: Copy + Add<Output = T>, U> LogicalRect<T, U> {
+    /// Returns the inline coordinate of the inline end side.
+    #[inline]
+    pub fn inline_end(&self) -> T {
+        self.start.i + self.size.inline
+    }
+
+    /// Returns the block coordinate of the block end side.
+    #[inline]
+    pub fn block_end(&self) -> T {
+        self.start.b + self.size.block
+    }
+}
+
+impl<T, U> LogicalRect<T, U>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T>,
+{
+    /// Converts to a physical rectangle in a container of the given size.
+    pub fn to_physical(&self, mode: WritingMode, container_size: Size2D<T, U>) -> Rect<T, U> {
+        // The start corner of the rectangle is its physical origin along the axes
+        // which are not reversed, and its end corner along the other ones.
+        let (inline_extent, block_extent) = mode.logical_extents(container_size);
+        let i = if mode.is_inline_reversed() {
+            inline_extent - self.inline_end()
+        } else {
+            self.start.i
+        };
+        let b = if mode.is_block_reversed() {
+            block_extent - self.block_end()
+        } else {
+            self.start.b
+        };
+
+        let origin = if mode.is_vertical() {
+            point2(b, i)
+        } else {
+            point2(i, b)
+        };
+        Rect::new(origin, self.size.to_physical(mode))
+    }
+
+    /// Converts from a physical rectangle in a container of the given size.
+    pub fn from_physical(
+        mode: WritingMode,
+        rect: Rect<T, U>,
+        container_size: Size2D<T, U>,
+    ) -> Self {
+        let (inline_extent, block_extent) = mode.logical_extents(container_size);
+        let size = LogicalSize::from_physical(mode, rect.size);
+        let (i, b) = mode.logical_extents(rect.origin.to_vector().to_size());
+        let i = if mode.is_inline_reversed() {
+            inline_extent - (i + size.inline)
+        } else {
+            i
+        };
+        let b = if mode.is_block_reversed() {
+            block_extent - (b + size.block)
+        } else {
+            b
+        };
+
+        LogicalRect::new(LogicalPoint::new(i, b), size)
+    }
+}
+
+/// A group of offsets for the sides of a logical rectangle, like the logical
+/// margins, borders and padding of CSS.
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Serialize", deserialize = "T: Deserialize<'de>"))
+)]
+pub struct LogicalSides<T, U> {
+    pub block_start: T,
+    pub inline_end: T,
+    pub block_end: T,
+    pub inline_start: T,
+    #[doc(hidden)]
+    pub _unit: PhantomData<U>,
+}
+
+impl<T: Copy, U> Copy for LogicalSides<T, U> {}
+
+impl<T: Clone, U> Clone for LogicalSides<T, U> {
+    fn clone(&self) -> Self {
+        LogicalSides::new(
+            self.block_start.clone(),
+            self.inline_end.clone(),
+            self.block_end.clone(),
+            self.inline_start.clone(),
+        )
+    }
+}
+
+impl<T: PartialEq, U> PartialEq for LogicalSides<T, U> {
+    fn eq(&self, other: &Self) -> bool {
+        self.block_start == other.block_start
+            && self.inline_end == other.inline_end
+            && self.block_end == other.block_end
+            && self.inline_start == other.inline_start
+    }
+}
+
+impl<T: Eq, U> Eq for LogicalSides<T, U> {}
+
+impl<T: Hash, U> Hash for LogicalSides<T, U> {
+    fn hash<H: Hasher>(&self, h: &mut H) {
+        self.block_start.hash(h);
+        self.inline_end.hash(h);
+        self.block_end.hash(h);
+        self.inline_start.hash(h);
+    }
+}
+
+impl<T: fmt::Debug, U> fmt::Debug for LogicalSides<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("LogicalSides")
+            .field(&self.block_start)
+            .field(&self.inline_end)
+            .field(&self.block_end)
+            .field(&self.inline_start)
+            .finish()
+    }
+}
+
+impl<T: Default, U> Default for LogicalSides<T, U> {
+    fn default() -> Self {
+        LogicalSides::new(
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+        )
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, T, U> arbitrary::Arbitrary<'a> for LogicalSides<T, U>
+where
+    T: arbitrary::Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self>
+    {
+        let (block_start, inline_end, block_end, inline_start) =
+            arbitrary::Arbitrary::arbitrary(u)?;
+        Ok(LogicalSides::new(block_start, inline_end, block_end, inline_start))
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: Zeroable, U> Zeroable for LogicalSides<T, U> {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: Pod, U: 'static> Pod for LogicalSides<T, U> {}
+
+impl<T, U> LogicalSides<T, U> {
+    /// Constructor taking a scalar for each side.
+    ///
+    /// Sides are specified in block-start, inline-end, block-end, inline-start
+    /// order, which is the top-right-bottom-left order of `SideOffsets2D` in
+    /// `WritingMode::HORIZONTAL_TB`.
+    #[inline]
+    pub const fn new(block_start: T, inline_end: T, block_end: T, inline_start: T) -> Self {
+        LogicalSides {
+            block_start,
+            inline_end,
+            block_end,
+            inline_start,
+            _unit: PhantomData,
+        }
+    }
+}
+
+impl<T: Zero, U> LogicalSides<T, U> {
+    /// Constructor, setting all sides to zero.
+    #[inline]
+    pub fn zero() -> Self {
+        LogicalSides::new(Zero::zero(), Zero::zero(), Zero::zero(), Zero::zero())
+    }
+}
+
+impl<T: Copy + Add<Output = T>, U> LogicalSides<T, U> {
+    /// Returns the sum of the inline start and inline end sides.
+    #[inline]
+    pub fn inline_start_end(&self) -> T {
+        self.inline_start + self.inline_end
+    }
+
+    /// Returns the sum of the block start and block end sides.
+    #[inline]
+    pub fn block_start_end(&self) -> T {
+        self.block_start + self.block_end
+    }
+}
+
+impl<T: Copy, U> LogicalSides<T, U> {
+    /// Converts to physical side offsets.
+    pub fn to_physical(&self, mode: WritingMode) -> SideOffsets2D<T, U> {
+        let (inline_min, inline_max) = if mode.is_inline_reversed() {
+            (self.inline_end, self.inline_start)
+        } else {
+            (self.inline_start, self.inline_end)
+        };
+        let (block_min, block_max) = if mode.is_block_reversed() {
+            (self.block_end, self.block_start)
+        } else {
+            (self.block_start, self.block_end)
+        };
+
+        if mode.is_vertical() {
+            SideOffsets2D::new(inline_min, block_max, inline_max, block_min)
+        } else {
+            SideOffsets2D::new(block_min, inline_max, block_max, inline_min)
+        }
+    }
+
+    /// Converts from physical side offsets.
+    pub fn from_physical(mode: WritingMode, sides: SideOffsets2D<T, U>) -> Self {
+        let (inline_min, inline_max, block_min, block_max) = if mode.is_vertical() {
+            (sides.top, sides.bottom, sides.left, sides.right)
+        } else {
+            (sides.left, sides.right, sides.top, sides.bottom)
+        };
+        let (inline_start, inline_end) = if mode.is_inline_reversed() {
+            (inline_max, inline_min)
+        } else {
+            (inline_min, inline_max)
+        };
+        let (block_start, block_end) = if mode.is_block_reversed() {
+            (block_max, block_min)
+        } else {
+            (block_min, block_max)
+        };
+
+        LogicalSides::new(block_start, inline_end, block_end, inline_start)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Direction, WritingMode};
+    use crate::default::{LogicalPoint, LogicalRect, LogicalSides, LogicalSize, SideOffsets2D};
+    use crate::{point2, rect, size2};
+
+    const MODES: [WritingMode; 5] = [
+        WritingMode::HORIZONTAL_TB,
+        WritingMode::VERTICAL_RL,
+        WritingMode::VERTICAL_LR,
+        WritingMode::SIDEWAYS_RL,
+        WritingMode::SIDEWAYS_LR,
+    ];
+
+    fn all_modes() -> impl Iterator<Item = WritingMode> {
+        MODES.iter().flat_map(|&mode| {
+            [Direction::Ltr, Direction::Rtl]
+                .iter()
+                .map(move |&direction| mode.with_direction(direction))
+        })
+    }
+
+    #[test]
+    fn test_point() {
+        let container = size2(100, 200);
+        let p = LogicalPoint::new(10, 20);
+        let rtl = |mode: WritingMode| mode.with_direction(Direction::Rtl);
+
+        assert_eq!(p.to_physical(WritingMode::HORIZONTAL_TB, container), point2(10, 20));
+        assert_eq!(p.to_physical(rtl(WritingMode::HORIZONTAL_TB), container), point2(90, 20));
+        assert_eq!(p.to_physical(WritingMode::VERTICAL_RL, container), point2(80, 10));
+        assert_eq!(p.to_physical(rtl(WritingMode::VERTICAL_RL), container), point2(80, 190));
+        assert_eq!(p.to_physical(WritingMode::VERTICAL_LR, container), point2(20, 10));
+        assert_eq!(p.to_physical(WritingMode::SIDEWAYS_RL, container), point2(80, 10));
+        assert_eq!(p.to_physical(WritingMode::SIDEWAYS_LR, container), point2(20, 190));
+        assert_eq!(p.to_physical(rtl(WritingMode::SIDEWAYS_LR), container), point2(20, 10));
+
+        for mode in all_modes() {
+            let physical = p.to_physical(mode, container);
+            assert_eq!(LogicalPoint::from_physical(mode, physical, container), p);
+        }
+    }
+
+    #[test]
+    fn test_size() {
+        let s = LogicalSize::new(10, 20);
+        assert_eq!(s.to_physical(WritingMode::HORIZONTAL_TB), size2(10, 20));
+        assert_eq!(s.to_physical(WritingMode::SIDEWAYS_LR), size2(20, 10));
+        for mode in all_modes() {
+            assert_eq!(LogicalSize::from_physical(mode, s.to_physical(mode)), s);
+        }
+    }
+
+    #[test]
+    fn test_rect() {
+        let container = size2(100, 200);
+        let r = LogicalRect::new(LogicalPoint::new(10, 20), LogicalSize::new(30, 40));
+        let rtl = WritingMode::HORIZONTAL_TB.with_direction(Direction::Rtl);
+
+        assert_eq!(r.to_physical(WritingMode::HORIZONTAL_TB, container), rect(10, 20, 30, 40));
+        assert_eq!(r.to_physical(rtl, container), rect(60, 20, 30, 40));
+        assert_eq!(r.to_physical(WritingMode::VERTICAL_RL, container), rect(40, 10, 40, 30));
+        assert_eq!(r.to_physical(WritingMode::SIDEWAYS_LR, container), rect(20, 160, 40, 30));
+
+        for mode in all_modes() {
+            let physical = r.to_physical(mode, container);
+            assert_eq!(LogicalRect::from_physical(mode, physical, container), r);
+
+            // The start corner is at the same place as the converted start point.
+            let start = r.start.to_physical(mode, container);
+            let corners = [
+                physical.min(),
+                point2(physical.max_x(), physical.min_y()),
+                physical.max(),
+                point2(physical.min_x(), physical.max_y()),
+            ];
+            assert!(corners.contains(&start));
+        }
+    }
+
+    #[test]
+    fn test_sides() {
+        let sides = LogicalSides::new(1, 2, 3, 4);
+        let rtl = |mode: WritingMode| mode.with_direction(Direction::Rtl);
+
+        assert_eq!(sides.to_physical(WritingMode::HORIZONTAL_TB), SideOffsets2D::new(1, 2, 3, 4));
+        assert_eq!(
+            sides.to_physical(rtl(WritingMode::HORIZONTAL_TB)),
+            SideOffsets2D::new(1, 4, 3, 2)
+        );
+        assert_eq!(sides.to_physical(WritingMode::VERTICAL_RL), SideOffsets2D::new(4, 1, 2, 3));
+        assert_eq!(sides.to_physical(WritingMode::VERTICAL_LR), SideOffsets2D::new(4, 3, 2, 1));
+        assert_eq!(sides.to_physical(WritingMode::SIDEWAYS_LR), SideOffsets2D::new(2, 3, 4, 1));
+
+        for mode in all_modes() {
+            assert_eq!(LogicalSides::from_physical(mode, sides.to_physical(mode)), sides);
+        }
+        assert_eq!(sides.inline_start_end(), 6);
+        assert_eq!(sides.block_start_end(), 4);
+    }
+}
@@ -0,0 +1,1067 @@
+// Copyright 2013 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::UnknownUnit;
+use crate::approxeq::ApproxEq;
+use crate::box2d::Box2D;
+use crate::num::*;
+use crate::point::Point2D;
+#[cfg(feature = "serde")]
+use crate::polygon::deserialize_vec;
+use crate::polygon::{FillRule, Polygon2D};
+use crate::segment::LineSegment2D;
+use crate::transform2d::Transform2D;
+use crate::vector::Vector2D;
+
+use alloc::vec;
+use alloc::vec::Vec;
+use num_traits::real::Real;
+use num_traits::NumCast;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use core::cmp::{Ordering, PartialOrd};
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::iter::FromIterator;
+use core::ops::{Add, Div, Mul, Sub};
+
+/// A boolean operation between two shapes, see `MultiPolygon2D::boolean`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum BooleanOp {
+    /// The points inside of either shape.
+    Union,
+    /// The points inside of both shapes.
+    Intersection,
+    /// The points inside of the first shape but not of the second one.
+    Difference,
+    /// The points inside of exactly one of the shapes.
+    Xor,
+}
+
+impl BooleanOp {
+    /// Returns `true` if a point is in the result of the operation, given whether it
+    /// is inside of each shape.
+    #[inline]
+    pub fn apply(self, a: bool, b: bool) -> bool {
+        match self {
+            BooleanOp::Union => a || b,
+            BooleanOp::Intersection => a && b,
+            BooleanOp::Difference => a && !b,
+            BooleanOp::Xor => a != b,
+        }
+    }
+}
+
+/// A shape made of several closed contours, like polygons with holes or sets of
+/// disjoint polygons.
+///
+/// Which points are inside of the shape is decided by a fill rule from the sum of
+/// the winding numbers of all of the contours. The results of boolean operations
+/// have their outer contours going counter-clockwise and their holes going
+/// clockwise, without overlaps, so that both fill rules agree on them.
+pub struct MultiPolygon2D<T, U> {
+    pub contours: Vec<Polygon2D<T, U>>,
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T, U> serde::Deserialize<'de> for MultiPolygon2D<T, U>
+where
+    T: serde::Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let contours = deserialize_vec(deserializer)?;
+        Ok(MultiPolygon2D { contours })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T, U> serde::Serialize for MultiPolygon2D<T, U>
+where
+    T: serde::Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.contours.as_slice().serialize(serializer)
+    }
+}
+
+impl<T: Hash, U> Hash for MultiPolygon2D<T, U> {
+    fn hash<H: Hasher>(&self, h: &mut H) {
+        self.contours.hash(h);
+    }
+}
+
+impl<T: Clone, U> Clone for MultiPolygon2D<T, U> {
+    fn clone(&self) -> Self {
+        MultiPolygon2D::new(self.contours.clone())
+    }
+}
+
+impl<T: PartialEq, U> PartialEq for MultiPolygon2D<T, U> {
+    fn eq(&self, other: &Self) -> bool {
+        self.contours.eq(&other.contours)
+    }
+}
+
+impl<T: Eq, U> Eq for MultiPolygon2D<T, U> {}
+
+impl<T: fmt::Debug, U> fmt::Debug for MultiPolygon2D<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("MultiPolygon2D").field(&self.contours).finish()
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, T, U> arbitrary::Arbitrary<'a> for MultiPolygon2D<T, U>
+where
+    T: arbitrary::Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self>
+    {
+        let contours = arbitrary::Arbitrary::arbitrary(u)?;
+        Ok(MultiPolygon2D { contours })
+    }
+}
+
+impl<T, U> Default for MultiPolygon2D<T, U> {
+    fn default() -> Self {
+        MultiPolygon2D::new(Vec::new())
+    }
+}
+
+impl<T, U> From<Vec<Polygon2D<T, U>>> for MultiPolygon2D<T, U> {
+    fn from(contours: Vec<Polygon2D<T, U>>) -> Self {
+        MultiPolygon2D::new(contours)
+    }
+}
+
+impl<T, U> From<Polygon2D<T, U>> for MultiPolygon2D<T, U> {
+    fn from(contour: Polygon2D<T, U>) -> Self {
+        MultiPolygon2D::new(vec![contour])
+    }
+}
+
+impl<T, U> FromIterator<Polygon2D<T, U>> for MultiPolygon2D<T, U> {
+    fn from_iter<I: IntoIterator<Item = Polygon2D<T, U>>>(iter: I) -> Self {
+        MultiPolygon2D::new(iter.into_iter().collect())
+    }
+}
+
+impl<T, U> MultiPolygon2D<T, U> {
+    /// Constructor.
+    #[inline]
+    pub const fn new(contours: Vec<Polygon2D<T, U>>) -> Self {
+        MultiPolygon2D { contours }
+    }
+
+    /// Returns `true` if there are no contours with vertices.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.contours.iter().all(|contour| contour.is_empty())
+    }
+}
+
+impl<T: Copy, U> MultiPolygon2D<T, U> {
+    /// Returns an iterator over the edges of all of the contours.
+    pub fn edges(&self) -> impl Iterator<Item = LineSegment2D<T, U>> + '_ {
+        self.contours.iter().flat_map(|contour| contour.edges())
+    }
+
+    /// Drop the units, preserving only the numeric value.
+    #[inline]
+    pub fn to_untyped(&self) -> MultiPolygon2D<T, UnknownUnit> {
+        self.cast_unit()
+    }
+
+    /// Tag a unitless value with units.
+    #[inline]
+    pub fn from_untyped(p: &MultiPolygon2D<T, UnknownUnit>) -> Self {
+        p.cast_unit()
+    }
+
+    /// Cast the unit
+    #[inline]
+    pub fn cast_unit<V>(&self) -> MultiPolygon2D<T, V> {
+        self.contours.iter().map(|c| c.cast_unit()).collect()
+    }
+}
+
+impl<T, U> MultiPolygon2D<T, U>
+where
+    T: Copy + Add<T, Output = T>,
+{
+    /// Translate all of the contours by a vector.
+    #[inline]
+    #[must_use]
+    pub fn translate(&self, by: Vector2D<T, U>) -> Self {
+        self.contours.iter().map(|c| c.translate(by)).collect()
+    }
+}
+
+impl<T, U> MultiPolygon2D<T, U>
+where
+    T: Copy + Zero + PartialOrd,
+{
+    /// Returns the smallest box containing all of the vertices.
+    pub fn bounding_box(&self) -> Box2D<T, U> {
+        Box2D::from_points(self.contours.iter().flat_map(|c| c.points.iter()))
+    }
+}
+
+impl<T, U> MultiPolygon2D<T, U>
+where
+    T: Copy + Zero + PartialOrd + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    /// Returns the sum of the winding numbers of all of the contours around a point.
+    ///
+    /// See `Polygon2D::winding_number` for how points on the boundary are classified.
+    pub fn winding_number(&self, p: Point2D<T, U>) -> i32 {
+        self.contours.iter().map(|c| c.winding_number(p)).sum()
+    }
+
+    /// Returns `true` if the point is inside of the shape according to the fill rule.
+    #[inline]
+    pub fn contains(&self, p: Point2D<T, U>, fill_rule: FillRule) -> bool {
+        fill_rule.is_in(self.winding_number(p))
+    }
+}
+
+impl<T, U> MultiPolygon2D<T, U>
+where
+    T: Copy + Zero + One + PartialOrd + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    /// Returns the sum of the signed areas of all of the contours.
+    ///
+    /// This is the area of the shape when it has no overlapping contours and its
+    /// holes go clockwise, which is the case for the results of boolean operations.
+    pub fn signed_area(&self) -> T {
+        self.contours.iter().fold(T::zero(), |sum, c| sum + c.signed_area())
+    }
+}
+
+impl<T: Real, U> MultiPolygon2D<T, U> {
+    /// Computes a boolean operation between two shapes, each of them filled
+    /// according to the fill rule.
+    ///
+    /// The contours are split wherever they cross or touch each other, and the
+    /// pieces which separate the inside of the result from its outside are chained
+    /// back into contours, with the inside on their left. Overlapping edges, edges
+    /// with no length and parts with no area are handled the same way as any other
+    /// input, and the output only depends on the input, so the same operation always
+    /// gives the same result. Aligned vertices are removed from the result, and
+    /// shapes which touch at a single point are kept as separate contours.
+    ///
+    /// Every pair of edges is tested, so the cost is quadratic in the number of
+    /// edges.
+    pub fn boolean(&self, other: &Self, op: BooleanOp, fill_rule: FillRule) -> Self {
+        let edges = arrangement(self, other);
+
+        let inside = |winding: [i32; 2]| {
+            op.apply(fill_rule.is_in(winding[0]), fill_rule.is_in(winding[1]))
+        };
+
+        let mut boundary = Vec::new();
+        for (index, edge) in edges.iter().enumerate() {
+            let (left, right) = side_windings(&edges, index);
+            match (inside(left), inside(right)) {
+                (true, false) => boundary.push((edge.from, edge.to)),
+                (false, true) => boundary.push((edge.to, edge.from)),
+                _ => {}
+            }
+        }
+
+        chain_contours(boundary)
+    }
+
+    /// Returns the points inside of either shape, using the non-zero fill rule.
+    #[inline]
+    pub fn union(&self, other: &Self) -> Self {
+        self.boolean(other, BooleanOp::Union, FillRule::NonZero)
+    }
+
+    /// Returns the points inside of both shapes, using the non-zero fill rule.
+    #[inline]
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.boolean(other, BooleanOp::Intersection, FillRule::NonZero)
+    }
+
+    /// Returns the points inside of this shape but not of the other one, using the
+    /// non-zero fill rule.
+    #[inline]
+    pub fn difference(&self, other: &Self) -> Self {
+        self.boolean(other, BooleanOp::Difference, FillRule::NonZero)
+    }
+
+    /// Returns the points inside of exactly one of the shapes, using the non-zero
+    /// fill rule.
+    #[inline]
+    pub fn xor(&self, other: &Self) -> Self {
+        self.boolean(other, BooleanOp::Xor, FillRule::NonZero)
+    }
+
+    /// Returns the same shape with its self-intersections and overlapping contours
+    /// resolved, oriented like the results of boolean operations.
+    #[inline]
+    pub fn simplified(&self, fill_rule: FillRule) -> Self {
+        self.boolean(&MultiPolygon2D::default(), BooleanOp::Union, fill_rule)
+    }
+}
+
+impl<T, U> MultiPolygon2D<T, U>
+where
+    T: Copy + Add<Output = T> + Mul<Output = T>,
+{
+    /// Applies the transform to all of the contours.
+    #[inline]
+    pub fn transform<Dst>(&self, transform: &Transform2D<T, U, Dst>) -> MultiPolygon2D<T, Dst> {
+        self.contours.iter().map(|c| c.transform(transform)).collect()
+    }
+}
+
+impl<T: NumCast + Copy, U> MultiPolygon2D<T, U> {
+    /// Cast from one numeric representation to another, preserving the units.
+    #[inline]
+    pub fn cast<NewT: NumCast>(&self) -> MultiPolygon2D<NewT, U> {
+        self.contours.iter().map(|c| c.cast()).collect()
+    }
+
+    /// Fallible cast from one numeric representation to another, preserving the units.
+    pub fn try_cast<NewT: NumCast>(&self) -> Option<MultiPolygon2D<NewT, U>> {
+        self.contours.iter().map(|c| c.try_cast()).collect()
+    }
+
+    // Convenience functions for common casts
+
+    /// Cast into an `f32` shape.
+    #[inline]
+    pub fn to_f32(&self) -> MultiPolygon2D<f32, U> {
+        self.cast()
+    }
+
+    /// Cast into an `f64` shape.
+    #[inline]
+    pub fn to_f64(&self) -> MultiPolygon2D<f64, U> {
+        self.cast()
+    }
+}
+
+impl<T: ApproxEq<T>, U> ApproxEq<T> for MultiPolygon2D<T, U> {
+    #[inline]
+    fn approx_epsilon() -> T {
+        T::approx_epsilon()
+    }
+
+    #[inline]
+    fn approx_eq_eps(&self, other: &Self, eps: &T) -> bool {
+        self.contours.len() == other.contours.len()
+            && self.contours.iter().zip(&other.contours).all(|(a, b)| a.approx_eq_eps(b, eps))
+    }
+}
+
+/// A piece of edge that does not cross any other one, going from its smallest
+/// endpoint to its largest one.
+struct ArrangementEdge<T, U> {
+    from: Point2D<T, U>,
+    to: Point2D<T, U>,
+    /// For each operand, the winding number on the left side of the edge minus the
+    /// one on its right side.
+    delta: [i32; 2],
+}
+
+/// How two segments meet.
+enum Crossing<T, U> {
+    None,
+    /// The segments meet at a single point.
+    Point(Point2D<T, U>),
+    /// The segments are aligned and share more than a point.
+    Overlap,
+}
+
+/// Orders points by x, then by y.
+fn compare_points<T: PartialOrd, U>(a: &Point2D<T, U>, b: &Point2D<T, U>) -> Ordering {
+    let x = a.x.partial_cmp(&b.x).unwrap_or(Ordering::Equal);
+    x.then(a.y.partial_cmp(&b.y).unwrap_or(Ordering::Equal))
+}
+
+/// Returns the position of a point along a segment, as a ratio of its length.
+fn segment_ratio<T: Real, U>(segment: &LineSegment2D<T, U>, p: Point2D<T, U>) -> T {
+    let v = segment.to_vector();
+    (p - segment.from).dot(v) / v.square_length()
+}
+
+/// Returns the segment with its endpoints in increasing order.
+fn sorted_segment<T: PartialOrd + Copy, U>(s: &LineSegment2D<T, U>) -> LineSegment2D<T, U> {
+    match compare_points(&s.from, &s.to) {
+        Ordering::Greater => s.flip(),
+        _ => *s,
+    }
+}
+
+fn crossing<T: Real, U>(a: &LineSegment2D<T, U>, b: &LineSegment2D<T, U>) -> Crossing<T, U> {
+    // The computed point must not depend on the order of the segments or on their
+    // directions.
+    let (a, b) = (sorted_segment(a), sorted_segment(b));
+    let (a, b) = match compare_points(&a.from, &b.from).then(compare_points(&a.to, &b.to)) {
+        Ordering::Greater => (&b, &a),
+        _ => (&a, &b),
+    };
+
+    if a.from.x.max(a.to.x) < b.from.x.min(b.to.x)
+        || b.from.x.max(b.to.x) < a.from.x.min(a.to.x)
+        || a.from.y.max(a.to.y) < b.from.y.min(b.to.y)
+        || b.from.y.max(b.to.y) < a.from.y.min(a.to.y)
+    {
+        return Crossing::None;
+    }
+
+    let zero = T::zero();
+    let one = T::one();
+    let two = one + one;
+    let eps = T::epsilon() * two * two * two * two;
+
+    let r = a.to_vector();
+    let s = b.to_vector();
+    let qp = b.from - a.from;
+    let denom = r.cross(s);
+
+    if denom.abs() <= eps * r.length() * s.length() {
+        let aligned = |p: Point2D<T, U>| {
+            let v = p - a.from;
+            r.cross(v).abs() <= eps * r.length() * v.length()
+        };
+        if !aligned(b.from) || !aligned(b.to) {
+            return Crossing::None;
+        }
+
+        let t0 = segment_ratio(a, b.from);
+        let t1 = segment_ratio(a, b.to);
+        if t0.max(t1) > zero && t0.min(t1) < one {
+            return Crossing::Overlap;
+        }
+
+        // The segments can only share an endpoint.
+        return Crossing::None;
+    }
+
+    let t = qp.cross(s) / denom;
+    let u = qp.cross(r) / denom;
+    if t < -eps || t > one + eps || u < -eps || u > one + eps {
+        return Crossing::None;
+    }
+
+    // Snap to the endpoints, so that vertices lying on other edges are shared
+    // exactly.
+    let point = if t <= eps {
+        a.from
+    } else if t >= one - eps {
+        a.to
+    } else if u <= eps {
+        b.from
+    } else if u >= one - eps {
+        b.to
+    } else {
+        // Keep the point exactly on horizontal and vertical segments.
+        let mut p = a.sample(t);
+        for segment in &[a, b] {
+            if segment.from.x == segment.to.x {
+                p.x = segment.from.x;
+            }
+            if segment.from.y == segment.to.y {
+                p.y = segment.from.y;
+            }
+        }
+        p
+    };
+
+    Crossing::Point(point)
+}
+
+/// Splits the edges of both operands where they meet, and merges the overlapping
+/// pieces.
+fn arrangement<T: Real, U>(
+    a: &MultiPolygon2D<T, U>,
+    b: &MultiPolygon2D<T, U>,
+) -> Vec<ArrangementEdge<T, U>> {
+    let mut segments = Vec::new();
+    for (operand, shape) in [a, b].iter().enumerate() {
+        for edge in shape.edges() {
+            if edge.from != edge.to {
+                segments.push((edge, operand));
+            }
+        }
+    }
+
+    let n = segments.len();
+    let mut splits: Vec<Vec<Point2D<T, U>>> = vec![Vec::new(); n];
+    let mut overlaps = Vec::new();
+    let is_inner = |segment: &LineSegment2D<T, U>, p: Point2D<T, U>| {
+        let t = segment_ratio(segment, p);
+        p != segment.from && p != segment.to && t > T::zero() && t < T::one()
+    };
+
+    for i in 0..n {
+        for j in (i + 1)..n {
+            let (a, b) = (&segments[i].0, &segments[j].0);
+            match crossing(a, b) {
+                Crossing::None => {}
+                Crossing::Point(p) => {
+                    if p != a.from && p != a.to {
+                        splits[i].push(p);
+                    }
+                    if p != b.from && p != b.to {
+                        splits[j].push(p);
+                    }
+                }
+                Crossing::Overlap => {
+                    for &p in &[b.from, b.to] {
+                        if is_inner(a, p) {
+                            splits[i].push(p);
+                        }
+                    }
+                    for &p in &[a.from, a.to] {
+                        if is_inner(b, p) {
+                            splits[j].push(p);
+                        }
+                    }
+                    overlaps.push((i, j));
+                }
+            }
+        }
+    }
+
+    // Overlapping segments must be split at the same points for their pieces to be
+    // merged, even where a third edge crosses them at slightly different points.
+    loop {
+        let mut changed = false;
+        for &(i, j) in &overlaps {
+            for &(src, dst) in &[(i, j), (j, i)] {
+                let segment = &segments[dst].0;
+                let missing: Vec<_> = splits[src]
+                    .iter()
+                    .copied()
+                    .filter(|&p| is_inner(segment, p) && !splits[dst].contains(&p))
+                    .collect();
+                if !missing.is_empty() {
+                    splits[dst].extend(missing);
+                    changed = true;
+                }
+            }
+        }
+
+        if !changed {
+            break;
+        }
+    }
+
+    let snap = snapped_points(&segments, &splits);
+
+    let mut edges = Vec::new();
+    for ((segment, operand), mut points) in segments.iter().zip(splits) {
+        points.push(segment.from);
+        points.push(segment.to);
+        points.sort_by(|p, q| {
+            let tp = segment_ratio(segment, *p);
+            let tq = segment_ratio(segment, *q);
+            tp.partial_cmp(&tq).unwrap_or(Ordering::Equal)
+        });
+        for p in &mut points {
+            *p = snap(*p);
+        }
+        points.dedup();
+
+        for pair in points.windows(2) {
+            let (from, to, sign) = match compare_points(&pair[0], &pair[1]) {
+                Ordering::Less => (pair[0], pair[1], 1),
+                Ordering::Greater => (pair[1], pair[0], -1),
+                Ordering::Equal => continue,
+            };
+            let mut delta = [0; 2];
+            delta[*operand] = sign;
+            edges.push(ArrangementEdge { from, to, delta });
+        }
+    }
+
+    edges.sort_by(|e, f| compare_points(&e.from, &f.from).then(compare_points(&e.to, &f.to)));
+
+    let mut merged: Vec<ArrangementEdge<T, U>> = Vec::with_capacity(edges.len());
+    for edge in edges {
+        match merged.last_mut() {
+            Some(last) if last.from == edge.from && last.to == edge.to => {
+                last.delta[0] += edge.delta[0];
+                last.delta[1] += edge.delta[1];
+            }
+            _ => merged.push(edge),
+        }
+    }
+
+    // Pieces of edges which cancel each other out do not separate anything.
+    merged.retain(|edge| edge.delta != [0, 0]);
+    merged
+}
+
+/// Returns a function merging the endpoints and split points of the segments which
+/// are closer than the rounding errors of `crossing`.
+///
+/// Where more than two edges cross at the same point, the crossing is computed for
+/// each pair of edges with different rounding errors. Without merging, the results
+/// would be joined by tiny edges, whose sides can't be told apart reliably.
+fn snapped_points<'a, T: Real + 'a, U: 'a>(
+    segments: &[(LineSegment2D<T, U>, usize)],
+    splits: &[Vec<Point2D<T, U>>],
+) -> impl Fn(Point2D<T, U>) -> Point2D<T, U> + 'a {
+    // Each point comes with whether its x and y coordinates are exact, which is the
+    // case for vertices and along horizontal and vertical segments.
+    let mut points: Vec<(Point2D<T, U>, [bool; 2])> = Vec::new();
+    for ((segment, _), split) in segments.iter().zip(splits) {
+        points.push((segment.from, [true; 2]));
+        points.push((segment.to, [true; 2]));
+        let exact = [segment.from.x == segment.to.x, segment.from.y == segment.to.y];
+        points.extend(split.iter().map(|&p| (p, exact)));
+    }
+    points.sort_by(|p, q| compare_points(&p.0, &q.0));
+    points.dedup_by(|p, q| {
+        let same = p.0 == q.0;
+        if same {
+            q.1 = [q.1[0] || p.1[0], q.1[1] || p.1[1]];
+        }
+        same
+    });
+
+    let two = T::one() + T::one();
+    let scale = points
+        .iter()
+        .fold(T::one(), |scale, (p, _)| scale.max(p.x.abs()).max(p.y.abs()));
+    let tolerance = T::epsilon() * two * two * two * two * scale;
+
+    // Group the points which are within the tolerance of each other, under the
+    // smallest one.
+    fn find(root: &mut [usize], mut i: usize) -> usize {
+        while root[i] != i {
+            root[i] = root[root[i]];
+            i = root[i];
+        }
+        i
+    }
+    let n = points.len();
+    let mut root: Vec<usize> = (0..n).collect();
+    for i in 0..n {
+        for j in (i + 1)..n {
+            let (p, q) = (points[i].0, points[j].0);
+            if q.x - p.x > tolerance {
+                break;
+            }
+            if (q.y - p.y).abs() <= tolerance {
+                let (ri, rj) = (find(&mut root, i), find(&mut root, j));
+                root[ri.max(rj)] = ri.min(rj);
+            }
+        }
+    }
+
+    // Each group is merged into its smallest point, taking each coordinate from its
+    // first point where that coordinate is exact, so that vertices stay in place and
+    // horizontal and vertical edges stay straight.
+    let mut merged: Vec<(Point2D<T, U>, [bool; 2])> = points.clone();
+    for (i, &(p, exact)) in points.iter().enumerate() {
+        let r = find(&mut root, i);
+        if exact[0] && !merged[r].1[0] {
+            merged[r].0.x = p.x;
+            merged[r].1[0] = true;
+        }
+        if exact[1] && !merged[r].1[1] {
+            merged[r].0.y = p.y;
+            merged[r].1[1] = true;
+        }
+    }
+    let snapped: Vec<(Point2D<T, U>, Point2D<T, U>)> = (0..n)
+        .map(|i| (points[i].0, merged[find(&mut root, i)].0))
+        .collect();
+
+    move |p| match snapped.binary_search_by(|(q, _)| compare_points(q, &p)) {
+        Ok(index) => snapped[index].1,
+        Err(_) => p,
+    }
+}
+
+/// Returns the winding numbers of both operands on the left and on the right side
+/// of an edge of the arrangement.
+fn side_windings<T: Real, U>(
+    edges: &[ArrangementEdge<T, U>],
+    index: usize,
+) -> ([i32; 2], [i32; 2]) {
+    let edge = &edges[index];
+    let two = T::one() + T::one();
+    let mid = edge.from.lerp(edge.to, T::one() / two);
+
+    // Cast a ray from the middle of the edge towards positive x. Like for
+    // `Polygon2D::winding_number`, the ray only crosses the edges going up from its
+    // height, which puts the middle of horizontal edges just above them.
+    let mut winding = [0; 2];
+    for (other_index, other) in edges.iter().enumerate() {
+        if other_index == index {
+            continue;
+        }
+
+        let (low, high, sign) = if other.from.y < other.to.y {
+            (other.from, other.to, 1)
+        } else {
+            (other.to, other.from, -1)
+        };
+        if low.y <= mid.y && mid.y < high.y && (high - low).cross(mid - low) > T::zero() {
+            winding[0] += sign * other.delta[0];
+            winding[1] += sign * other.delta[1];
+        }
+    }
+
+    let apply = |w: [i32; 2], sign: i32| [w[0] + sign * edge.delta[0], w[1] + sign * edge.delta[1]];
+
+    // Edges go towards positive x, so the ray starts on their right side if they go
+    // up and on their left side otherwise.
+    if edge.to.y > edge.from.y {
+        (apply(winding, 1), winding)
+    } else {
+        (winding, apply(winding, -1))
+    }
+}
+
+/// Chains directed edges into contours, and removes their aligned vertices.
+fn chain_contours<T: Real, U>(
+    mut edges: Vec<(Point2D<T, U>, Point2D<T, U>)>,
+) -> MultiPolygon2D<T, U> {
+    edges.sort_by(|e, f| compare_points(&e.0, &f.0).then(compare_points(&e.1, &f.1)));
+
+    let n = edges.len();
+    let mut used = vec![false; n];
+    let mut contours = Vec::new();
+    for first in 0..n {
+        if used[first] {
+            continue;
+        }
+
+        let mut points = Vec::new();
+        let mut current = first;
+        loop {
+            used[current] = true;
+            let (from, to) = edges[current];
+            points.push(from);
+
+            // Where several contours touch, take the sharpest left turn, which keeps
+            // following the boundary of the same part of the inside.
+            let back = from - to;
+            let start = edges.partition_point(|e| compare_points(&e.0, &to) == Ordering::Less);
+            let mut next: Option<(usize, (bool, T))> = None;
+            for (k, edge) in edges.iter().enumerate().skip(start) {
+                if edge.0 != to {
+                    break;
+                }
+
+                // The clockwise angle from the way back to the outgoing edge.
+                let out = edge.1 - to;
+                let angle = out.cross(back).atan2(out.dot(back));
+                let key = (angle <= T::zero(), angle);
+                let better = match next {
+                    None => true,
+                    Some((_, best)) => key.partial_cmp(&best) == Some(Ordering::Less),
+                };
+                if better {
+                    next = Some((k, key));
+                }
+            }
+
+            // The boundary has as many edges going in and out of each vertex, so
+            // the contour can only end where it started.
+            match next {
+                Some((k, _)) if !used[k] => current = k,
+                next => {
+                    debug_assert!(
+                        matches!(next, Some((k, _)) if k == first),
+                        "unclosed contour"
+                    );
+                    break;
+                }
+            }
+        }
+
+        remove_aligned_points(&mut points);
+        if points.len() >= 3 {
+            contours.push(Polygon2D::new(points));
+        }
+    }
+
+    MultiPolygon2D::new(contours)
+}
+
+/// Removes the vertices which are exactly aligned with their neighbors, between
+/// them.
+fn remove_aligned_points<T: Real, U>(points: &mut Vec<Point2D<T, U>>) {
+    let mut i = 0;
+    let mut kept_since_removal = 0;
+    while points.len() >= 3 && kept_since_removal < points.len() {
+        let n = points.len();
+        let prev = points[(i + n - 1) % n];
+        let p = points[i % n];
+        let next = points[(i + 1) % n];
+        let (a, b) = (p - prev, next - p);
+        if a.cross(b) == T::zero() && a.dot(b) > T::zero() {
+            points.remove(i % n);
+            kept_since_removal = 0;
+            i %= n;
+        } else {
+            kept_since_removal += 1;
+            i = (i + 1) % n;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BooleanOp;
+    use crate::default::{MultiPolygon2D, Point2D, Polygon2D};
+    use crate::point2;
+    use crate::polygon::FillRule;
+
+    fn square(x: f64, y: f64, size: f64) -> Polygon2D<f64> {
+        Polygon2D::new(vec![
+            point2(x, y),
+            point2(x + size, y),
+            point2(x + size, y + size),
+            point2(x, y + size),
+        ])
+    }
+
+    fn shape(contours: &[Polygon2D<f64>]) -> MultiPolygon2D<f64> {
+        MultiPolygon2D::new(contours.to_vec())
+    }
+
+    const OPS: [BooleanOp; 4] = [
+        BooleanOp::Union,
+        BooleanOp::Intersection,
+        BooleanOp::Difference,
+        BooleanOp::Xor,
+    ];
+
+    // Compares the result with the operation applied to sample points, which avoid
+    // the edges of the test shapes.
+    fn check(a: &MultiPolygon2D<f64>, b: &MultiPolygon2D<f64>, op: BooleanOp, fill_rule: FillRule) {
+        let result = a.boolean(b, op, fill_rule);
+        for contour in &result.contours {
+            assert!(contour.len() >= 3);
+        }
+
+        for i in -10..70 {
+            for j in -10..70 {
+                let p: Point2D<f64> = point2(i as f64 * 0.1 + 0.013, j as f64 * 0.1 + 0.027);
+                let expected = op.apply(a.contains(p, fill_rule), b.contains(p, fill_rule));
+                assert_eq!(result.contains(p, FillRule::NonZero), expected, "{:?} at {:?}", op, p);
+                assert_eq!(result.contains(p, FillRule::EvenOdd), expected, "{:?} at {:?}", op, p);
+            }
+        }
+    }
+
+    #[test]
+    fn test_overlapping_squares() {
+        let a = shape(&[square(0.0, 0.0, 2.0)]);
+        let b = shape(&[square(1.0, 1.0, 2.0)]);
+
+        let union = a.union(&b);
+        assert_eq!(union.contours.len(), 1);
+        assert_eq!(union.contours[0].len(), 8);
+        assert_eq!(union.signed_area(), 7.0);
+
+        let intersection = a.intersection(&b);
+        assert_eq!(intersection.contours.len(), 1);
+        assert_eq!(intersection.contours[0].len(), 4);
+        assert_eq!(intersection.signed_area(), 1.0);
+
+        assert_eq!(a.difference(&b).signed_area(), 3.0);
+
+        let xor = a.xor(&b);
+        assert_eq!(xor.contours.len(), 2);
+        assert_eq!(xor.signed_area(), 6.0);
+
+        for &op in &OPS {
+            check(&a, &b, op, FillRule::NonZero);
+        }
+    }
+
+    #[test]
+    fn test_holes() {
+        let mut hole = square(1.0, 1.0, 2.0);
+        hole.reverse();
+        let frame = shape(&[square(0.0, 0.0, 4.0), hole]);
+        let bar = shape(&[Polygon2D::new(vec![
+            point2(2.0, -1.0),
+            point2(5.0, 2.0),
+            point2(2.0, 5.0),
+            point2(-1.0, 2.0),
+        ])]);
+
+        let difference = frame.difference(&shape(&[square(1.5, 1.5, 1.0)]));
+        assert_eq!(difference.signed_area(), 12.0);
+
+        let hole = shape(&[square(1.0, 1.0, 2.0)]);
+        let filled = frame.union(&hole);
+        assert_eq!(filled.contours.len(), 1);
+        assert_eq!(filled.signed_area(), 16.0);
+
+        for &op in &OPS {
+            check(&frame, &bar, op, FillRule::NonZero);
+            check(&bar, &frame, op, FillRule::NonZero);
+        }
+    }
+
+    #[test]
+    fn test_degenerate_inputs() {
+        let a = shape(&[square(0.0, 0.0, 2.0)]);
+
+        // Identical shapes.
+        assert_eq!(a.union(&a), a);
+        assert_eq!(a.intersection(&a), a);
+        assert!(a.difference(&a).is_empty());
+        assert!(a.xor(&a).is_empty());
+
+        // Shared edges and aligned vertices.
+        let b = shape(&[square(2.0, 0.0, 2.0)]);
+        let union = a.union(&b);
+        assert_eq!(union.contours.len(), 1);
+        assert_eq!(union.contours[0].len(), 4);
+        assert_eq!(union.signed_area(), 8.0);
+        assert!(a.intersection(&b).is_empty());
+
+        // Partially overlapping edges.
+        let c = shape(&[Polygon2D::new(vec![
+            point2(1.0, 0.0),
+            point2(3.0, 0.0),
+            point2(3.0, 1.0),
+            point2(1.0, 1.0),
+        ])]);
+        assert_eq!(a.union(&c).contours[0].len(), 6);
+        assert_eq!(a.intersection(&c).signed_area(), 1.0);
+
+        // Shapes touching at a corner stay separate.
+        let d = shape(&[square(2.0, 2.0, 1.0)]);
+        let union = a.union(&d);
+        assert_eq!(union.contours.len(), 2);
+        assert_eq!(union.signed_area(), 5.0);
+
+        // Repeated vertices, spikes and flat contours.
+        let e = shape(&[
+            Polygon2D::new(vec![
+                point2(1.0, 1.0),
+                point2(1.0, 1.0),
+                point2(5.0, 1.0),
+                point2(6.0, 1.0),
+                point2(5.0, 1.0),
+                point2(5.0, 5.0),
+                point2(1.0, 5.0),
+            ]),
+            Polygon2D::new(vec![point2(0.0, 0.0), point2(3.0, 3.0), point2(6.0, 6.0)]),
+        ]);
+        assert_eq!(e.simplified(FillRule::NonZero), shape(&[square(1.0, 1.0, 4.0)]));
+        for &op in &OPS {
+            check(&a, &e, op, FillRule::NonZero);
+            check(&e, &c, op, FillRule::EvenOdd);
+        }
+
+        // Three edges crossing at the same point, which is computed with different
+        // rounding errors for each pair of edges.
+        let polygon = |points: &[(f64, f64)]| {
+            Polygon2D::new(points.iter().map(|&(x, y)| point2(x, y)).collect())
+        };
+        let f = shape(&[
+            polygon(&[(4.0, 2.0), (3.0, 4.0), (0.0, 3.0)]),
+            polygon(&[(4.0, 1.0), (0.0, 0.0), (0.0, 1.0), (2.0, 0.0)]),
+        ]);
+        let g = shape(&[polygon(&[(1.0, 0.0), (5.0, 1.0), (5.0, 4.0)])]);
+        assert!(f.contains(point2(1.0, 3.0), FillRule::NonZero));
+        assert!(f.union(&g).contains(point2(1.0, 3.0), FillRule::NonZero));
+
+        // Crossings on horizontal and vertical edges.
+        let h = shape(&[polygon(&[(3.0, 2.0), (2.0, 4.0), (3.0, 1.0), (0.0, 4.0), (3.0, 2.0)])]);
+        let k = shape(&[polygon(&[(0.0, 5.0), (2.0, 2.0), (0.0, 3.0), (5.0, 3.0), (3.0, 0.0)])]);
+        let m = shape(&[
+            polygon(&[(6.0, 7.0), (0.0, 3.0), (2.0, 5.0), (10.0, 0.0), (3.0, 6.0), (8.0, 1.0)]),
+            polygon(&[(7.0, 0.0), (7.0, 10.0), (1.0, 7.0), (2.0, 3.0), (1.0, 2.0), (7.0, 7.0)]),
+        ]);
+        let n = shape(&[
+            polygon(&[(0.0, 10.0), (3.0, 2.0), (6.0, 2.0), (8.0, 2.0), (9.0, 10.0), (10.0, 2.0)]),
+        ]);
+
+        for &op in &OPS {
+            for &fill_rule in &[FillRule::NonZero, FillRule::EvenOdd] {
+                check(&f, &g, op, fill_rule);
+                check(&h, &k, op, fill_rule);
+                check(&m, &n, op, fill_rule);
+            }
+        }
+    }
+
+    #[test]
+    fn test_self_intersections() {
+        let star = shape(&[Polygon2D::new(vec![
+            point2(3.0, 0.0),
+            point2(4.8, 5.5),
+            point2(0.0, 2.0),
+            point2(6.0, 2.0),
+            point2(1.2, 5.5),
+        ])]);
+        let a = shape(&[square(1.0, 1.0, 3.0)]);
+
+        // Clockwise contours and overlapping contours in the same shape.
+        let mut cw = square(2.0, 0.5, 3.0);
+        cw.reverse();
+        let b = shape(&[cw, square(0.5, 3.0, 2.0)]);
+
+        for &fill_rule in &[FillRule::NonZero, FillRule::EvenOdd] {
+            // The even-odd rule leaves out the middle of the star.
+            let parts = if fill_rule == FillRule::NonZero { 1 } else { 5 };
+            assert_eq!(star.simplified(fill_rule).contours.len(), parts);
+            for &op in &OPS {
+                check(&star, &a, op, fill_rule);
+                check(&b, &star, op, fill_rule);
+            }
+        }
+    }
+
+    #[test]
+    fn test_deterministic() {
+        let a = shape(&[square(0.0, 0.0, 2.0), square(3.0, 0.5, 1.0)]);
+        let b = shape(&[Polygon2D::new(vec![
+            point2(1.0, -1.0),
+            point2(3.5, 1.0),
+            point2(1.0, 3.0),
+        ])]);
+
+        for &op in &OPS {
+            assert_eq!(a.boolean(&b, op, FillRule::NonZero), a.boolean(&b, op, FillRule::NonZero));
+        }
+
+        // The result does not depend on how the contours are listed.
+        let mut reordered = a.clone();
+        reordered.contours.reverse();
+        reordered.contours[0].points.rotate_left(2);
+        assert_eq!(reordered.union(&b), a.union(&b));
+        assert_eq!(b.union(&a), a.union(&b));
+        assert_eq!(b.intersection(&a), a.intersection(&b));
+    }
+
+    #[test]
+    fn test_integer_like_f32() {
+        let a: MultiPolygon2D<f32> = shape(&[square(0.0, 0.0, 2.0)]).to_f32();
+        let b = shape(&[square(1.0, 1.0, 2.0)]).to_f32();
+        assert_eq!(a.union(&b).signed_area(), 7.0);
+        assert_eq!(a.xor(&b).signed_area(), 6.0);
+    }
+}
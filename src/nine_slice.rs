@@ -0,0 +1,223 @@
+// Copyright 2013 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Tiling of the slices of a nine-slice decomposition, like the CSS `border-image-repeat`
+//! property.
+
+use num_traits::Float;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// How the edge and middle slices of a nine-slice decomposition fill their
+/// destination along one axis, like the CSS `border-image-repeat` property.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum RepeatMode {
+    /// Stretches the slice to fill the destination.
+    Stretch,
+    /// Tiles the slice, centered in the destination and clipped at its edges.
+    Repeat,
+    /// Tiles the slice, scaled so that a whole number of tiles fill the destination.
+    Round,
+    /// Tiles the slice as many times as it fits without clipping, and distributes
+    /// the remaining space evenly around the tiles.
+    Space,
+}
+
+/// The tiles of a slice along one axis.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct AxisTiles<T> {
+    src: (T, T),
+    dst: (T, T),
+    /// Offset of the first tile from the start of the destination.
+    start: T,
+    /// Length of a tile in the destination.
+    tile: T,
+    /// Distance between the starts of two consecutive tiles.
+    step: T,
+    count: usize,
+    /// Whether the last tile ends exactly at the end of the destination.
+    snap_end: bool,
+}
+
+impl<T: Float> AxisTiles<T> {
+    /// Tiles the destination range with the source range, each tile having the
+    /// given length in the destination before adjustments of the repeat mode.
+    pub(crate) fn new(mode: RepeatMode, src: (T, T), dst: (T, T), tile: T) -> Self {
+        let zero = T::zero();
+        let length = dst.1 - dst.0;
+        let mut tiles = AxisTiles {
+            src,
+            dst,
+            start: zero,
+            tile: length,
+            step: length,
+            count: 0,
+            snap_end: true,
+        };
+        if !(src.1 > src.0 && length > zero) {
+            return tiles;
+        }
+        // Tiles without a usable length are stretched.
+        let mode = if tile > zero && tile.is_finite() {
+            mode
+        } else {
+            RepeatMode::Stretch
+        };
+
+        match mode {
+            RepeatMode::Stretch => {
+                tiles.count = 1;
+            }
+            RepeatMode::Repeat => {
+                // Center a tile, and place the other ones so that they cover the
+                // destination from its start.
+                let mut start = ((length - tile) / (T::one() + T::one())) % tile;
+                if start < zero {
+                    start = start + tile;
+                }
+                if start > zero {
+                    start = start - tile;
+                }
+                tiles.start = start;
+                tiles.tile = tile;
+                tiles.step = tile;
+                tiles.count = ((length - start) / tile).ceil().to_usize().unwrap_or(0);
+                tiles.snap_end = false;
+            }
+            RepeatMode::Round => {
+                let count = (length / tile).round().max(T::one());
+                tiles.tile = length / count;
+                tiles.step = tiles.tile;
+                tiles.count = count.to_usize().unwrap_or(0);
+            }
+            RepeatMode::Space => {
+                let count = (length / tile).floor();
+                let gap = (length - count * tile) / (count + T::one());
+                tiles.start = gap;
+                tiles.tile = tile;
+                tiles.step = tile + gap;
+                tiles.count = count.to_usize().unwrap_or(0);
+                tiles.snap_end = false;
+            }
+        }
+
+        tiles
+    }
+
+    /// Returns the number of tiles.
+    pub(crate) fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the source and destination ranges of a tile, clipped to the
+    /// destination.
+    pub(crate) fn get(&self, index: usize) -> ((T, T), (T, T)) {
+        let i = T::from(index).unwrap();
+        let start = self.dst.0 + self.start + self.step * i;
+        let end = if self.snap_end && index + 1 == self.count {
+            self.dst.1
+        } else {
+            start + self.tile
+        };
+
+        let dst_start = start.max(self.dst.0);
+        let dst_end = end.min(self.dst.1);
+        let src_length = self.src.1 - self.src.0;
+        let src_start = if dst_start == start {
+            self.src.0
+        } else {
+            self.src.0 + (dst_start - start) / (end - start) * src_length
+        };
+        let src_end = if dst_end == end {
+            self.src.1
+        } else {
+            self.src.0 + (dst_end - start) / (end - start) * src_length
+        };
+
+        ((src_start, src_end), (dst_start, dst_end))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AxisTiles, RepeatMode};
+
+    type Range = (f32, f32);
+
+    fn tiles(mode: RepeatMode, src: Range, dst: Range, tile: f32) -> Vec<(Range, Range)> {
+        let t = AxisTiles::new(mode, src, dst, tile);
+        (0..t.count()).map(|i| t.get(i)).collect()
+    }
+
+    #[test]
+    fn test_stretch() {
+        assert_eq!(
+            tiles(RepeatMode::Stretch, (0.0, 10.0), (5.0, 35.0), 10.0),
+            [((0.0, 10.0), (5.0, 35.0))]
+        );
+        assert!(tiles(RepeatMode::Stretch, (0.0, 0.0), (5.0, 35.0), 10.0).is_empty());
+        assert!(tiles(RepeatMode::Round, (0.0, 10.0), (5.0, 5.0), 10.0).is_empty());
+    }
+
+    #[test]
+    fn test_repeat() {
+        // Three tiles of 10 fit exactly.
+        assert_eq!(
+            tiles(RepeatMode::Repeat, (0.0, 4.0), (0.0, 30.0), 10.0),
+            [
+                ((0.0, 4.0), (0.0, 10.0)),
+                ((0.0, 4.0), (10.0, 20.0)),
+                ((0.0, 4.0), (20.0, 30.0)),
+            ]
+        );
+        // The middle tile is centered, and the ones at the edges are clipped.
+        assert_eq!(
+            tiles(RepeatMode::Repeat, (0.0, 4.0), (0.0, 20.0), 8.0),
+            [
+                ((1.0, 4.0), (0.0, 6.0)),
+                ((0.0, 4.0), (6.0, 14.0)),
+                ((0.0, 3.0), (14.0, 20.0)),
+            ]
+        );
+        // A tile larger than the destination is clipped on both sides.
+        assert_eq!(
+            tiles(RepeatMode::Repeat, (0.0, 4.0), (0.0, 10.0), 20.0),
+            [((1.0, 3.0), (0.0, 10.0))]
+        );
+    }
+
+    #[test]
+    fn test_round() {
+        assert_eq!(
+            tiles(RepeatMode::Round, (0.0, 4.0), (0.0, 24.0), 10.0),
+            [((0.0, 4.0), (0.0, 12.0)), ((0.0, 4.0), (12.0, 24.0))]
+        );
+        assert_eq!(
+            tiles(RepeatMode::Round, (0.0, 4.0), (0.0, 4.0), 10.0),
+            [((0.0, 4.0), (0.0, 4.0))]
+        );
+        let t = tiles(RepeatMode::Round, (0.0, 1.0), (0.1, 1.0), 0.3);
+        assert_eq!(t.len(), 3);
+        assert_eq!(t[2].1 .1, 1.0);
+    }
+
+    #[test]
+    fn test_space() {
+        assert_eq!(
+            tiles(RepeatMode::Space, (0.0, 4.0), (0.0, 32.0), 10.0),
+            [
+                ((0.0, 4.0), (0.5, 10.5)),
+                ((0.0, 4.0), (11.0, 21.0)),
+                ((0.0, 4.0), (21.5, 31.5)),
+            ]
+        );
+        assert!(tiles(RepeatMode::Space, (0.0, 4.0), (0.0, 5.0), 10.0).is_empty());
+    }
+}
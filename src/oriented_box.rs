@@ -0,0 +1,569 @@
+// Copyright 2013 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::UnknownUnit;
+use crate::approxeq::ApproxEq;
+use crate::box2d::Box2D;
+use crate::box3d::Box3D;
+use crate::point::{Point2D, Point3D};
+use crate::rigid::RigidTransform3D;
+use crate::rotation::{Rotation2D, Rotation3D};
+use crate::vector::{vec2, vec3, Vector2D, Vector3D};
+
+use num_traits::real::Real;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use core::fmt;
+use core::hash::{Hash, Hasher};
+
+/// A 2d box that is not necessarily aligned with the axes of its coordinate space,
+/// represented by its center, its half extents and a rotation.
+///
+/// The box contains the points `center + rotation.transform_vector(v)` where
+/// `v.x.abs() <= half_extents.x` and `v.y.abs() <= half_extents.y`.
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Serialize", deserialize = "T: Deserialize<'de>"))
+)]
+pub struct OrientedBox2D<T, U> {
+    pub center: Point2D<T, U>,
+    pub half_extents: Vector2D<T, U>,
+    pub rotation: Rotation2D<T, U, U>,
+}
+
+impl<T: Hash, U> Hash for OrientedBox2D<T, U> {
+    fn hash<H: Hasher>(&self, h: &mut H) {
+        self.center.hash(h);
+        self.half_extents.hash(h);
+        self.rotation.hash(h);
+    }
+}
+
+impl<T: Copy, U> Copy for OrientedBox2D<T, U> {}
+
+impl<T: Clone, U> Clone for OrientedBox2D<T, U> {
+    fn clone(&self) -> Self {
+        Self::new(
+            self.center.clone(),
+            self.half_extents.clone(),
+            self.rotation.clone(),
+        )
+    }
+}
+
+impl<T: PartialEq, U> PartialEq for OrientedBox2D<T, U> {
+    fn eq(&self, other: &Self) -> bool {
+        self.center.eq(&other.center)
+            && self.half_extents.eq(&other.half_extents)
+            && self.rotation.eq(&other.rotation)
+    }
+}
+
+impl<T: Eq, U> Eq for OrientedBox2D<T, U> {}
+
+impl<T: fmt::Debug, U> fmt::Debug for OrientedBox2D<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("OrientedBox2D")
+            .field(&self.center)
+            .field(&self.half_extents)
+            .field(&self.rotation.angle)
+            .finish()
+    }
+}
+
+impl<T, U> OrientedBox2D<T, U> {
+    /// Constructor.
+    #[inline]
+    pub const fn new(
+        center: Point2D<T, U>,
+        half_extents: Vector2D<T, U>,
+        rotation: Rotation2D<T, U, U>,
+    ) -> Self {
+        OrientedBox2D {
+            center,
+            half_extents,
+            rotation,
+        }
+    }
+}
+
+impl<T: Copy, U> OrientedBox2D<T, U> {
+    /// Drop the units, preserving only the numeric value.
+    #[inline]
+    pub fn to_untyped(&self) -> OrientedBox2D<T, UnknownUnit> {
+        self.cast_unit()
+    }
+
+    /// Tag a unitless value with units.
+    #[inline]
+    pub fn from_untyped(b: &OrientedBox2D<T, UnknownUnit>) -> Self {
+        b.cast_unit()
+    }
+
+    /// Cast the unit
+    #[inline]
+    pub fn cast_unit<V>(&self) -> OrientedBox2D<T, V> {
+        OrientedBox2D::new(
+            self.center.cast_unit(),
+            self.half_extents.cast_unit(),
+            self.rotation.cast_unit(),
+        )
+    }
+}
+
+impl<T: Real, U> OrientedBox2D<T, U> {
+    /// Creates an oriented box covering the same area as an axis-aligned box.
+    pub fn from_box(b: &Box2D<T, U>) -> Self {
+        let two = T::one() + T::one();
+        OrientedBox2D::new(
+            b.center(),
+            (b.max - b.min) / two,
+            Rotation2D::radians(T::zero()),
+        )
+    }
+
+    /// Returns the directions of the local x and y axes of the box.
+    #[inline]
+    pub fn axes(&self) -> [Vector2D<T, U>; 2] {
+        let (sin, cos) = self.rotation.angle.sin_cos();
+        [vec2(cos, sin), vec2(-sin, cos)]
+    }
+
+    /// Returns the four corners of the box, in counter-clockwise order (in a y-up
+    /// coordinate system) starting with the corner at `-half_extents`.
+    pub fn corners(&self) -> [Point2D<T, U>; 4] {
+        let [ax, ay] = self.axes();
+        let x = ax * self.half_extents.x;
+        let y = ay * self.half_extents.y;
+        let c = self.center;
+        [c - x - y, c + x - y, c + x + y, c - x + y]
+    }
+
+    /// Returns `true` if the point is inside of the box or on its boundary.
+    pub fn contains(&self, p: Point2D<T, U>) -> bool {
+        let [ax, ay] = self.axes();
+        let v = p - self.center;
+        v.dot(ax).abs() <= self.half_extents.x && v.dot(ay).abs() <= self.half_extents.y
+    }
+
+    /// Returns `true` if the two boxes overlap or touch, using the separating axis
+    /// theorem.
+    pub fn intersects(&self, other: &Self) -> bool {
+        let a = self.axes();
+        let b = other.axes();
+        let t = other.center - self.center;
+
+        let radius = |axes: &[Vector2D<T, U>; 2], half: Vector2D<T, U>, l: Vector2D<T, U>| {
+            half.x * axes[0].dot(l).abs() + half.y * axes[1].dot(l).abs()
+        };
+
+        a.iter().chain(b.iter()).all(|&l| {
+            t.dot(l).abs() <= radius(&a, self.half_extents, l) + radius(&b, other.half_extents, l)
+        })
+    }
+
+    /// Returns `true` if this box and an axis-aligned box overlap or touch.
+    ///
+    /// Empty axis-aligned boxes never intersect.
+    #[inline]
+    pub fn intersects_box(&self, b: &Box2D<T, U>) -> bool {
+        !b.is_empty() && self.intersects(&OrientedBox2D::from_box(b))
+    }
+
+    /// Returns the smallest axis-aligned box containing this box.
+    pub fn to_box2d(&self) -> Box2D<T, U> {
+        let [ax, ay] = self.axes();
+        let h = self.half_extents;
+        let extents = vec2(
+            ax.x.abs() * h.x + ay.x.abs() * h.y,
+            ax.y.abs() * h.x + ay.y.abs() * h.y,
+        );
+        Box2D::new(self.center - extents, self.center + extents)
+    }
+
+    /// Returns this box translated by a vector.
+    #[inline]
+    pub fn translate(&self, by: Vector2D<T, U>) -> Self {
+        OrientedBox2D::new(self.center + by, self.half_extents, self.rotation)
+    }
+}
+
+/// A 3d box that is not necessarily aligned with the axes of its coordinate space,
+/// represented by its center, its half extents and a rotation.
+///
+/// The box contains the points `center + rotation.transform_vector3d(v)` where each
+/// component of `v` is within the corresponding half extent.
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Serialize", deserialize = "T: Deserialize<'de>"))
+)]
+pub struct OrientedBox3D<T, U> {
+    pub center: Point3D<T, U>,
+    pub half_extents: Vector3D<T, U>,
+    pub rotation: Rotation3D<T, U, U>,
+}
+
+impl<T: Hash, U> Hash for OrientedBox3D<T, U> {
+    fn hash<H: Hasher>(&self, h: &mut H) {
+        self.center.hash(h);
+        self.half_extents.hash(h);
+        self.rotation.hash(h);
+    }
+}
+
+impl<T: Copy, U> Copy for OrientedBox3D<T, U> {}
+
+impl<T: Clone, U> Clone for OrientedBox3D<T, U> {
+    fn clone(&self) -> Self {
+        Self::new(
+            self.center.clone(),
+            self.half_extents.clone(),
+            self.rotation.clone(),
+        )
+    }
+}
+
+impl<T: PartialEq, U> PartialEq for OrientedBox3D<T, U> {
+    fn eq(&self, other: &Self) -> bool {
+        self.center.eq(&other.center)
+            && self.half_extents.eq(&other.half_extents)
+            && self.rotation.eq(&other.rotation)
+    }
+}
+
+impl<T: Eq, U> Eq for OrientedBox3D<T, U> {}
+
+impl<T: fmt::Debug, U> fmt::Debug for OrientedBox3D<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("OrientedBox3D")
+            .field(&self.center)
+            .field(&self.half_extents)
+            .field(&self.rotation)
+            .finish()
+    }
+}
+
+impl<T, U> OrientedBox3D<T, U> {
+    /// Constructor.
+    #[inline]
+    pub const fn new(
+        center: Point3D<T, U>,
+        half_extents: Vector3D<T, U>,
+        rotation: Rotation3D<T, U, U>,
+    ) -> Self {
+        OrientedBox3D {
+            center,
+            half_extents,
+            rotation,
+        }
+    }
+}
+
+impl<T: Copy, U> OrientedBox3D<T, U> {
+    /// Drop the units, preserving only the numeric value.
+    #[inline]
+    pub fn to_untyped(&self) -> OrientedBox3D<T, UnknownUnit> {
+        self.cast_unit()
+    }
+
+    /// Tag a unitless value with units.
+    #[inline]
+    pub fn from_untyped(b: &OrientedBox3D<T, UnknownUnit>) -> Self {
+        b.cast_unit()
+    }
+
+    /// Cast the unit
+    #[inline]
+    pub fn cast_unit<V>(&self) -> OrientedBox3D<T, V> {
+        OrientedBox3D::new(
+            self.center.cast_unit(),
+            self.half_extents.cast_unit(),
+            self.rotation.cast_unit(),
+        )
+    }
+}
+
+impl<T: Real + ApproxEq<T>, U> OrientedBox3D<T, U> {
+    /// Creates an oriented box covering the same volume as an axis-aligned box.
+    pub fn from_box(b: &Box3D<T, U>) -> Self {
+        let two = T::one() + T::one();
+        OrientedBox3D::new(b.center(), (b.max - b.min) / two, Rotation3D::identity())
+    }
+
+    /// Returns the directions of the local x, y and z axes of the box.
+    #[inline]
+    pub fn axes(&self) -> [Vector3D<T, U>; 3] {
+        let zero = T::zero();
+        let one = T::one();
+        [
+            self.rotation.transform_vector3d(vec3(one, zero, zero)),
+            self.rotation.transform_vector3d(vec3(zero, one, zero)),
+            self.rotation.transform_vector3d(vec3(zero, zero, one)),
+        ]
+    }
+
+    /// Returns the eight corners of the box.
+    ///
+    /// The bits of the index of each corner select the sign of its local coordinates:
+    /// the first bit is set for positive x, the second one for positive y and the third
+    /// one for positive z.
+    pub fn corners(&self) -> [Point3D<T, U>; 8] {
+        let [ax, ay, az] = self.axes();
+        let h = self.half_extents;
+        let mut corners = [self.center; 8];
+        for (i, corner) in corners.iter_mut().enumerate() {
+            let sign = |bit: usize| if i & bit == 0 { -T::one() } else { T::one() };
+            *corner = self.center
+                + ax * (h.x * sign(1))
+                + ay * (h.y * sign(2))
+                + az * (h.z * sign(4));
+        }
+        corners
+    }
+
+    /// Returns `true` if the point is inside of the box or on its boundary.
+    pub fn contains(&self, p: Point3D<T, U>) -> bool {
+        let [ax, ay, az] = self.axes();
+        let v = p - self.center;
+        let h = self.half_extents;
+        v.dot(ax).abs() <= h.x && v.dot(ay).abs() <= h.y && v.dot(az).abs() <= h.z
+    }
+
+    /// Returns `true` if the two boxes overlap or touch, using the separating axis
+    /// theorem.
+    pub fn intersects(&self, other: &Self) -> bool {
+        // See "Real-Time Collision Detection" by Christer Ericson, section 4.4.1.
+        let a = self.axes();
+        let b = other.axes();
+        let ea = self.half_extents.to_array();
+        let eb = other.half_extents.to_array();
+
+        let zero = T::zero();
+        let mut r = [[zero; 3]; 3];
+        let mut abs_r = [[zero; 3]; 3];
+        for i in 0..3 {
+            for j in 0..3 {
+                r[i][j] = a[i].dot(b[j]);
+                // Adding an epsilon keeps the cross product axes from degenerating when
+                // two edges are parallel.
+                abs_r[i][j] = r[i][j].abs() + T::epsilon();
+            }
+        }
+
+        let d = other.center - self.center;
+        let t = [d.dot(a[0]), d.dot(a[1]), d.dot(a[2])];
+
+        // The axes of self.
+        for i in 0..3 {
+            let rb = eb[0] * abs_r[i][0] + eb[1] * abs_r[i][1] + eb[2] * abs_r[i][2];
+            if t[i].abs() > ea[i] + rb {
+                return false;
+            }
+        }
+
+        // The axes of other.
+        for j in 0..3 {
+            let ra = ea[0] * abs_r[0][j] + ea[1] * abs_r[1][j] + ea[2] * abs_r[2][j];
+            let dist = t[0] * r[0][j] + t[1] * r[1][j] + t[2] * r[2][j];
+            if dist.abs() > ra + eb[j] {
+                return false;
+            }
+        }
+
+        // The cross products of the axes of self and other.
+        for i in 0..3 {
+            let (i1, i2) = ((i + 1) % 3, (i + 2) % 3);
+            for j in 0..3 {
+                let (j1, j2) = ((j + 1) % 3, (j + 2) % 3);
+                let ra = ea[i1] * abs_r[i2][j] + ea[i2] * abs_r[i1][j];
+                let rb = eb[j1] * abs_r[i][j2] + eb[j2] * abs_r[i][j1];
+                let dist = t[i2] * r[i1][j] - t[i1] * r[i2][j];
+                if dist.abs() > ra + rb {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+
+    /// Returns `true` if this box and an axis-aligned box overlap or touch.
+    ///
+    /// Empty axis-aligned boxes never intersect.
+    #[inline]
+    pub fn intersects_box(&self, b: &Box3D<T, U>) -> bool {
+        !b.is_empty() && self.intersects(&OrientedBox3D::from_box(b))
+    }
+
+    /// Returns the smallest axis-aligned box containing this box.
+    pub fn to_box3d(&self) -> Box3D<T, U> {
+        let [ax, ay, az] = self.axes();
+        let h = self.half_extents;
+        let extents = vec3(
+            ax.x.abs() * h.x + ay.x.abs() * h.y + az.x.abs() * h.z,
+            ax.y.abs() * h.x + ay.y.abs() * h.y + az.y.abs() * h.z,
+            ax.z.abs() * h.x + ay.z.abs() * h.y + az.z.abs() * h.z,
+        );
+        Box3D::new(self.center - extents, self.center + extents)
+    }
+
+    /// Returns this box translated by a vector.
+    #[inline]
+    pub fn translate(&self, by: Vector3D<T, U>) -> Self {
+        OrientedBox3D::new(self.center + by, self.half_extents, self.rotation)
+    }
+
+    /// Returns this box transformed by a rigid transform.
+    ///
+    /// Unlike axis-aligned boxes, oriented boxes are transformed exactly.
+    pub fn transform<Dst>(&self, transform: &RigidTransform3D<T, U, Dst>) -> OrientedBox3D<T, Dst> {
+        let center = transform.rotation.transform_point3d(self.center) + transform.translation;
+        OrientedBox3D::new(
+            center,
+            self.half_extents.cast_unit(),
+            self.rotation.then(&transform.rotation).cast_unit(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::approxeq::ApproxEq;
+    use crate::default::{Box2D, Box3D, OrientedBox2D, OrientedBox3D, RigidTransform3D};
+    use crate::default::{Rotation2D, Rotation3D};
+    use crate::{point2, point3, vec2, vec3, Angle};
+    use core::f64::consts::FRAC_PI_4;
+
+    #[test]
+    fn test_2d_contains() {
+        let b = OrientedBox2D::new(point2(1.0, 1.0), vec2(2.0, 1.0), Rotation2D::radians(FRAC_PI_4));
+        assert!(b.contains(point2(1.0, 1.0)));
+        assert!(b.contains(point2(2.0, 2.0)));
+        assert!(!b.contains(point2(3.0, 1.0)));
+        assert!(!b.contains(point2(2.0, 0.0)));
+
+        for c in &b.corners() {
+            assert!(b.contains(*c + (b.center - *c) * 0.001));
+            assert!(!b.contains(*c + (*c - b.center) * 0.001));
+        }
+    }
+
+    #[test]
+    fn test_2d_to_box() {
+        let b = OrientedBox2D::new(point2(1.0, 2.0), vec2(1.0, 1.0), Rotation2D::radians(FRAC_PI_4));
+        let s = 2.0f64.sqrt();
+        assert!(b.to_box2d().min.approx_eq(&point2(1.0 - s, 2.0 - s)));
+        assert!(b.to_box2d().max.approx_eq(&point2(1.0 + s, 2.0 + s)));
+
+        let aabb = Box2D::new(point2(-1.0, 2.0), point2(3.0, 5.0));
+        let b = OrientedBox2D::from_box(&aabb);
+        assert_eq!(b.to_box2d(), aabb);
+    }
+
+    #[test]
+    fn test_2d_intersects() {
+        let s = 2.0f64.sqrt();
+        let a = OrientedBox2D::new(point2(0.0, 0.0), vec2(1.0, 1.0), Rotation2D::radians(FRAC_PI_4));
+        // The corner of `a` is at (s, 0).
+        let b = OrientedBox2D::new(point2(s + 1.1, 0.0), vec2(1.0, 1.0), Rotation2D::radians(0.0));
+        assert!(!a.intersects(&b));
+        assert!(!b.intersects(&a));
+        let b = b.translate(vec2(-0.2, 0.0));
+        assert!(a.intersects(&b));
+        assert!(b.intersects(&a));
+
+        // The bounding boxes overlap but not the boxes themselves.
+        let c = a.translate(vec2(1.6, 1.6));
+        assert!(a.to_box2d().intersects(&c.to_box2d()));
+        assert!(!a.intersects(&c));
+
+        assert!(a.intersects_box(&Box2D::new(point2(0.5, 0.5), point2(2.0, 2.0))));
+        assert!(!a.intersects_box(&Box2D::new(point2(0.8, 0.8), point2(2.0, 2.0))));
+        assert!(!a.intersects_box(&Box2D::new(point2(1.0, 1.0), point2(0.0, 0.0))));
+        // Zero-area boxes are empty, even inside of the oriented box.
+        assert!(!a.intersects_box(&Box2D::new(a.center, a.center)));
+    }
+
+    #[test]
+    fn test_3d_contains_and_corners() {
+        let r = Rotation3D::around_axis(vec3(1.0, 1.0, 0.0), Angle::radians(0.7));
+        let b = OrientedBox3D::new(point3(1.0, 2.0, 3.0), vec3(1.0, 2.0, 3.0), r);
+        assert!(b.contains(b.center));
+        for c in &b.corners() {
+            assert!(b.contains(*c + (b.center - *c) * 0.001));
+            assert!(!b.contains(*c + (*c - b.center) * 0.001));
+        }
+
+        // The bounding box touches the extreme corners.
+        let aabb = b.to_box3d();
+        let from_corners = Box3D::from_points(b.corners().iter());
+        assert!(aabb.min.approx_eq(&from_corners.min));
+        assert!(aabb.max.approx_eq(&from_corners.max));
+    }
+
+    #[test]
+    fn test_3d_intersects() {
+        let s = 2.0f64.sqrt();
+        let unit = vec3(1.0, 1.0, 1.0);
+
+        // `a` has an edge parallel to the y axis at x = s, and `b` an edge parallel to
+        // the z axis at x = s + gap. None of the face axes separate these boxes, only
+        // the cross product of those edges does.
+        let a = OrientedBox3D::new(
+            point3(0.0, 0.0, 0.0),
+            unit,
+            Rotation3D::around_y(Angle::radians(FRAC_PI_4)),
+        );
+        let b = |gap: f64| {
+            OrientedBox3D::new(
+                point3(2.0 * s + gap, 0.0, 0.0),
+                unit,
+                Rotation3D::around_z(Angle::radians(FRAC_PI_4)),
+            )
+        };
+        assert!(!a.intersects(&b(0.1)));
+        assert!(!b(0.1).intersects(&a));
+        assert!(a.intersects(&b(-0.1)));
+        assert!(b(-0.1).intersects(&a));
+
+        // Parallel boxes.
+        let c = OrientedBox3D::new(point3(2.5, 0.0, 0.0), unit, Rotation3D::identity());
+        let d = OrientedBox3D::new(point3(0.0, 0.0, 0.0), unit, Rotation3D::identity());
+        assert!(!c.intersects(&d));
+        assert!(c.translate(vec3(-0.6, 0.0, 0.0)).intersects(&d));
+
+        let aabb = Box3D::new(point3(1.2, -1.0, -1.0), point3(2.0, 1.0, 1.0));
+        assert!(a.intersects_box(&aabb));
+        assert!(!a.intersects_box(&aabb.translate(vec3(0.3, 0.0, 0.0))));
+        assert!(!a.intersects_box(&Box3D::new(a.center, a.center)));
+    }
+
+    #[test]
+    fn test_3d_transform() {
+        let b = OrientedBox3D::new(
+            point3(1.0, 2.0, 3.0),
+            vec3(1.0, 2.0, 3.0),
+            Rotation3D::around_x(Angle::radians(0.3)),
+        );
+        let t = RigidTransform3D::new(
+            Rotation3D::around_axis(vec3(0.0, 1.0, 1.0), Angle::radians(1.1)),
+            vec3(-4.0, 5.0, 6.0),
+        );
+        let transformed = b.transform(&t);
+        let m = t.to_transform();
+        for (c, tc) in b.corners().iter().zip(transformed.corners().iter()) {
+            assert!(m.transform_point3d(*c).unwrap().approx_eq(tc));
+        }
+    }
+}
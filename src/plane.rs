@@ -0,0 +1,343 @@
+// Copyright 2013 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::UnknownUnit;
+use crate::approxeq::ApproxEq;
+use crate::point::Point3D;
+use crate::ray::Ray3D;
+use crate::segment::LineSegment3D;
+use crate::vector::Vector3D;
+
+use num_traits::real::Real;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "bytemuck")]
+use bytemuck::{Zeroable, Pod};
+
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::{Add, Mul, Neg};
+
+/// A plane in 3d space, defined as the set of points `p` such that
+/// `normal.dot(p) + offset == 0`.
+///
+/// The normal points towards the positive side of the plane. Distances returned
+/// by the methods of this type are only true euclidean distances if the normal
+/// is normalized, which is the case for planes created with [`from_points`] or
+/// transformed with [`Transform3D::transform_plane`].
+///
+/// [`from_points`]: #method.from_points
+/// [`Transform3D::transform_plane`]: struct.Transform3D.html#method.transform_plane
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Serialize", deserialize = "T: Deserialize<'de>"))
+)]
+pub struct Plane3D<T, U> {
+    pub normal: Vector3D<T, U>,
+    pub offset: T,
+}
+
+impl<T: Hash, U> Hash for Plane3D<T, U> {
+    fn hash<H: Hasher>(&self, h: &mut H) {
+        self.normal.hash(h);
+        self.offset.hash(h);
+    }
+}
+
+impl<T: Copy, U> Copy for Plane3D<T, U> {}
+
+impl<T: Clone, U> Clone for Plane3D<T, U> {
+    fn clone(&self) -> Self {
+        Self::new(self.normal.clone(), self.offset.clone())
+    }
+}
+
+impl<T: PartialEq, U> PartialEq for Plane3D<T, U> {
+    fn eq(&self, other: &Self) -> bool {
+        self.normal.eq(&other.normal) && self.offset.eq(&other.offset)
+    }
+}
+
+impl<T: Eq, U> Eq for Plane3D<T, U> {}
+
+impl<T: fmt::Debug, U> fmt::Debug for Plane3D<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Plane3D")
+            .field(&self.normal)
+            .field(&self.offset)
+            .finish()
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, T, U> arbitrary::Arbitrary<'a> for Plane3D<T, U>
+where
+    T: arbitrary::Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self>
+    {
+        let (x, y, z, offset) = arbitrary::Arbitrary::arbitrary(u)?;
+        Ok(Plane3D {
+            normal: Vector3D::new(x, y, z),
+            offset,
+        })
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: Zeroable, U> Zeroable for Plane3D<T, U> {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: Pod, U: 'static> Pod for Plane3D<T, U> {}
+
+impl<T, U> Plane3D<T, U> {
+    /// Constructor.
+    #[inline]
+    pub const fn new(normal: Vector3D<T, U>, offset: T) -> Self {
+        Plane3D { normal, offset }
+    }
+}
+
+impl<T: Copy, U> Plane3D<T, U> {
+    /// Drop the units, preserving only the numeric value.
+    #[inline]
+    pub fn to_untyped(&self) -> Plane3D<T, UnknownUnit> {
+        Plane3D::new(self.normal.to_untyped(), self.offset)
+    }
+
+    /// Tag a unitless value with units.
+    #[inline]
+    pub fn from_untyped(p: &Plane3D<T, UnknownUnit>) -> Self {
+        Plane3D::new(Vector3D::from_untyped(p.normal), p.offset)
+    }
+
+    /// Cast the unit
+    #[inline]
+    pub fn cast_unit<V>(&self) -> Plane3D<T, V> {
+        Plane3D::new(self.normal.cast_unit(), self.offset)
+    }
+}
+
+impl<T, U> Plane3D<T, U>
+where
+    T: Copy + Neg<Output = T>,
+{
+    /// Returns the same plane with the positive and negative sides swapped.
+    #[inline]
+    #[must_use]
+    pub fn flip(&self) -> Self {
+        Plane3D::new(-self.normal, -self.offset)
+    }
+}
+
+impl<T, U> Plane3D<T, U>
+where
+    T: Copy + Add<Output = T> + Mul<Output = T> + Neg<Output = T>,
+{
+    /// Creates the plane going through `point`, oriented by `normal`.
+    #[inline]
+    pub fn from_point_and_normal(point: Point3D<T, U>, normal: Vector3D<T, U>) -> Self {
+        Plane3D::new(normal, -normal.dot(point.to_vector()))
+    }
+
+    /// Returns the signed distance between the plane and a point, positive on the
+    /// side the normal points to.
+    #[inline]
+    pub fn signed_distance_to_point(&self, p: Point3D<T, U>) -> T {
+        self.normal.dot(p.to_vector()) + self.offset
+    }
+}
+
+impl<T: Real, U> Plane3D<T, U> {
+    /// Creates the plane going through three points, or `None` if the points are
+    /// aligned.
+    ///
+    /// The normal is normalized and points towards the side from which `a`, `b` and
+    /// `c` appear in counter-clockwise order.
+    pub fn from_points(a: Point3D<T, U>, b: Point3D<T, U>, c: Point3D<T, U>) -> Option<Self> {
+        let normal = (b - a).cross(c - a);
+        let length = normal.length();
+        if length == T::zero() {
+            return None;
+        }
+
+        Some(Plane3D::from_point_and_normal(a, normal / length))
+    }
+
+    /// Returns the same plane with a normal of length one, or `None` if the normal
+    /// has a length of zero.
+    pub fn try_normalize(&self) -> Option<Self> {
+        let length = self.normal.length();
+        if length == T::zero() {
+            return None;
+        }
+
+        Some(Plane3D::new(self.normal / length, self.offset / length))
+    }
+
+    /// Returns the distance between the plane and a point.
+    #[inline]
+    pub fn distance_to_point(&self, p: Point3D<T, U>) -> T {
+        self.signed_distance_to_point(p).abs()
+    }
+
+    /// Returns the orthogonal projection of a point onto the plane.
+    #[inline]
+    pub fn project_point(&self, p: Point3D<T, U>) -> Point3D<T, U> {
+        let d = self.signed_distance_to_point(p) / self.normal.square_length();
+        p - self.normal * d
+    }
+
+    /// Returns the parameter of the point where the ray crosses the plane, if any.
+    ///
+    /// Rays that are parallel to the plane never intersect it.
+    pub fn intersect_ray(&self, ray: &Ray3D<T, U>) -> Option<T> {
+        let denom = self.normal.dot(ray.direction);
+        if denom == T::zero() {
+            return None;
+        }
+
+        let t = -self.signed_distance_to_point(ray.origin) / denom;
+        if t < T::zero() {
+            return None;
+        }
+
+        Some(t)
+    }
+
+    /// Returns the parameter of the point where the segment crosses the plane, if any.
+    ///
+    /// Segments that are parallel to the plane never intersect it.
+    pub fn intersect_segment(&self, segment: &LineSegment3D<T, U>) -> Option<T> {
+        let d0 = self.signed_distance_to_point(segment.from);
+        let d1 = self.signed_distance_to_point(segment.to);
+        if d0 == d1 {
+            return None;
+        }
+
+        let t = d0 / (d0 - d1);
+        if t < T::zero() || t > T::one() {
+            return None;
+        }
+
+        Some(t)
+    }
+
+    /// Returns the part of the segment that is on the positive side of the plane,
+    /// or `None` if the segment is entirely on the negative side.
+    pub fn clip_segment(&self, segment: &LineSegment3D<T, U>) -> Option<LineSegment3D<T, U>> {
+        let zero = T::zero();
+        let d0 = self.signed_distance_to_point(segment.from);
+        let d1 = self.signed_distance_to_point(segment.to);
+        match (d0 >= zero, d1 >= zero) {
+            (true, true) => Some(*segment),
+            (false, false) => None,
+            (true, false) => {
+                let p = segment.sample(d0 / (d0 - d1));
+                Some(LineSegment3D::new(segment.from, p))
+            }
+            (false, true) => {
+                let p = segment.sample(d0 / (d0 - d1));
+                Some(LineSegment3D::new(p, segment.to))
+            }
+        }
+    }
+}
+
+impl<T: ApproxEq<T>, U> ApproxEq<T> for Plane3D<T, U> {
+    #[inline]
+    fn approx_epsilon() -> T {
+        T::approx_epsilon()
+    }
+
+    #[inline]
+    fn approx_eq_eps(&self, other: &Self, eps: &T) -> bool {
+        self.normal.x.approx_eq_eps(&other.normal.x, eps)
+            && self.normal.y.approx_eq_eps(&other.normal.y, eps)
+            && self.normal.z.approx_eq_eps(&other.normal.z, eps)
+            && self.offset.approx_eq_eps(&other.offset, eps)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::approxeq::ApproxEq;
+    use crate::default::{LineSegment3D, Plane3D, Ray3D};
+    use crate::{point3, vec3};
+
+    #[test]
+    fn test_from_points() {
+        let p = Plane3D::from_points(
+            point3(0.0, 0.0, 2.0),
+            point3(1.0, 0.0, 2.0),
+            point3(0.0, 1.0, 2.0),
+        )
+        .unwrap();
+        assert_eq!(p.normal, vec3(0.0, 0.0, 1.0));
+        assert_eq!(p.offset, -2.0);
+
+        assert!(Plane3D::from_points(
+            point3(0.0, 0.0, 0.0),
+            point3(1.0, 1.0, 1.0),
+            point3(2.0, 2.0, 2.0),
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn test_distance_and_projection() {
+        let p = Plane3D::from_point_and_normal(point3(0.0, 3.0, 0.0), vec3(0.0, 1.0, 0.0));
+        assert_eq!(p.signed_distance_to_point(point3(4.0, 5.0, 6.0)), 2.0);
+        assert_eq!(p.signed_distance_to_point(point3(4.0, 1.0, 6.0)), -2.0);
+        assert_eq!(p.distance_to_point(point3(4.0, 1.0, 6.0)), 2.0);
+        assert_eq!(p.project_point(point3(4.0, 1.0, 6.0)), point3(4.0, 3.0, 6.0));
+
+        // Not normalized.
+        let p = Plane3D::new(vec3(0.0, 2.0, 0.0), -6.0);
+        assert_eq!(p.project_point(point3(4.0, 1.0, 6.0)), point3(4.0, 3.0, 6.0));
+        assert!(p.try_normalize().unwrap().approx_eq(&Plane3D::new(vec3(0.0, 1.0, 0.0), -3.0)));
+    }
+
+    #[test]
+    fn test_intersect_ray() {
+        let p = Plane3D::new(vec3(0.0, 0.0, 1.0), -1.0);
+
+        let r = Ray3D::new(point3(1.0, 1.0, -1.0), vec3(0.0, 0.0, 1.0));
+        assert_eq!(p.intersect_ray(&r), Some(2.0));
+
+        let r = Ray3D::new(point3(1.0, 1.0, -1.0), vec3(0.0, 0.0, -1.0));
+        assert_eq!(p.intersect_ray(&r), None);
+
+        let r = Ray3D::new(point3(1.0, 1.0, -1.0), vec3(1.0, 0.0, 0.0));
+        assert_eq!(p.intersect_ray(&r), None);
+    }
+
+    #[test]
+    fn test_segment() {
+        let p = Plane3D::new(vec3(0.0, 0.0, 1.0), -1.0);
+
+        let s = LineSegment3D::new(point3(0.0, 0.0, 0.0), point3(0.0, 0.0, 4.0));
+        assert_eq!(p.intersect_segment(&s), Some(0.25));
+        assert_eq!(
+            p.clip_segment(&s),
+            Some(LineSegment3D::new(point3(0.0, 0.0, 1.0), point3(0.0, 0.0, 4.0)))
+        );
+        assert_eq!(
+            p.flip().clip_segment(&s),
+            Some(LineSegment3D::new(point3(0.0, 0.0, 0.0), point3(0.0, 0.0, 1.0)))
+        );
+
+        let s = LineSegment3D::new(point3(0.0, 0.0, 2.0), point3(0.0, 0.0, 4.0));
+        assert_eq!(p.intersect_segment(&s), None);
+        assert_eq!(p.clip_segment(&s), Some(s));
+        assert_eq!(p.flip().clip_segment(&s), None);
+    }
+}
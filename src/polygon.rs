@@ -0,0 +1,789 @@
+// Copyright 2013 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::UnknownUnit;
+use crate::approxeq::ApproxEq;
+use crate::box2d::Box2D;
+use crate::num::*;
+use crate::line::Line2D;
+use crate::point::{point2, Point2D};
+use crate::segment::LineSegment2D;
+use crate::transform2d::Transform2D;
+use crate::transform3d::Transform3D;
+use crate::vector::Vector2D;
+
+use alloc::vec::Vec;
+use num_traits::real::Real;
+use num_traits::NumCast;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "serde")]
+use core::marker::PhantomData;
+
+use core::cmp::PartialOrd;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::iter::FromIterator;
+use core::ops::{Add, Div, Mul, Sub};
+
+/// The direction in which the vertices of a polygon go around it.
+///
+/// Counter-clockwise is the direction of increasing angles, which is the
+/// clockwise direction on screen when the y axis points down.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Orientation {
+    CounterClockwise,
+    Clockwise,
+}
+
+/// The rule deciding which points are inside of a polygon, from its winding number
+/// around them (see `Polygon2D::winding_number`).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum FillRule {
+    /// Points with an odd winding number are inside.
+    EvenOdd,
+    /// Points with a non-zero winding number are inside.
+    NonZero,
+}
+
+impl FillRule {
+    /// Returns `true` if points with this winding number are inside.
+    #[inline]
+    pub fn is_in(self, winding_number: i32) -> bool {
+        match self {
+            FillRule::EvenOdd => winding_number % 2 != 0,
+            FillRule::NonZero => winding_number != 0,
+        }
+    }
+}
+
+/// A closed polygon, represented by the sequence of its vertices.
+///
+/// The last vertex is implicitly connected to the first one. The polygon may be
+/// concave or self-intersecting.
+pub struct Polygon2D<T, U> {
+    pub points: Vec<Point2D<T, U>>,
+}
+
+/// Deserializes a `Vec` from a sequence.
+///
+/// Serde is used without its alloc feature, which rules out deriving the
+/// implementations for types with `Vec` fields.
+#[cfg(feature = "serde")]
+pub(crate) fn deserialize_vec<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: serde::Deserialize<'de>,
+{
+    struct VecVisitor<T>(PhantomData<T>);
+
+    impl<'de, T> serde::de::Visitor<'de> for VecVisitor<T>
+    where
+        T: serde::Deserialize<'de>,
+    {
+        type Value = Vec<T>;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("a sequence")
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: serde::de::SeqAccess<'de>,
+        {
+            let mut items = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+            while let Some(item) = seq.next_element()? {
+                items.push(item);
+            }
+            Ok(items)
+        }
+    }
+
+    deserializer.deserialize_seq(VecVisitor(PhantomData))
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T, U> serde::Deserialize<'de> for Polygon2D<T, U>
+where
+    T: serde::Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let points = deserialize_vec(deserializer)?;
+        Ok(Polygon2D { points })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T, U> serde::Serialize for Polygon2D<T, U>
+where
+    T: serde::Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.points.as_slice().serialize(serializer)
+    }
+}
+
+impl<T: Hash, U> Hash for Polygon2D<T, U> {
+    fn hash<H: Hasher>(&self, h: &mut H) {
+        self.points.hash(h);
+    }
+}
+
+impl<T: Clone, U> Clone for Polygon2D<T, U> {
+    fn clone(&self) -> Self {
+        Polygon2D::new(self.points.clone())
+    }
+}
+
+impl<T: PartialEq, U> PartialEq for Polygon2D<T, U> {
+    fn eq(&self, other: &Self) -> bool {
+        self.points.eq(&other.points)
+    }
+}
+
+impl<T: Eq, U> Eq for Polygon2D<T, U> {}
+
+impl<T: fmt::Debug, U> fmt::Debug for Polygon2D<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Polygon2D").field(&self.points).finish()
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, T, U> arbitrary::Arbitrary<'a> for Polygon2D<T, U>
+where
+    T: arbitrary::Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self>
+    {
+        let points = arbitrary::Arbitrary::arbitrary(u)?;
+        Ok(Polygon2D { points })
+    }
+}
+
+impl<T, U> Default for Polygon2D<T, U> {
+    fn default() -> Self {
+        Polygon2D::new(Vec::new())
+    }
+}
+
+impl<T, U> From<Vec<Point2D<T, U>>> for Polygon2D<T, U> {
+    fn from(points: Vec<Point2D<T, U>>) -> Self {
+        Polygon2D::new(points)
+    }
+}
+
+impl<T, U> FromIterator<Point2D<T, U>> for Polygon2D<T, U> {
+    fn from_iter<I: IntoIterator<Item = Point2D<T, U>>>(iter: I) -> Self {
+        Polygon2D::new(iter.into_iter().collect())
+    }
+}
+
+impl<T, U> Polygon2D<T, U> {
+    /// Constructor.
+    #[inline]
+    pub const fn new(points: Vec<Point2D<T, U>>) -> Self {
+        Polygon2D { points }
+    }
+
+    /// Returns the number of vertices.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Returns `true` if the polygon has no vertices.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// Reverses the order of the vertices, which flips the orientation.
+    #[inline]
+    pub fn reverse(&mut self) {
+        self.points.reverse();
+    }
+}
+
+impl<T: Copy, U> Polygon2D<T, U> {
+    /// Returns an iterator over the edges of the polygon, including the one going
+    /// from the last vertex back to the first one.
+    pub fn edges(&self) -> impl Iterator<Item = LineSegment2D<T, U>> + '_ {
+        let n = self.points.len();
+        (0..n).map(move |i| LineSegment2D::new(self.points[i], self.points[(i + 1) % n]))
+    }
+
+    /// Drop the units, preserving only the numeric value.
+    #[inline]
+    pub fn to_untyped(&self) -> Polygon2D<T, UnknownUnit> {
+        self.cast_unit()
+    }
+
+    /// Tag a unitless value with units.
+    #[inline]
+    pub fn from_untyped(p: &Polygon2D<T, UnknownUnit>) -> Self {
+        p.cast_unit()
+    }
+
+    /// Cast the unit
+    #[inline]
+    pub fn cast_unit<V>(&self) -> Polygon2D<T, V> {
+        self.points.iter().map(|p| p.cast_unit()).collect()
+    }
+}
+
+impl<T, U> Polygon2D<T, U>
+where
+    T: Copy + Add<T, Output = T>,
+{
+    /// Translate the polygon by a vector.
+    #[inline]
+    #[must_use]
+    pub fn translate(&self, by: Vector2D<T, U>) -> Self {
+        self.points.iter().map(|&p| p + by).collect()
+    }
+}
+
+impl<T, U> Polygon2D<T, U>
+where
+    T: Copy + Zero + PartialOrd,
+{
+    /// Returns the smallest box containing all of the vertices.
+    #[inline]
+    pub fn bounding_box(&self) -> Box2D<T, U> {
+        Box2D::from_points(&self.points)
+    }
+}
+
+impl<T, U> Polygon2D<T, U>
+where
+    T: Copy + Zero + PartialOrd + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    /// Twice the signed area, which is exact for integers.
+    fn double_signed_area(&self) -> T {
+        let mut sum = T::zero();
+        if let Some(&origin) = self.points.first() {
+            // Coordinates relative to the first vertex keep the products small.
+            for edge in self.edges() {
+                sum = sum + (edge.from - origin).cross(edge.to - origin);
+            }
+        }
+
+        sum
+    }
+
+    /// Returns the orientation of the polygon, or `None` if its signed area is zero.
+    ///
+    /// For self-intersecting polygons this is the orientation of the parts which
+    /// contribute the most to the signed area.
+    pub fn orientation(&self) -> Option<Orientation> {
+        let area = self.double_signed_area();
+        if area > T::zero() {
+            Some(Orientation::CounterClockwise)
+        } else if area < T::zero() {
+            Some(Orientation::Clockwise)
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if the polygon is convex.
+    ///
+    /// This requires all of the turns between consecutive edges to go in the same
+    /// direction and the vertices to go around only once, so that self-intersecting
+    /// polygons like pentagrams are not convex. Aligned and repeated vertices are
+    /// allowed, but polygons with less than three vertices or with no area are not
+    /// convex.
+    pub fn is_convex(&self) -> bool {
+        let n = self.points.len();
+        if n < 3 {
+            return false;
+        }
+
+        let zero = T::zero();
+        let mut turn = None;
+        let mut x_flips = SignFlips::new();
+        let mut y_flips = SignFlips::new();
+        for i in 0..n {
+            let a = self.points[i];
+            let b = self.points[(i + 1) % n];
+            let c = self.points[(i + 2) % n];
+            let edge = b - a;
+
+            let cross = edge.cross(c - b);
+            if cross != zero {
+                let left = cross > zero;
+                match turn {
+                    None => turn = Some(left),
+                    Some(t) if t != left => return false,
+                    Some(_) => {}
+                }
+            }
+
+            x_flips.add(edge.x);
+            y_flips.add(edge.y);
+        }
+
+        // A convex polygon goes back and forth only once along each axis.
+        turn.is_some() && x_flips.count() <= 2 && y_flips.count() <= 2
+    }
+
+    /// Returns the number of times the polygon winds around a point, counting the
+    /// counter-clockwise loops positively.
+    ///
+    /// The computation is exact for integers. Each point of the plane is on exactly
+    /// one side of every edge: points on the left and bottom boundaries of a polygon
+    /// with counter-clockwise orientation are inside, while points on its right and
+    /// top boundaries are outside. This lets polygons sharing edges partition the
+    /// plane without overlaps or gaps.
+    pub fn winding_number(&self, p: Point2D<T, U>) -> i32 {
+        let zero = T::zero();
+        let mut winding = 0;
+        for edge in self.edges() {
+            let side = edge.to_vector().cross(p - edge.from);
+            if edge.from.y <= p.y {
+                if edge.to.y > p.y && side > zero {
+                    winding += 1;
+                }
+            } else if edge.to.y <= p.y && side < zero {
+                winding -= 1;
+            }
+        }
+
+        winding
+    }
+
+    /// Returns `true` if the point is inside of the polygon according to the fill rule.
+    ///
+    /// See `winding_number` for how points on the boundary are classified.
+    #[inline]
+    pub fn contains(&self, p: Point2D<T, U>, fill_rule: FillRule) -> bool {
+        fill_rule.is_in(self.winding_number(p))
+    }
+}
+
+impl<T, U> Polygon2D<T, U>
+where
+    T: Copy + Zero + One + PartialOrd + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    /// Returns the signed area of the polygon, positive if its orientation is
+    /// counter-clockwise.
+    ///
+    /// Loops of self-intersecting polygons contribute with different signs
+    /// depending on their orientation.
+    #[inline]
+    pub fn signed_area(&self) -> T {
+        self.double_signed_area() / (T::one() + T::one())
+    }
+}
+
+impl<T: Real, U> Polygon2D<T, U> {
+    /// Returns the center of mass of the polygon, or `None` if its signed area is zero.
+    pub fn centroid(&self) -> Option<Point2D<T, U>> {
+        let origin = *self.points.first()?;
+
+        let mut area = T::zero();
+        let mut moment = Vector2D::zero();
+        for edge in self.edges() {
+            let a = edge.from - origin;
+            let b = edge.to - origin;
+            let cross = a.cross(b);
+            area = area + cross;
+            moment += (a + b) * cross;
+        }
+
+        if area == T::zero() {
+            return None;
+        }
+
+        let three = T::one() + T::one() + T::one();
+        Some(origin + moment / (area * three))
+    }
+}
+
+impl<T: Real, U> Polygon2D<T, U> {
+    /// Returns the part of the polygon on the left side of the line, or on the line
+    /// itself.
+    ///
+    /// The left side is in the direction of the line's vector rotated by 90 degrees
+    /// counter-clockwise (see `Line2D::signed_distance_to_point`).
+    pub fn clip_to_half_plane(&self, line: &Line2D<T, U>) -> Self {
+        Polygon2D::new(clip_polygon(
+            &self.points,
+            |p| line.vector.cross(*p - line.point),
+            |a, b, t| a.lerp(*b, t),
+        ))
+    }
+
+    /// Returns the part of the polygon that is inside of the box.
+    ///
+    /// The points created on the sides of the box have exactly the coordinate of
+    /// the side. The result is empty if the box is empty.
+    pub fn clip_to_box(&self, b: &Box2D<T, U>) -> Self {
+        if b.is_empty() {
+            return Polygon2D::default();
+        }
+
+        let lerp = |a: T, b: T, t: T| a + (b - a) * t;
+        let points = clip_polygon(&self.points, |p| p.x - b.min.x, |p, q, t| {
+            point2(b.min.x, lerp(p.y, q.y, t))
+        });
+        let points = clip_polygon(&points, |p| b.max.x - p.x, |p, q, t| {
+            point2(b.max.x, lerp(p.y, q.y, t))
+        });
+        let points = clip_polygon(&points, |p| p.y - b.min.y, |p, q, t| {
+            point2(lerp(p.x, q.x, t), b.min.y)
+        });
+        let points = clip_polygon(&points, |p| b.max.y - p.y, |p, q, t| {
+            point2(lerp(p.x, q.x, t), b.max.y)
+        });
+
+        Polygon2D::new(points)
+    }
+
+    /// Returns the part of the polygon that is inside of a convex polygon, which can
+    /// have either orientation.
+    ///
+    /// The result is empty if the clipping polygon has no area.
+    pub fn clip_to_convex(&self, clip: &Polygon2D<T, U>) -> Self {
+        let sign = match clip.orientation() {
+            Some(Orientation::CounterClockwise) => T::one(),
+            Some(Orientation::Clockwise) => -T::one(),
+            None => return Polygon2D::default(),
+        };
+
+        let mut points = self.points.clone();
+        for edge in clip.edges() {
+            let vector = edge.to_vector();
+            points = clip_polygon(
+                &points,
+                |p| vector.cross(*p - edge.from) * sign,
+                |a, b, t| a.lerp(*b, t),
+            );
+        }
+
+        Polygon2D::new(points)
+    }
+}
+
+impl<T, U> Polygon2D<T, U>
+where
+    T: Copy + Add<Output = T> + Mul<Output = T>,
+{
+    /// Applies the transform to all of the vertices of the polygon.
+    #[inline]
+    pub fn transform<Dst>(&self, transform: &Transform2D<T, U, Dst>) -> Polygon2D<T, Dst> {
+        self.points.iter().map(|&p| transform.transform_point(p)).collect()
+    }
+
+    /// Applies the transform to all of the vertices of the polygon, returning `None`
+    /// if any of them ends up behind the projection plane (see
+    /// [`Transform3D::transform_point2d`]).
+    ///
+    /// [`Transform3D::transform_point2d`]: struct.Transform3D.html#method.transform_point2d
+    pub fn transform3d<Dst>(&self, transform: &Transform3D<T, U, Dst>) -> Option<Polygon2D<T, Dst>>
+    where
+        T: Div<Output = T> + Zero + PartialOrd,
+    {
+        self.points.iter().map(|&p| transform.transform_point2d(p)).collect()
+    }
+}
+
+impl<T: NumCast + Copy, U> Polygon2D<T, U> {
+    /// Cast from one numeric representation to another, preserving the units.
+    #[inline]
+    pub fn cast<NewT: NumCast>(&self) -> Polygon2D<NewT, U> {
+        self.points.iter().map(|p| p.cast()).collect()
+    }
+
+    /// Fallible cast from one numeric representation to another, preserving the units.
+    pub fn try_cast<NewT: NumCast>(&self) -> Option<Polygon2D<NewT, U>> {
+        self.points.iter().map(|p| p.try_cast()).collect()
+    }
+
+    // Convenience functions for common casts
+
+    /// Cast into an `f32` polygon.
+    #[inline]
+    pub fn to_f32(&self) -> Polygon2D<f32, U> {
+        self.cast()
+    }
+
+    /// Cast into an `f64` polygon.
+    #[inline]
+    pub fn to_f64(&self) -> Polygon2D<f64, U> {
+        self.cast()
+    }
+}
+
+impl<T: ApproxEq<T>, U> ApproxEq<T> for Polygon2D<T, U> {
+    #[inline]
+    fn approx_epsilon() -> T {
+        T::approx_epsilon()
+    }
+
+    #[inline]
+    fn approx_eq_eps(&self, other: &Self, eps: &T) -> bool {
+        self.points.len() == other.points.len()
+            && self.points.iter().zip(&other.points).all(|(a, b)| {
+                a.x.approx_eq_eps(&b.x, eps) && a.y.approx_eq_eps(&b.y, eps)
+            })
+    }
+}
+
+/// One step of the Sutherland–Hodgman algorithm: returns the part of a polygon
+/// where `distance` is positive or zero.
+///
+/// `lerp` returns the point at a ratio between two vertices, which is used to create
+/// the vertices where the edges cross the boundary. Clipping a concave polygon can
+/// produce degenerate edges along the boundary, connecting the separate parts.
+pub(crate) fn clip_polygon<P, T>(
+    points: &[P],
+    distance: impl Fn(&P) -> T,
+    lerp: impl Fn(&P, &P, T) -> P,
+) -> Vec<P>
+where
+    P: Copy,
+    T: Copy + Zero + PartialOrd + Sub<Output = T> + Div<Output = T>,
+{
+    let zero = T::zero();
+    let n = points.len();
+    let mut result = Vec::with_capacity(n + 1);
+    for i in 0..n {
+        let current = &points[i];
+        let next = &points[(i + 1) % n];
+        let current_distance = distance(current);
+        let next_distance = distance(next);
+
+        if current_distance >= zero {
+            result.push(*current);
+        }
+
+        // Vertices on the boundary are kept as they are, the edge only needs to be
+        // split if it strictly crosses the boundary.
+        if (current_distance > zero && next_distance < zero)
+            || (current_distance < zero && next_distance > zero)
+        {
+            let t = current_distance / (current_distance - next_distance);
+            result.push(lerp(current, next, t));
+        }
+    }
+
+    result
+}
+
+/// Counts the sign changes in a cyclic sequence of values, ignoring zeros.
+struct SignFlips {
+    first: Option<bool>,
+    last: Option<bool>,
+    flips: u32,
+}
+
+impl SignFlips {
+    fn new() -> Self {
+        SignFlips { first: None, last: None, flips: 0 }
+    }
+
+    fn add<T: Zero + PartialOrd>(&mut self, value: T) {
+        if value == T::zero() {
+            return;
+        }
+
+        let positive = value > T::zero();
+        match self.last {
+            None => self.first = Some(positive),
+            Some(last) if last != positive => self.flips += 1,
+            Some(_) => {}
+        }
+        self.last = Some(positive);
+    }
+
+    fn count(&self) -> u32 {
+        // Close the cycle.
+        match (self.first, self.last) {
+            (Some(first), Some(last)) if first != last => self.flips + 1,
+            _ => self.flips,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FillRule, Orientation};
+    use crate::approxeq::ApproxEq;
+    use crate::default::{Box2D, Line2D, Point2D, Polygon2D, Transform2D, Transform3D};
+    use crate::{point2, vec2};
+
+    fn polygon<T: Copy>(points: &[(T, T)]) -> Polygon2D<T> {
+        points.iter().map(|&(x, y)| point2(x, y)).collect()
+    }
+
+    #[test]
+    fn test_area_and_orientation() {
+        let square = polygon(&[(0, 0), (4, 0), (4, 4), (0, 4)]);
+        assert_eq!(square.signed_area(), 16);
+        assert_eq!(square.orientation(), Some(Orientation::CounterClockwise));
+
+        let mut reversed = square.clone();
+        reversed.reverse();
+        assert_eq!(reversed.signed_area(), -16);
+        assert_eq!(reversed.orientation(), Some(Orientation::Clockwise));
+
+        let line = polygon(&[(0, 0), (1, 1), (2, 2)]);
+        assert_eq!(line.orientation(), None);
+        assert_eq!(Polygon2D::<i32>::default().signed_area(), 0);
+
+        assert_eq!(square.bounding_box(), Box2D::new(point2(0, 0), point2(4, 4)));
+    }
+
+    #[test]
+    fn test_centroid() {
+        let square = polygon(&[(1.0, 1.0), (3.0, 1.0), (3.0, 3.0), (1.0, 3.0)]);
+        assert!(square.centroid().unwrap().approx_eq(&point2(2.0, 2.0)));
+
+        // An L shape made of a 2x1 and a 1x1 boxes.
+        let l = polygon(&[(0.0, 0.0), (2.0, 0.0), (2.0, 1.0), (1.0, 1.0), (1.0, 2.0), (0.0, 2.0)]);
+        let expected = point2(
+            (1.0 * 2.0 + 0.5 * 1.0) / 3.0,
+            (0.5 * 2.0 + 1.5 * 1.0) / 3.0,
+        );
+        assert!(l.centroid().unwrap().approx_eq(&expected));
+
+        assert!(polygon(&[(0.0, 0.0), (1.0, 1.0)]).centroid().is_none());
+        assert!(Polygon2D::<f64>::default().centroid().is_none());
+    }
+
+    #[test]
+    fn test_is_convex() {
+        assert!(polygon(&[(0, 0), (4, 0), (4, 4), (0, 4)]).is_convex());
+        assert!(polygon(&[(0, 0), (0, 4), (4, 4), (4, 0)]).is_convex());
+        // Aligned and repeated vertices.
+        assert!(polygon(&[(0, 0), (2, 0), (4, 0), (4, 0), (4, 4), (0, 4)]).is_convex());
+
+        assert!(!polygon(&[(0, 0), (2, 1), (4, 0), (4, 4), (0, 4)]).is_convex());
+        assert!(!polygon(&[(0, 0), (1, 1)]).is_convex());
+        assert!(!polygon(&[(0, 0), (1, 1), (2, 2)]).is_convex());
+
+        // A pentagram only turns in one direction, but goes around twice.
+        let pentagram = polygon(&[(0.0, 10.0), (5.9, -8.1), (-9.5, 3.1), (9.5, 3.1), (-5.9, -8.1)]);
+        assert!(!pentagram.is_convex());
+    }
+
+    #[test]
+    fn test_contains() {
+        let square = polygon(&[(0, 0), (4, 0), (4, 4), (0, 4)]);
+        assert!(square.contains(point2(2, 2), FillRule::EvenOdd));
+        assert!(!square.contains(point2(5, 2), FillRule::NonZero));
+        assert!(!square.contains(point2(2, -1), FillRule::NonZero));
+
+        // The left and bottom edges are inside, the right and top ones outside.
+        assert!(square.contains(point2(0, 2), FillRule::NonZero));
+        assert!(square.contains(point2(2, 0), FillRule::NonZero));
+        assert!(square.contains(point2(0, 0), FillRule::NonZero));
+        assert!(!square.contains(point2(4, 2), FillRule::NonZero));
+        assert!(!square.contains(point2(2, 4), FillRule::NonZero));
+
+        // Two adjacent squares partition their union.
+        let right = polygon(&[(4, 0), (8, 0), (8, 4), (4, 4)]);
+        for x in -1..10 {
+            for y in -1..6 {
+                let p = point2(x, y);
+                let count = square.contains(p, FillRule::NonZero) as i32
+                    + right.contains(p, FillRule::NonZero) as i32;
+                assert_eq!(count, ((0..8).contains(&x) && (0..4).contains(&y)) as i32, "{:?}", p);
+            }
+        }
+
+        // The center of a pentagram is wound around twice.
+        let pentagram = polygon(&[(0.0, 10.0), (5.9, -8.1), (-9.5, 3.1), (9.5, 3.1), (-5.9, -8.1)]);
+        let center: Point2D<f64> = point2(0.0, 0.0);
+        assert_eq!(pentagram.winding_number(center).abs(), 2);
+        assert!(pentagram.contains(center, FillRule::NonZero));
+        assert!(!pentagram.contains(center, FillRule::EvenOdd));
+        assert!(pentagram.contains(point2(0.0, 8.0), FillRule::EvenOdd));
+
+        let mut clockwise = square.clone();
+        clockwise.reverse();
+        assert_eq!(clockwise.winding_number(point2(2, 2)), -1);
+    }
+
+    #[test]
+    fn test_clip_to_box() {
+        let b = Box2D::new(point2(0.0, 0.0), point2(4.0, 4.0));
+
+        // A diamond sticking out of all sides of the box gives an octagon.
+        let diamond = polygon(&[(2.0, -1.0), (5.0, 2.0), (2.0, 5.0), (-1.0, 2.0)]);
+        let clipped = diamond.clip_to_box(&b);
+        assert_eq!(clipped.len(), 8);
+        assert_eq!(clipped.signed_area(), 14.0);
+        for p in &clipped.points {
+            assert!(p.x == 0.0 || p.x == 4.0 || p.y == 0.0 || p.y == 4.0);
+        }
+
+        // Works the same with f32, and keeps the orientation.
+        let mut reversed = diamond.to_f32();
+        reversed.reverse();
+        let clipped = reversed.clip_to_box(&b.to_f32());
+        assert_eq!(clipped.len(), 8);
+        assert_eq!(clipped.signed_area(), -14.0);
+
+        // Inside and outside.
+        let inside = polygon(&[(1.0, 1.0), (2.0, 1.0), (2.0, 2.0)]);
+        assert_eq!(inside.clip_to_box(&b), inside);
+        let outside = polygon(&[(5.0, 5.0), (6.0, 5.0), (6.0, 6.0)]);
+        assert!(outside.clip_to_box(&b).is_empty());
+        assert!(inside.clip_to_box(&Box2D::zero()).is_empty());
+    }
+
+    #[test]
+    fn test_clip_to_half_plane_and_convex() {
+        let square = polygon(&[(0.0, 0.0), (2.0, 0.0), (2.0, 2.0), (0.0, 2.0)]);
+
+        // Keeps the left side of an upward line, that is x <= 1.
+        let line = Line2D { point: point2(1.0, 0.0), vector: vec2(0.0, 1.0) };
+        let clipped = square.clip_to_half_plane(&line);
+        assert_eq!(clipped, polygon(&[(0.0, 0.0), (1.0, 0.0), (1.0, 2.0), (0.0, 2.0)]));
+
+        // The clipping polygon can have both orientations.
+        let mut triangle = polygon(&[(1.0, -1.0), (3.0, 1.0), (1.0, 3.0)]);
+        let clipped = square.clip_to_convex(&triangle);
+        assert!(clipped.signed_area().approx_eq(&2.0));
+        triangle.reverse();
+        assert_eq!(square.clip_to_convex(&triangle), clipped);
+
+        assert!(square.clip_to_convex(&polygon(&[(0.0, 0.0), (1.0, 1.0)])).is_empty());
+    }
+
+    #[test]
+    fn test_transform() {
+        let square = polygon(&[(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)]);
+
+        let transform = Transform2D::scale(2.0, 3.0).then_translate(vec2(1.0, 1.0));
+        let transformed = square.transform(&transform);
+        assert_eq!(transformed, polygon(&[(1.0, 1.0), (3.0, 1.0), (3.0, 4.0), (1.0, 4.0)]));
+        assert_eq!(transformed.signed_area(), 6.0);
+        assert_eq!(square.translate(vec2(1.0, 1.0)).points[2], point2(2.0, 2.0));
+
+        let transform3d = Transform3D::scale(2.0, 3.0, 1.0).then_translate(crate::vec3(1.0, 1.0, 0.0));
+        assert_eq!(square.transform3d(&transform3d), Some(transformed));
+
+        let mut behind = Transform3D::identity();
+        behind.m44 = -1.0;
+        assert!(square.transform3d(&behind).is_none());
+    }
+}
@@ -0,0 +1,404 @@
+// Copyright 2013 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::UnknownUnit;
+use crate::box2d::Box2D;
+use crate::box3d::Box3D;
+use crate::line::Line2D;
+use crate::point::{Point2D, Point3D};
+use crate::segment::LineSegment2D;
+use crate::transform2d::Transform2D;
+use crate::vector::{Vector2D, Vector3D};
+
+use num_traits::real::Real;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "bytemuck")]
+use bytemuck::{Zeroable, Pod};
+
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::{Add, Mul};
+
+/// A 2d ray, starting at `origin` and going infinitely along `direction`.
+///
+/// The direction vector does not need to be normalized. Intersection methods
+/// express positions along the ray as a parameter `t`, the corresponding point
+/// being `origin + direction * t`.
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Serialize", deserialize = "T: Deserialize<'de>"))
+)]
+pub struct Ray2D<T, U> {
+    pub origin: Point2D<T, U>,
+    pub direction: Vector2D<T, U>,
+}
+
+impl<T: Hash, U> Hash for Ray2D<T, U> {
+    fn hash<H: Hasher>(&self, h: &mut H) {
+        self.origin.hash(h);
+        self.direction.hash(h);
+    }
+}
+
+impl<T: Copy, U> Copy for Ray2D<T, U> {}
+
+impl<T: Clone, U> Clone for Ray2D<T, U> {
+    fn clone(&self) -> Self {
+        Self::new(self.origin.clone(), self.direction.clone())
+    }
+}
+
+impl<T: PartialEq, U> PartialEq for Ray2D<T, U> {
+    fn eq(&self, other: &Self) -> bool {
+        self.origin.eq(&other.origin) && self.direction.eq(&other.direction)
+    }
+}
+
+impl<T: Eq, U> Eq for Ray2D<T, U> {}
+
+impl<T: fmt::Debug, U> fmt::Debug for Ray2D<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Ray2D")
+            .field(&self.origin)
+            .field(&self.direction)
+            .finish()
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, T, U> arbitrary::Arbitrary<'a> for Ray2D<T, U>
+where
+    T: arbitrary::Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self>
+    {
+        let (origin, direction) = arbitrary::Arbitrary::arbitrary(u)?;
+        Ok(Ray2D {
+            origin,
+            direction,
+        })
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: Zeroable, U> Zeroable for Ray2D<T, U> {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: Pod, U: 'static> Pod for Ray2D<T, U> {}
+
+impl<T, U> Ray2D<T, U> {
+    /// Constructor.
+    #[inline]
+    pub const fn new(origin: Point2D<T, U>, direction: Vector2D<T, U>) -> Self {
+        Ray2D { origin, direction }
+    }
+}
+
+impl<T: Copy, U> Ray2D<T, U> {
+    /// Returns the infinite line that contains this ray.
+    #[inline]
+    pub fn to_line(&self) -> Line2D<T, U> {
+        Line2D::new(self.origin, self.direction)
+    }
+
+    /// Drop the units, preserving only the numeric value.
+    #[inline]
+    pub fn to_untyped(&self) -> Ray2D<T, UnknownUnit> {
+        Ray2D::new(self.origin.to_untyped(), self.direction.to_untyped())
+    }
+
+    /// Tag a unitless value with units.
+    #[inline]
+    pub fn from_untyped(r: &Ray2D<T, UnknownUnit>) -> Self {
+        Ray2D::new(Point2D::from_untyped(r.origin), Vector2D::from_untyped(r.direction))
+    }
+
+    /// Cast the unit
+    #[inline]
+    pub fn cast_unit<V>(&self) -> Ray2D<T, V> {
+        Ray2D::new(self.origin.cast_unit(), self.direction.cast_unit())
+    }
+}
+
+impl<T, U> Ray2D<T, U>
+where
+    T: Copy + Add<Output = T> + Mul<Output = T>,
+{
+    /// Returns the point of the ray at parameter `t`, that is `origin + direction * t`.
+    #[inline]
+    pub fn sample(&self, t: T) -> Point2D<T, U> {
+        self.origin + self.direction * t
+    }
+
+    /// Applies the transform to the ray.
+    #[inline]
+    pub fn transform<Dst>(&self, transform: &Transform2D<T, U, Dst>) -> Ray2D<T, Dst> {
+        Ray2D::new(
+            transform.transform_point(self.origin),
+            transform.transform_vector(self.direction),
+        )
+    }
+}
+
+impl<T: Real, U> Ray2D<T, U> {
+    /// Computes the parameter range `(t_enter, t_exit)` over which the ray is inside
+    /// of the box, if the ray hits it.
+    ///
+    /// `t_enter` is zero if the origin of the ray is inside of the box.
+    /// Empty boxes are never hit.
+    pub fn intersect_box(&self, b: &Box2D<T, U>) -> Option<(T, T)> {
+        if b.is_empty() {
+            return None;
+        }
+
+        let mut t_min = T::zero();
+        let mut t_max = T::max_value();
+        clip_slab(self.origin.x, self.direction.x, b.min.x, b.max.x, &mut t_min, &mut t_max)?;
+        clip_slab(self.origin.y, self.direction.y, b.min.y, b.max.y, &mut t_min, &mut t_max)?;
+
+        Some((t_min, t_max))
+    }
+
+    /// Computes the intersection of the ray and a segment, returning the parameter
+    /// of the intersection point on the ray and on the segment respectively.
+    ///
+    /// Parallel and collinear segments are considered as not intersecting.
+    pub fn intersect_segment(&self, segment: &LineSegment2D<T, U>) -> Option<(T, T)> {
+        let (t, segment_t) = self.to_line().intersection_t(&segment.to_line())?;
+        if t < T::zero() || segment_t < T::zero() || segment_t > T::one() {
+            return None;
+        }
+
+        Some((t, segment_t))
+    }
+}
+
+/// A 3d ray, starting at `origin` and going infinitely along `direction`.
+///
+/// The direction vector does not need to be normalized. Intersection methods
+/// express positions along the ray as a parameter `t`, the corresponding point
+/// being `origin + direction * t`.
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Serialize", deserialize = "T: Deserialize<'de>"))
+)]
+pub struct Ray3D<T, U> {
+    pub origin: Point3D<T, U>,
+    pub direction: Vector3D<T, U>,
+}
+
+impl<T: Hash, U> Hash for Ray3D<T, U> {
+    fn hash<H: Hasher>(&self, h: &mut H) {
+        self.origin.hash(h);
+        self.direction.hash(h);
+    }
+}
+
+impl<T: Copy, U> Copy for Ray3D<T, U> {}
+
+impl<T: Clone, U> Clone for Ray3D<T, U> {
+    fn clone(&self) -> Self {
+        Self::new(self.origin.clone(), self.direction.clone())
+    }
+}
+
+impl<T: PartialEq, U> PartialEq for Ray3D<T, U> {
+    fn eq(&self, other: &Self) -> bool {
+        self.origin.eq(&other.origin) && self.direction.eq(&other.direction)
+    }
+}
+
+impl<T: Eq, U> Eq for Ray3D<T, U> {}
+
+impl<T: fmt::Debug, U> fmt::Debug for Ray3D<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Ray3D")
+            .field(&self.origin)
+            .field(&self.direction)
+            .finish()
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, T, U> arbitrary::Arbitrary<'a> for Ray3D<T, U>
+where
+    T: arbitrary::Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self>
+    {
+        let (x, y, z, dx, dy, dz) = arbitrary::Arbitrary::arbitrary(u)?;
+        Ok(Ray3D {
+            origin: Point3D::new(x, y, z),
+            direction: Vector3D::new(dx, dy, dz),
+        })
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: Zeroable, U> Zeroable for Ray3D<T, U> {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: Pod, U: 'static> Pod for Ray3D<T, U> {}
+
+impl<T, U> Ray3D<T, U> {
+    /// Constructor.
+    #[inline]
+    pub const fn new(origin: Point3D<T, U>, direction: Vector3D<T, U>) -> Self {
+        Ray3D { origin, direction }
+    }
+}
+
+impl<T: Copy, U> Ray3D<T, U> {
+    /// Drop the units, preserving only the numeric value.
+    #[inline]
+    pub fn to_untyped(&self) -> Ray3D<T, UnknownUnit> {
+        Ray3D::new(self.origin.to_untyped(), self.direction.to_untyped())
+    }
+
+    /// Tag a unitless value with units.
+    #[inline]
+    pub fn from_untyped(r: &Ray3D<T, UnknownUnit>) -> Self {
+        Ray3D::new(Point3D::from_untyped(r.origin), Vector3D::from_untyped(r.direction))
+    }
+
+    /// Cast the unit
+    #[inline]
+    pub fn cast_unit<V>(&self) -> Ray3D<T, V> {
+        Ray3D::new(self.origin.cast_unit(), self.direction.cast_unit())
+    }
+}
+
+impl<T, U> Ray3D<T, U>
+where
+    T: Copy + Add<Output = T> + Mul<Output = T>,
+{
+    /// Returns the point of the ray at parameter `t`, that is `origin + direction * t`.
+    #[inline]
+    pub fn sample(&self, t: T) -> Point3D<T, U> {
+        self.origin + self.direction * t
+    }
+}
+
+impl<T: Real, U> Ray3D<T, U> {
+    /// Computes the parameter range `(t_enter, t_exit)` over which the ray is inside
+    /// of the box, if the ray hits it.
+    ///
+    /// `t_enter` is zero if the origin of the ray is inside of the box.
+    /// Empty boxes are never hit.
+    pub fn intersect_box(&self, b: &Box3D<T, U>) -> Option<(T, T)> {
+        if b.is_empty() {
+            return None;
+        }
+
+        let mut t_min = T::zero();
+        let mut t_max = T::max_value();
+        clip_slab(self.origin.x, self.direction.x, b.min.x, b.max.x, &mut t_min, &mut t_max)?;
+        clip_slab(self.origin.y, self.direction.y, b.min.y, b.max.y, &mut t_min, &mut t_max)?;
+        clip_slab(self.origin.z, self.direction.z, b.min.z, b.max.z, &mut t_min, &mut t_max)?;
+
+        Some((t_min, t_max))
+    }
+}
+
+/// Narrows the `[t_min, t_max]` range to the part of the ray that is between
+/// `min` and `max` along one axis. Returns `None` if the range becomes empty.
+fn clip_slab<T: Real>(
+    origin: T,
+    direction: T,
+    min: T,
+    max: T,
+    t_min: &mut T,
+    t_max: &mut T,
+) -> Option<()> {
+    if direction == T::zero() {
+        // The ray is parallel to the slab.
+        if origin < min || origin > max {
+            return None;
+        }
+        return Some(());
+    }
+
+    let inv = T::one() / direction;
+    let mut t0 = (min - origin) * inv;
+    let mut t1 = (max - origin) * inv;
+    if t0 > t1 {
+        core::mem::swap(&mut t0, &mut t1);
+    }
+
+    *t_min = t_min.max(t0);
+    *t_max = t_max.min(t1);
+    if *t_min > *t_max {
+        return None;
+    }
+
+    Some(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::default::{Box2D, Box3D, LineSegment2D, Ray2D, Ray3D};
+    use crate::{point2, point3, vec2, vec3};
+
+    #[test]
+    fn test_intersect_box_2d() {
+        let b = Box2D::new(point2(0.0, 0.0), point2(10.0, 10.0));
+
+        let r = Ray2D::new(point2(-5.0, 5.0), vec2(1.0, 0.0));
+        assert_eq!(r.intersect_box(&b), Some((5.0, 15.0)));
+
+        let r = Ray2D::new(point2(5.0, 5.0), vec2(0.0, -2.0));
+        assert_eq!(r.intersect_box(&b), Some((0.0, 2.5)));
+
+        let r = Ray2D::new(point2(-5.0, 5.0), vec2(-1.0, 0.0));
+        assert_eq!(r.intersect_box(&b), None);
+
+        let r = Ray2D::new(point2(-5.0, 15.0), vec2(1.0, 0.0));
+        assert_eq!(r.intersect_box(&b), None);
+
+        let r = Ray2D::new(point2(-5.0, -5.0), vec2(1.0, 1.0));
+        assert_eq!(r.intersect_box(&b), Some((5.0, 15.0)));
+    }
+
+    #[test]
+    fn test_intersect_box_3d() {
+        let b = Box3D::new(point3(0.0, 0.0, 0.0), point3(10.0, 10.0, 10.0));
+
+        let r = Ray3D::new(point3(5.0, 5.0, -10.0), vec3(0.0, 0.0, 2.0));
+        assert_eq!(r.intersect_box(&b), Some((5.0, 10.0)));
+        assert_eq!(r.sample(5.0), point3(5.0, 5.0, 0.0));
+
+        let r = Ray3D::new(point3(5.0, 15.0, -10.0), vec3(0.0, 0.0, 1.0));
+        assert_eq!(r.intersect_box(&b), None);
+
+        let r = Ray3D::new(point3(-1.0, -1.0, -1.0), vec3(1.0, 1.0, 1.0));
+        assert_eq!(r.intersect_box(&b), Some((1.0, 11.0)));
+
+        let empty = Box3D::new(point3(0.0, 0.0, 0.0), point3(10.0, 0.0, 10.0));
+        assert_eq!(r.intersect_box(&empty), None);
+    }
+
+    #[test]
+    fn test_intersect_segment() {
+        let s = LineSegment2D::new(point2(2.0, -1.0), point2(2.0, 3.0));
+
+        let r = Ray2D::new(point2(0.0, 0.0), vec2(1.0, 0.0));
+        assert_eq!(r.intersect_segment(&s), Some((2.0, 0.25)));
+
+        let r = Ray2D::new(point2(0.0, 0.0), vec2(-1.0, 0.0));
+        assert_eq!(r.intersect_segment(&s), None);
+
+        let r = Ray2D::new(point2(0.0, 5.0), vec2(1.0, 0.0));
+        assert_eq!(r.intersect_segment(&s), None);
+    }
+}
@@ -246,6 +246,15 @@ where
 
         Some(box2d.to_rect())
     }
+
+    /// Returns the parts of this rectangle which are not in the other one, as at
+    /// most four non-overlapping rectangles.
+    ///
+    /// See [`Box2D::subtract`](struct.Box2D.html#method.subtract).
+    #[inline]
+    pub fn subtract(&self, other: &Self) -> impl Iterator<Item = Self> {
+        self.to_box2d().subtract(&other.to_box2d()).map(|b| b.to_rect())
+    }
 }
 
 impl<T, U> Rect<T, U>
@@ -928,4 +937,16 @@ mod tests {
 
         assert_eq!(r1.intersection(&r2), None);
     }
+
+    #[test]
+    fn test_subtract() {
+        let a: Rect<i32> = rect(0, 0, 10, 10);
+        let b: Rect<i32> = rect(5, -5, 10, 10);
+        assert_eq!(
+            a.subtract(&b).collect::<Vec<_>>(),
+            [rect(0, 5, 10, 5), rect(0, 0, 5, 5)]
+        );
+        assert_eq!(a.subtract(&rect(20, 20, 1, 1)).collect::<Vec<_>>(), [a]);
+        assert_eq!(a.subtract(&a).count(), 0);
+    }
 }
@@ -0,0 +1,664 @@
+// Copyright 2013 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::UnknownUnit;
+use crate::box2d::Box2D;
+use crate::multi_polygon::BooleanOp;
+use crate::num::*;
+use crate::point::{point2, Point2D};
+#[cfg(feature = "serde")]
+use crate::polygon::deserialize_vec;
+use crate::vector::Vector2D;
+
+use alloc::vec::Vec;
+use num_traits::NumCast;
+
+use core::cmp::{Ordering, PartialOrd};
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::iter::FromIterator;
+use core::ops::Add;
+use core::slice;
+
+/// A set of points made of non-overlapping boxes, like the damaged or opaque
+/// areas of a compositor.
+///
+/// The boxes are kept in a canonical form, like in X11 or pixman regions: they are
+/// grouped in horizontal bands sorted from top to bottom, in which the boxes all
+/// have the same vertical extent and are sorted from left to right without
+/// touching each other. Two bands which are next to each other never have the
+/// same horizontal extents. As a result two regions covering the same points have
+/// the same boxes, and the operations only need comparisons, so they are exact for
+/// integers.
+///
+/// Like for `Box2D::contains`, the boxes include their minimum sides but not their
+/// maximum ones.
+pub struct Region<T, U> {
+    boxes: Vec<Box2D<T, U>>,
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T, U> serde::Deserialize<'de> for Region<T, U>
+where
+    T: serde::Deserialize<'de> + Copy + PartialOrd,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        // The boxes are not trusted to be in canonical form.
+        let boxes: Vec<Box2D<T, U>> = deserialize_vec(deserializer)?;
+        Ok(boxes.into_iter().collect())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T, U> serde::Serialize for Region<T, U>
+where
+    T: serde::Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serde::Serialize::serialize(self.boxes.as_slice(), serializer)
+    }
+}
+
+impl<T: Hash, U> Hash for Region<T, U> {
+    fn hash<H: Hasher>(&self, h: &mut H) {
+        self.boxes.hash(h);
+    }
+}
+
+impl<T: Clone, U> Clone for Region<T, U> {
+    fn clone(&self) -> Self {
+        Region {
+            boxes: self.boxes.clone(),
+        }
+    }
+}
+
+impl<T: PartialEq, U> PartialEq for Region<T, U> {
+    fn eq(&self, other: &Self) -> bool {
+        self.boxes.eq(&other.boxes)
+    }
+}
+
+impl<T: Eq, U> Eq for Region<T, U> {}
+
+impl<T: fmt::Debug, U> fmt::Debug for Region<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Region").field(&self.boxes).finish()
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, T, U> arbitrary::Arbitrary<'a> for Region<T, U>
+where
+    T: arbitrary::Arbitrary<'a> + Copy + PartialOrd,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self>
+    {
+        let corners: Vec<(Point2D<T, U>, Point2D<T, U>)> = arbitrary::Arbitrary::arbitrary(u)?;
+        Ok(corners.into_iter().map(|(min, max)| Box2D::new(min, max)).collect())
+    }
+}
+
+impl<T, U> Default for Region<T, U> {
+    fn default() -> Self {
+        Region::new()
+    }
+}
+
+impl<T: Copy + PartialOrd, U> From<Box2D<T, U>> for Region<T, U> {
+    fn from(b: Box2D<T, U>) -> Self {
+        Region::from_box(b)
+    }
+}
+
+impl<T: Copy + PartialOrd, U> FromIterator<Box2D<T, U>> for Region<T, U> {
+    /// Returns the union of the boxes.
+    fn from_iter<I: IntoIterator<Item = Box2D<T, U>>>(iter: I) -> Self {
+        iter.into_iter()
+            .fold(Region::new(), |region, b| region.union(&Region::from_box(b)))
+    }
+}
+
+impl<'a, T, U> IntoIterator for &'a Region<T, U> {
+    type Item = &'a Box2D<T, U>;
+    type IntoIter = slice::Iter<'a, Box2D<T, U>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.boxes.iter()
+    }
+}
+
+impl<T, U> Region<T, U> {
+    /// Creates an empty region.
+    #[inline]
+    pub const fn new() -> Self {
+        Region { boxes: Vec::new() }
+    }
+
+    /// Returns `true` if the region contains no points.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.boxes.is_empty()
+    }
+
+    /// Returns the boxes of the region, in canonical order.
+    #[inline]
+    pub fn boxes(&self) -> &[Box2D<T, U>] {
+        &self.boxes
+    }
+
+    /// Returns an iterator over the boxes of the region, in canonical order.
+    #[inline]
+    pub fn iter(&self) -> slice::Iter<'_, Box2D<T, U>> {
+        self.boxes.iter()
+    }
+}
+
+impl<T: Copy + PartialOrd, U> Region<T, U> {
+    /// Creates a region covering a box, which is empty if the box is empty.
+    #[inline]
+    pub fn from_box(b: Box2D<T, U>) -> Self {
+        let mut region = Region::new();
+        if !b.is_empty() {
+            region.boxes.push(b);
+        }
+
+        region
+    }
+
+    /// Returns `true` if the point is in one of the boxes.
+    pub fn contains(&self, p: Point2D<T, U>) -> bool {
+        // The bottom of the bands increases along the boxes.
+        let start = self.boxes.partition_point(|b| b.max.y <= p.y);
+        self.boxes[start..]
+            .iter()
+            .take_while(|b| b.min.y <= p.y)
+            .any(|b| b.contains(p))
+    }
+
+    /// Returns `true` if all of the points of the box are in the region.
+    ///
+    /// Empty boxes are contained in any region.
+    pub fn contains_box(&self, b: &Box2D<T, U>) -> bool {
+        Region::from_box(*b).subtract(self).is_empty()
+    }
+
+    /// Returns `true` if the regions have points in common.
+    pub fn intersects(&self, other: &Self) -> bool {
+        !self.intersection(other).is_empty()
+    }
+
+    /// Returns the points which are in either region.
+    pub fn union(&self, other: &Self) -> Self {
+        self.combine(other, BooleanOp::Union)
+    }
+
+    /// Returns the points which are in both regions.
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.combine(other, BooleanOp::Intersection)
+    }
+
+    /// Returns the points of this region which are not in the other one.
+    pub fn subtract(&self, other: &Self) -> Self {
+        self.combine(other, BooleanOp::Difference)
+    }
+
+    /// Returns the points which are in exactly one of the regions.
+    pub fn xor(&self, other: &Self) -> Self {
+        self.combine(other, BooleanOp::Xor)
+    }
+
+    /// Splits the plane into horizontal slices along the bands of both regions, and
+    /// applies the operation to the horizontal extents of the boxes of each slice.
+    fn combine(&self, other: &Self, op: BooleanOp) -> Self {
+        let a = bands(&self.boxes);
+        let b = bands(&other.boxes);
+
+        let mut result = Region::new();
+        let mut last_band = 0;
+        let mut spans = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        let mut y = None;
+        while i < a.len() || j < b.len() {
+            // The top of the slice is where the next band starts, unless some bands
+            // are already going on.
+            let top = |band: &[Box2D<T, U>]| match y {
+                Some(y) => max(band[0].min.y, y),
+                None => band[0].min.y,
+            };
+            let start = match (a.get(i), b.get(j)) {
+                (Some(a), Some(b)) => min(top(a), top(b)),
+                (Some(a), None) => top(a),
+                (None, Some(b)) => top(b),
+                (None, None) => unreachable!(),
+            };
+
+            // The slice ends at the next top or bottom of a band.
+            let mut end = None;
+            let in_a = boxes_in_slice(a.get(i).copied(), start, &mut end);
+            let in_b = boxes_in_slice(b.get(j).copied(), start, &mut end);
+            let end = end.unwrap();
+
+            spans.clear();
+            combine_spans(in_a, in_b, op, &mut spans);
+            if !spans.is_empty() {
+                let previous = &result.boxes[last_band..];
+                let coalesce = !previous.is_empty()
+                    && previous[0].max.y == start
+                    && previous.len() == spans.len()
+                    && previous
+                        .iter()
+                        .zip(&spans)
+                        .all(|(b, s)| b.min.x == s.0 && b.max.x == s.1);
+                if coalesce {
+                    for b in &mut result.boxes[last_band..] {
+                        b.max.y = end;
+                    }
+                } else {
+                    last_band = result.boxes.len();
+                    result.boxes.extend(
+                        spans
+                            .iter()
+                            .map(|&(x0, x1)| Box2D::new(point2(x0, start), point2(x1, end))),
+                    );
+                }
+            }
+
+            y = Some(end);
+            if i < a.len() && a[i][0].max.y <= end {
+                i += 1;
+            }
+            if j < b.len() && b[j][0].max.y <= end {
+                j += 1;
+            }
+        }
+
+        result
+    }
+}
+
+impl<T, U> Region<T, U>
+where
+    T: Copy + PartialOrd + NumCast,
+{
+    /// Returns a region with at most `max_boxes` boxes (and at least one) which
+    /// contains this one.
+    ///
+    /// The gaps adding the least area are filled first: the ones between the boxes
+    /// of a band, then the ones between bands. This is typically used to limit the
+    /// number of separate areas to repaint. The areas are computed with `f64`, so
+    /// that they can't overflow integer coordinates.
+    pub fn simplified(&self, max_boxes: usize) -> Self {
+        let max_boxes = max_boxes.max(1);
+        if self.boxes.len() <= max_boxes {
+            return self.clone();
+        }
+
+        let compare = |a: &(usize, f64), b: &(usize, f64)| {
+            a.1.partial_cmp(&b.1).unwrap_or(Ordering::Equal)
+        };
+
+        let mut boxes = self.boxes.clone();
+        while boxes.len() > max_boxes {
+            let gap = (0..boxes.len() - 1)
+                .filter(|&k| boxes[k].min.y == boxes[k + 1].min.y)
+                .map(|k| {
+                    let (a, b) = (boxes[k].to_f64(), boxes[k + 1].to_f64());
+                    (k, (b.min.x - a.max.x) * a.height())
+                })
+                .min_by(compare);
+            match gap {
+                Some((k, _)) => {
+                    boxes[k].max.x = boxes[k + 1].max.x;
+                    boxes.remove(k + 1);
+                }
+                None => break,
+            }
+        }
+
+        // All of the bands now have a single box.
+        while boxes.len() > max_boxes {
+            let (k, _) = (0..boxes.len() - 1)
+                .map(|k| {
+                    let (a, b) = (boxes[k].to_f64(), boxes[k + 1].to_f64());
+                    let added = a.union(&b).area() - a.area() - b.area();
+                    (k, added)
+                })
+                .min_by(compare)
+                .unwrap();
+            boxes[k] = boxes[k].union(&boxes[k + 1]);
+            boxes.remove(k + 1);
+        }
+
+        boxes.into_iter().collect()
+    }
+}
+
+impl<T, U> Region<T, U>
+where
+    T: Copy + Zero + PartialOrd,
+{
+    /// Returns the smallest box containing the region, or a zero box if the region
+    /// is empty.
+    pub fn bounds(&self) -> Box2D<T, U> {
+        let (first, last) = match (self.boxes.first(), self.boxes.last()) {
+            (Some(first), Some(last)) => (first, last),
+            _ => return Box2D::zero(),
+        };
+
+        let mut min_x = first.min.x;
+        let mut max_x = first.max.x;
+        for b in &self.boxes {
+            min_x = min(min_x, b.min.x);
+            max_x = max(max_x, b.max.x);
+        }
+
+        Box2D::new(point2(min_x, first.min.y), point2(max_x, last.max.y))
+    }
+}
+
+impl<T, U> Region<T, U>
+where
+    T: Copy + Add<T, Output = T>,
+{
+    /// Translate the region by a vector.
+    #[inline]
+    #[must_use]
+    pub fn translate(&self, by: Vector2D<T, U>) -> Self {
+        Region {
+            boxes: self.boxes.iter().map(|b| b.translate(by)).collect(),
+        }
+    }
+}
+
+impl<T: Copy, U> Region<T, U> {
+    /// Drop the units, preserving only the numeric value.
+    #[inline]
+    pub fn to_untyped(&self) -> Region<T, UnknownUnit> {
+        self.cast_unit()
+    }
+
+    /// Tag a unitless value with units.
+    #[inline]
+    pub fn from_untyped(r: &Region<T, UnknownUnit>) -> Self {
+        r.cast_unit()
+    }
+
+    /// Cast the unit
+    #[inline]
+    pub fn cast_unit<V>(&self) -> Region<T, V> {
+        Region {
+            boxes: self.boxes.iter().map(|b| b.cast_unit()).collect(),
+        }
+    }
+}
+
+impl<T: NumCast + Copy, U> Region<T, U> {
+    /// Cast from one numeric representation to another, preserving the units.
+    ///
+    /// The boxes are merged again if the cast makes some of them touch.
+    #[inline]
+    pub fn cast<NewT: NumCast + Copy + PartialOrd>(&self) -> Region<NewT, U> {
+        self.boxes.iter().map(|b| b.cast()).collect()
+    }
+
+    /// Fallible cast from one numeric representation to another, preserving the units.
+    pub fn try_cast<NewT: NumCast + Copy + PartialOrd>(&self) -> Option<Region<NewT, U>> {
+        let boxes: Option<Vec<_>> = self.boxes.iter().map(|b| b.try_cast()).collect();
+        boxes.map(|boxes| boxes.into_iter().collect())
+    }
+
+    // Convenience functions for common casts
+
+    /// Cast into an `f32` region.
+    #[inline]
+    pub fn to_f32(&self) -> Region<f32, U> {
+        self.cast()
+    }
+
+    /// Cast into an `f64` region.
+    #[inline]
+    pub fn to_f64(&self) -> Region<f64, U> {
+        self.cast()
+    }
+
+    /// Cast into an `i32` region, truncating decimals if any.
+    #[inline]
+    pub fn to_i32(&self) -> Region<i32, U> {
+        self.cast()
+    }
+}
+
+fn min<T: PartialOrd>(a: T, b: T) -> T {
+    if b < a {
+        b
+    } else {
+        a
+    }
+}
+
+fn max<T: PartialOrd>(a: T, b: T) -> T {
+    if b > a {
+        b
+    } else {
+        a
+    }
+}
+
+/// Splits canonical boxes into their bands.
+fn bands<T: PartialOrd, U>(boxes: &[Box2D<T, U>]) -> Vec<&[Box2D<T, U>]> {
+    let mut bands = Vec::new();
+    let mut start = 0;
+    for k in 1..=boxes.len() {
+        if k == boxes.len() || boxes[k].min.y != boxes[start].min.y {
+            bands.push(&boxes[start..k]);
+            start = k;
+        }
+    }
+
+    bands
+}
+
+/// Returns the boxes of a band in a horizontal slice starting at `start`, and moves
+/// `end` up to where they stop being the same.
+fn boxes_in_slice<'a, T: Copy + PartialOrd, U>(
+    band: Option<&'a [Box2D<T, U>]>,
+    start: T,
+    end: &mut Option<T>,
+) -> &'a [Box2D<T, U>] {
+    let (boxes, y) = match band {
+        Some(band) if band[0].min.y <= start => (band, band[0].max.y),
+        Some(band) => (&[][..], band[0].min.y),
+        None => return &[],
+    };
+    *end = Some(match *end {
+        Some(e) => min(e, y),
+        None => y,
+    });
+
+    boxes
+}
+
+/// Applies the operation to the horizontal extents of two sorted lists of
+/// non-overlapping boxes, and appends the resulting extents.
+fn combine_spans<T: Copy + PartialOrd, U>(
+    a: &[Box2D<T, U>],
+    b: &[Box2D<T, U>],
+    op: BooleanOp,
+    spans: &mut Vec<(T, T)>,
+) {
+    let mut xs: Vec<T> = a.iter().chain(b).flat_map(|b| [b.min.x, b.max.x]).collect();
+    xs.sort_by(|x, y| x.partial_cmp(y).unwrap_or(Ordering::Equal));
+    xs.dedup();
+
+    let (mut i, mut j) = (0, 0);
+    for pair in xs.windows(2) {
+        let (x0, x1) = (pair[0], pair[1]);
+        while i < a.len() && a[i].max.x <= x0 {
+            i += 1;
+        }
+        while j < b.len() && b[j].max.x <= x0 {
+            j += 1;
+        }
+
+        let in_a = i < a.len() && a[i].min.x <= x0;
+        let in_b = j < b.len() && b[j].min.x <= x0;
+        if op.apply(in_a, in_b) {
+            match spans.last_mut() {
+                Some(last) if last.1 == x0 => last.1 = x1,
+                _ => spans.push((x0, x1)),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::default::{Box2D, Region};
+    use crate::{point2, vec2};
+
+    fn b(x0: i32, y0: i32, x1: i32, y1: i32) -> Box2D<i32> {
+        Box2D::new(point2(x0, y0), point2(x1, y1))
+    }
+
+    fn region(boxes: &[Box2D<i32>]) -> Region<i32> {
+        boxes.iter().copied().collect()
+    }
+
+    // Checks the operation on every point of a grid.
+    fn check_points(
+        a: &Region<i32>,
+        b: &Region<i32>,
+        result: &Region<i32>,
+        op: impl Fn(bool, bool) -> bool,
+    ) {
+        for x in -2..14 {
+            for y in -2..14 {
+                let p = point2(x, y);
+                assert_eq!(result.contains(p), op(a.contains(p), b.contains(p)), "at {:?}", p);
+            }
+        }
+    }
+
+    #[test]
+    fn test_canonical_form() {
+        // Two overlapping boxes become three bands.
+        let r = region(&[b(0, 0, 4, 4), b(2, 2, 6, 6)]);
+        assert_eq!(r.boxes(), &[b(0, 0, 4, 2), b(0, 2, 6, 4), b(2, 4, 6, 6)]);
+
+        // Touching boxes are merged, in both directions.
+        assert_eq!(region(&[b(0, 0, 2, 2), b(2, 0, 4, 2)]).boxes(), &[b(0, 0, 4, 2)]);
+        assert_eq!(region(&[b(0, 0, 2, 2), b(0, 2, 2, 4)]).boxes(), &[b(0, 0, 2, 4)]);
+
+        // The order of the boxes doesn't matter, and empty boxes are ignored.
+        let boxes = [b(0, 0, 2, 2), b(5, 1, 7, 3), b(1, 1, 6, 2), b(3, 3, 3, 8)];
+        let mut reversed = boxes;
+        reversed.reverse();
+        assert_eq!(region(&boxes), region(&reversed));
+        assert_eq!(region(&boxes).boxes(), &[b(0, 0, 2, 1), b(0, 1, 7, 2), b(5, 2, 7, 3)]);
+
+        assert!(region(&[b(0, 0, 0, 5)]).is_empty());
+        assert_eq!(region(&[]), Region::new());
+    }
+
+    #[test]
+    fn test_operations() {
+        let a = region(&[b(0, 0, 6, 4), b(2, 6, 10, 10)]);
+        let c = region(&[b(4, 2, 8, 8), b(0, 9, 1, 12)]);
+
+        check_points(&a, &c, &a.union(&c), |a, b| a || b);
+        check_points(&a, &c, &a.intersection(&c), |a, b| a && b);
+        check_points(&a, &c, &a.subtract(&c), |a, b| a && !b);
+        check_points(&a, &c, &a.xor(&c), |a, b| a != b);
+
+        assert_eq!(a.union(&c), c.union(&a));
+        assert_eq!(a.intersection(&c), region(&[b(4, 2, 6, 4), b(4, 6, 8, 8)]));
+        assert_eq!(a.xor(&c), a.union(&c).subtract(&a.intersection(&c)));
+        assert_eq!(a.subtract(&a), Region::new());
+        assert_eq!(a.union(&a), a);
+        assert!(a.intersects(&c));
+        assert!(!a.intersects(&region(&[b(6, 0, 8, 2)])));
+
+        // Results stay canonical.
+        assert_eq!(a.subtract(&c).union(&a.intersection(&c)), a);
+        assert_eq!(
+            region(&[b(0, 0, 4, 4)]).subtract(&region(&[b(1, 1, 3, 3)])).boxes(),
+            &[b(0, 0, 4, 1), b(0, 1, 1, 3), b(3, 1, 4, 3), b(0, 3, 4, 4)]
+        );
+    }
+
+    #[test]
+    fn test_contains_and_bounds() {
+        let r = region(&[b(0, 0, 2, 2), b(4, 1, 6, 5)]);
+        assert!(r.contains(point2(0, 0)));
+        assert!(r.contains(point2(5, 4)));
+        assert!(!r.contains(point2(2, 1)));
+        assert!(!r.contains(point2(6, 4)));
+        assert!(!r.contains(point2(5, 5)));
+        assert!(r.contains_box(&b(4, 2, 6, 5)));
+        assert!(!r.contains_box(&b(1, 1, 5, 2)));
+        assert!(r.contains_box(&b(10, 10, 10, 20)));
+
+        assert_eq!(r.bounds(), b(0, 0, 6, 5));
+        assert_eq!(Region::<i32>::new().bounds(), Box2D::zero());
+        assert_eq!(r.iter().count(), 4);
+        assert_eq!((&r).into_iter().next(), Some(&b(0, 0, 2, 1)));
+
+        let moved = r.translate(vec2(1, -1));
+        assert_eq!(moved, region(&[b(1, -1, 3, 1), b(5, 0, 7, 4)]));
+    }
+
+    #[test]
+    fn test_simplified() {
+        let r = region(&[b(0, 0, 2, 2), b(3, 0, 5, 2), b(10, 0, 12, 2), b(0, 4, 5, 6)]);
+        assert_eq!(r.boxes().len(), 4);
+        assert_eq!(r.simplified(4), r);
+
+        // The smallest gap is filled first.
+        let s = r.simplified(3);
+        assert_eq!(s, region(&[b(0, 0, 5, 2), b(10, 0, 12, 2), b(0, 4, 5, 6)]));
+
+        let s = r.simplified(2);
+        assert_eq!(s, region(&[b(0, 0, 12, 2), b(0, 4, 5, 6)]));
+
+        let s = r.simplified(0);
+        assert_eq!(s.boxes(), &[b(0, 0, 12, 6)]);
+
+        for n in 0..5 {
+            let s = r.simplified(n);
+            assert!(s.boxes().len() <= n.max(1));
+            assert_eq!(s.intersection(&r), r);
+        }
+
+        // The areas of the gaps don't fit in an i32.
+        let r = region(&[
+            b(0, 0, 10_000, 100_000),
+            b(40_000, 0, 50_000, 100_000),
+            b(0, 200_000, 50_000, 300_000),
+        ]);
+        assert_eq!(r.simplified(2).boxes().len(), 2);
+        assert_eq!(r.simplified(1).boxes(), &[b(0, 0, 50_000, 300_000)]);
+    }
+
+    #[test]
+    fn test_float() {
+        let r: Region<f32> = region(&[b(0, 0, 4, 4), b(2, 2, 6, 6)]).to_f32();
+        let half = Region::from(Box2D::new(point2(0.5, 0.5), point2(1.5, 1.5)));
+        assert!(r.contains_box(&half.bounds()));
+        assert_eq!(r.union(&half), r);
+        assert_eq!(r.cast::<i32>(), region(&[b(0, 0, 4, 4), b(2, 2, 6, 6)]));
+        assert_eq!(r.subtract(&half).boxes().len(), 6);
+    }
+}
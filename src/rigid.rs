@@ -4,7 +4,7 @@
 
 use crate::approxeq::ApproxEq;
 use crate::trig::Trig;
-use crate::{Rotation3D, Transform3D, UnknownUnit, Vector3D};
+use crate::{Ray3D, Rotation3D, Transform3D, UnknownUnit, Vector3D};
 use num_traits::real::Real;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -159,6 +159,15 @@ impl<T: Real + ApproxEq<T>, Src, Dst> RigidTransform3D<T, Src, Dst> {
         RigidTransform3D::new_from_reversed(-self.translation, self.rotation.inverse())
     }
 
+    /// Returns the given ray transformed by this transform.
+    #[inline]
+    pub fn transform_ray(&self, ray: &Ray3D<T, Src>) -> Ray3D<T, Dst> {
+        Ray3D::new(
+            self.rotation.transform_point3d(ray.origin) + self.translation,
+            self.rotation.transform_vector3d(ray.direction),
+        )
+    }
+
     pub fn to_transform(&self) -> Transform3D<T, Src, Dst>
     where
         T: Trig,
@@ -219,7 +228,9 @@ impl<T: Real + ApproxEq<T>, Src, Dst> From<Vector3D<T, Dst>> for RigidTransform3
 #[cfg(test)]
 mod test {
     use super::RigidTransform3D;
-    use crate::default::{Rotation3D, Transform3D, Vector3D};
+    use crate::default::{Ray3D, Rotation3D, Transform3D, Vector3D};
+    use crate::{point3, vec3, Angle};
+    use crate::approxeq::ApproxEq;
 
     #[test]
     fn test_rigid_construction() {
@@ -283,4 +294,20 @@ mod test {
             .to_transform()
             .approx_eq(&rigid2.to_transform().then(&rigid.to_transform())));
     }
+
+    #[test]
+    fn test_rigid_transform_ray() {
+        let rigid = RigidTransform3D::new(
+            Rotation3D::around_z(Angle::frac_pi_2()),
+            Vector3D::new(1.0, 2.0, 3.0),
+        );
+        let ray = Ray3D::new(point3(1.0, 0.0, 0.0), vec3(0.0, 2.0, 0.0));
+        let transformed = rigid.transform_ray(&ray);
+        assert!(transformed.origin.approx_eq(&point3(1.0, 3.0, 3.0)));
+        assert!(transformed.direction.approx_eq(&vec3(-2.0, 0.0, 0.0)));
+
+        let expected = rigid.to_transform().transform_ray(&ray).unwrap();
+        assert!(transformed.origin.approx_eq(&expected.origin));
+        assert!(transformed.direction.approx_eq(&expected.direction));
+    }
 }
@@ -17,7 +17,7 @@ use core::hash::Hash;
 use core::marker::PhantomData;
 use core::ops::{Add, Mul, Neg, Sub};
 use num_traits::real::Real;
-use num_traits::{NumCast, One, Zero};
+use num_traits::{FloatConst, NumCast, One, Zero};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 #[cfg(feature = "bytemuck")]
@@ -684,6 +684,46 @@ where
         )
     }
 
+    /// Creates a rotation from the rows of an orthonormal rotation matrix, using
+    /// Shepperd's method.
+    pub(crate) fn from_orthonormal_rows(
+        x: Vector3D<T, UnknownUnit>,
+        y: Vector3D<T, UnknownUnit>,
+        z: Vector3D<T, UnknownUnit>,
+    ) -> Self {
+        let (m11, m12, m13) = (x.x, x.y, x.z);
+        let (m21, m22, m23) = (y.x, y.y, y.z);
+        let (m31, m32, m33) = (z.x, z.y, z.z);
+
+        let one = T::one();
+        let two = one + one;
+        let half = one / two;
+        let quarter = half / two;
+
+        // Pick the largest of the four quaternion components to avoid dividing by a
+        // small value.
+        let trace = m11 + m22 + m33;
+        let q = if trace >= m11 && trace >= m22 && trace >= m33 {
+            let r = half * (one + trace).sqrt();
+            let s = quarter / r;
+            Self::quaternion((m23 - m32) * s, (m31 - m13) * s, (m12 - m21) * s, r)
+        } else if m11 >= m22 && m11 >= m33 {
+            let i = half * (one + m11 - m22 - m33).sqrt();
+            let s = quarter / i;
+            Self::quaternion(i, (m12 + m21) * s, (m31 + m13) * s, (m23 - m32) * s)
+        } else if m22 >= m33 {
+            let j = half * (one - m11 + m22 - m33).sqrt();
+            let s = quarter / j;
+            Self::quaternion((m12 + m21) * s, j, (m23 + m32) * s, (m31 - m13) * s)
+        } else {
+            let k = half * (one - m11 - m22 + m33).sqrt();
+            let s = quarter / k;
+            Self::quaternion((m31 + m13) * s, (m23 + m32) * s, k, (m12 - m21) * s)
+        };
+
+        q.normalize()
+    }
+
     // add, sub and mul are used internally for intermediate computation but aren't public
     // because they don't carry real semantic meanings (I think?).
 
@@ -718,6 +758,234 @@ where
     }
 }
 
+/// The order of the elementary rotations that make up a set of Euler angles.
+///
+/// Rotations are applied around the fixed (extrinsic) axes of the source space, in the
+/// order of the name: `XYZ` rotates around the x axis first, then around the y axis and
+/// finally around the z axis. This is the same as rotating around the axes of the
+/// rotated (intrinsic) space in the reverse order.
+///
+/// The first six orders are Tait-Bryan angles (three distinct axes), the last six are
+/// proper Euler angles (the first and last axes are the same).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum EulerOrder {
+    XYZ,
+    XZY,
+    YXZ,
+    YZX,
+    ZXY,
+    ZYX,
+    XYX,
+    XZX,
+    YXY,
+    YZY,
+    ZXZ,
+    ZYZ,
+}
+
+impl EulerOrder {
+    /// Returns the indices of the three rotation axes.
+    fn axes(self) -> (usize, usize, usize) {
+        match self {
+            EulerOrder::XYZ => (0, 1, 2),
+            EulerOrder::XZY => (0, 2, 1),
+            EulerOrder::YXZ => (1, 0, 2),
+            EulerOrder::YZX => (1, 2, 0),
+            EulerOrder::ZXY => (2, 0, 1),
+            EulerOrder::ZYX => (2, 1, 0),
+            EulerOrder::XYX => (0, 1, 0),
+            EulerOrder::XZX => (0, 2, 0),
+            EulerOrder::YXY => (1, 0, 1),
+            EulerOrder::YZY => (1, 2, 1),
+            EulerOrder::ZXZ => (2, 0, 2),
+            EulerOrder::ZYZ => (2, 1, 2),
+        }
+    }
+}
+
+impl<T, Src, Dst> Rotation3D<T, Src, Dst>
+where
+    T: Real + ApproxEq<T>,
+{
+    /// Creates a rotation from Euler angles applied in the given order.
+    ///
+    /// `first`, `second` and `third` are the angles of the rotations around the first,
+    /// second and third axis of `order` respectively.
+    /// `Rotation3D::euler(roll, pitch, yaw)` is equivalent to
+    /// `Rotation3D::from_euler(EulerOrder::XYZ, roll, pitch, yaw)`.
+    pub fn from_euler(order: EulerOrder, first: Angle<T>, second: Angle<T>, third: Angle<T>) -> Self {
+        let (a, b, c) = order.axes();
+        Self::around_basis_axis(a, first)
+            .then(&Rotation3D::<T, Dst, Dst>::around_basis_axis(b, second))
+            .then(&Rotation3D::around_basis_axis(c, third))
+    }
+
+    /// Returns the Euler angles of this rotation in the given order, such that
+    /// `Rotation3D::from_euler(order, first, second, third)` is equivalent to this
+    /// rotation.
+    ///
+    /// The first and third angles are in the `]-PI, PI]` range. The second angle is in
+    /// the `[-PI/2, PI/2]` range for Tait-Bryan orders and in the `[0, PI]` range for
+    /// proper Euler orders.
+    ///
+    /// In the case of a gimbal lock (when the first and third axes are aligned) only
+    /// the sum or the difference of the first and third angles is defined. The third
+    /// angle is then set to zero and the first angle accounts for the whole rotation.
+    pub fn to_euler(&self, order: EulerOrder) -> (Angle<T>, Angle<T>, Angle<T>)
+    where
+        T: FloatConst,
+    {
+        // See "Quaternion to Euler angles conversion: A direct, general and
+        // computationally efficient method" by Bernardes and Viollet.
+        let zero = T::zero();
+        let one = T::one();
+        let two = one + one;
+        let pi = Angle::pi().get();
+
+        let q = self.normalize();
+        let q = [q.i, q.j, q.k, q.r];
+
+        let (i, j, k) = order.axes();
+        let symmetric = i == k;
+        let k = if symmetric { 3 - i - j } else { k };
+        // Whether the axes are an even or an odd permutation of xyz.
+        let sign = if (i + 1) % 3 == j { one } else { -one };
+
+        let (a, b, c, d) = if symmetric {
+            (q[3], q[i], q[j], q[k] * sign)
+        } else {
+            (q[3] - q[j], q[i] + q[k] * sign, q[j] + q[3], q[k] * sign - q[i])
+        };
+
+        let second = two * Real::atan2(c.hypot(d), a.hypot(b));
+        let half_sum = b.atan2(a);
+        let half_diff = d.atan2(c);
+
+        let eps = T::approx_epsilon();
+        let (first, third) = if second.abs() <= eps {
+            (two * half_sum, zero)
+        } else if (second - pi).abs() <= eps {
+            (-two * half_diff, zero)
+        } else {
+            (half_sum - half_diff, half_sum + half_diff)
+        };
+
+        let (second, third) = if symmetric {
+            (second, third)
+        } else {
+            (second - pi / two, third * sign)
+        };
+
+        (
+            Angle::radians(first).signed(),
+            Angle::radians(second).signed(),
+            Angle::radians(third).signed(),
+        )
+    }
+
+    /// Returns the axis and the angle of this rotation.
+    ///
+    /// The axis is normalized and the angle is in the `[0, PI]` range. The identity
+    /// rotation has no axis, in which case the x axis is returned with an angle of zero.
+    pub fn to_axis_angle(&self) -> (Vector3D<T, Src>, Angle<T>) {
+        let q = self.normalize();
+        // q and -q represent the same rotation, pick the one with the smaller angle.
+        let q = if q.r < T::zero() { q.mul(-T::one()) } else { q };
+
+        let v = Vector3D::new(q.i, q.j, q.k);
+        let sin = v.length();
+        if sin == T::zero() {
+            return (Vector3D::new(T::one(), T::zero(), T::zero()), Angle::zero());
+        }
+
+        let two = T::one() + T::one();
+        (v / sin, Angle::radians(two * sin.atan2(q.r)))
+    }
+
+    fn around_basis_axis(axis: usize, angle: Angle<T>) -> Self {
+        match axis {
+            0 => Self::around_x(angle),
+            1 => Self::around_y(angle),
+            _ => Self::around_z(angle),
+        }
+    }
+}
+
+impl<T, Src, Dst> Rotation3D<T, Src, Dst>
+where
+    T: Real + ApproxEq<T>,
+{
+    /// Creates the shortest rotation that maps the direction of `from` to the direction
+    /// of `to`.
+    ///
+    /// If the vectors point in opposite directions, the rotation is a half turn around
+    /// an arbitrary axis orthogonal to `from`. The vectors must not be zero.
+    pub fn rotation_between(from: Vector3D<T, Src>, to: Vector3D<T, Dst>) -> Self {
+        let zero = T::zero();
+        let one = T::one();
+
+        let from = from.to_untyped().normalize();
+        let to = to.to_untyped().normalize();
+        let dot = from.dot(to);
+
+        if dot + one <= T::approx_epsilon() {
+            // Cross with the basis axis that is the furthest from `from` to get a
+            // well conditioned orthogonal axis.
+            let (x, y, z) = (from.x.abs(), from.y.abs(), from.z.abs());
+            let basis = if x <= y && x <= z {
+                vec3(one, zero, zero)
+            } else if y <= z {
+                vec3(zero, one, zero)
+            } else {
+                vec3(zero, zero, one)
+            };
+            let axis = from.cross(basis).normalize();
+            return Self::quaternion(axis.x, axis.y, axis.z, zero);
+        }
+
+        let axis = from.cross(to);
+        Self::unit_quaternion(axis.x, axis.y, axis.z, one + dot)
+    }
+
+    /// Creates a rotation that maps the z axis to `forward` and the y axis to the
+    /// direction orthogonal to `forward` that is the closest to `up`.
+    ///
+    /// `forward` must not be zero. If `up` is zero or parallel to `forward`, the result
+    /// is the same as `Rotation3D::rotation_between(vec3(0, 0, 1), forward)`.
+    pub fn look_rotation(forward: Vector3D<T, Dst>, up: Vector3D<T, Dst>) -> Self {
+        let z = forward.to_untyped().normalize();
+        let up = up.to_untyped();
+        let x = up.cross(z);
+        let x_length = x.length();
+        if x_length <= T::approx_epsilon() * up.length() || x_length == T::zero() {
+            let zero = T::zero();
+            return Self::rotation_between(vec3(zero, zero, T::one()), forward);
+        }
+
+        let x = x / x_length;
+        let y = z.cross(x);
+        Self::from_orthonormal_rows(x, y, z)
+    }
+
+    /// Extracts the rotation of a transform, using Shepperd's method.
+    ///
+    /// The upper 3x3 part of the transform is expected to be a rotation, possibly
+    /// combined with a positive scale which is removed by normalizing the rows of the
+    /// matrix. Translation and perspective components are ignored. Use
+    /// [`Transform3D::decompose`] for transforms that may contain skews or reflections.
+    ///
+    /// [`Transform3D::decompose`]: struct.Transform3D.html#method.decompose
+    pub fn from_transform(transform: &Transform3D<T, Src, Dst>) -> Self {
+        let m = transform;
+        Self::from_orthonormal_rows(
+            vec3(m.m11, m.m12, m.m13).normalize(),
+            vec3(m.m21, m.m22, m.m23).normalize(),
+            vec3(m.m31, m.m32, m.m33).normalize(),
+        )
+    }
+}
+
 impl<T: fmt::Debug, Src, Dst> fmt::Debug for Rotation3D<T, Src, Dst> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
@@ -1012,3 +1280,206 @@ fn from_euler() {
 
     assert!(ypr_pe.approx_eq(&ypr_pq));
 }
+
+#[cfg(test)]
+const EULER_ORDERS: [EulerOrder; 12] = [
+    EulerOrder::XYZ,
+    EulerOrder::XZY,
+    EulerOrder::YXZ,
+    EulerOrder::YZX,
+    EulerOrder::ZXY,
+    EulerOrder::ZYX,
+    EulerOrder::XYX,
+    EulerOrder::XZX,
+    EulerOrder::YXY,
+    EulerOrder::YZY,
+    EulerOrder::ZXZ,
+    EulerOrder::ZYZ,
+];
+
+#[test]
+fn from_euler_order() {
+    use crate::default::Rotation3D;
+
+    let (a, b, c) = (Angle::radians(0.3), Angle::radians(-1.2), Angle::radians(2.0));
+    assert!(Rotation3D::from_euler(EulerOrder::XYZ, a, b, c).approx_eq(&Rotation3D::euler(a, b, c)));
+
+    let zyx = Rotation3D::around_z(a)
+        .then(&Rotation3D::around_y(b))
+        .then(&Rotation3D::around_x(c));
+    assert!(Rotation3D::from_euler(EulerOrder::ZYX, a, b, c).approx_eq(&zyx));
+
+    let zxz = Rotation3D::around_z(a)
+        .then(&Rotation3D::around_x(b))
+        .then(&Rotation3D::around_z(c));
+    assert!(Rotation3D::from_euler(EulerOrder::ZXZ, a, b, c).approx_eq(&zxz));
+}
+
+#[test]
+fn to_euler() {
+    use crate::default::Rotation3D;
+
+    let tait_bryan = [(0.3, -1.2, 2.0), (-2.5, 0.1, -0.4), (3.0, 1.5, -3.0), (0.0, 0.0, 1.0)];
+    let proper = [(0.3, 1.2, 2.0), (-2.5, 0.1, -0.4), (3.0, 3.0, -3.0), (1.0, 2.0, 0.0)];
+
+    for &order in &EULER_ORDERS {
+        let symmetric = order.axes().0 == order.axes().2;
+        let angles = if symmetric { &proper } else { &tait_bryan };
+        for &(a, b, c) in angles.iter() {
+            let r = Rotation3D::from_euler(order, Angle::radians(a), Angle::radians(b), Angle::radians(c));
+            let (ra, rb, rc) = r.to_euler(order);
+            assert!(
+                ra.get().approx_eq_eps(&a, &1e-9)
+                    && rb.get().approx_eq_eps(&b, &1e-9)
+                    && rc.get().approx_eq_eps(&c, &1e-9),
+                "{:?} {:?} -> {:?}",
+                order,
+                (a, b, c),
+                (ra, rb, rc)
+            );
+        }
+    }
+}
+
+#[test]
+fn to_euler_round_trip() {
+    use crate::default::Rotation3D;
+
+    let rotations = [
+        Rotation3D::identity(),
+        Rotation3D::around_x(Angle::radians(1.0)),
+        Rotation3D::around_y(Angle::pi()),
+        Rotation3D::around_axis(vec3(1.0, 2.0, 3.0), Angle::radians(2.5)),
+        Rotation3D::around_axis(vec3(-1.0, 0.5, 0.2), Angle::radians(-0.7)),
+        Rotation3D::unit_quaternion(0.5, 0.5, 0.5, 0.5),
+    ];
+
+    for &order in &EULER_ORDERS {
+        for r in &rotations {
+            let (a, b, c) = r.to_euler(order);
+            let r2 = Rotation3D::from_euler(order, a, b, c);
+            assert!(r2.approx_eq(r), "{:?} {:?} -> {:?}", order, r, (a, b, c));
+        }
+    }
+}
+
+#[test]
+fn to_euler_gimbal_lock() {
+    use crate::default::Rotation3D;
+    use core::f64::consts::{FRAC_PI_2, PI};
+
+    // Tait-Bryan angles lock when the second angle is +/- PI/2, proper Euler angles
+    // when it is 0 or PI. The third angle is then always zero.
+    for &order in &EULER_ORDERS {
+        let symmetric = order.axes().0 == order.axes().2;
+        let locked = if symmetric { [0.0, PI] } else { [FRAC_PI_2, -FRAC_PI_2] };
+        for &b in &locked {
+            let r = Rotation3D::from_euler(order, Angle::radians(0.4), Angle::radians(b), Angle::radians(0.5));
+            let (ra, rb, rc) = r.to_euler(order);
+            assert_eq!(rc.get(), 0.0);
+            assert!(rb.get().abs().approx_eq(&b.abs()));
+            assert!(Rotation3D::from_euler(order, ra, rb, rc).approx_eq(&r), "{:?} {}", order, b);
+        }
+    }
+}
+
+#[test]
+fn to_axis_angle() {
+    use crate::default::Rotation3D;
+    use core::f64::consts::PI;
+
+    let axis = vec3(1.0, 2.0, 2.0) / 3.0;
+    let (a, angle) = Rotation3D::around_axis(axis, Angle::radians(1.2)).to_axis_angle();
+    assert!(a.approx_eq(&axis));
+    assert!(angle.get().approx_eq(&1.2));
+
+    // The angle is always positive, the axis is flipped instead.
+    let (a, angle) = Rotation3D::around_axis(axis, Angle::radians(-1.2)).to_axis_angle();
+    assert!(a.approx_eq(&-axis));
+    assert!(angle.get().approx_eq(&1.2));
+
+    let (a, angle) = Rotation3D::around_z(Angle::radians(3.0 * PI / 2.0)).to_axis_angle();
+    assert!(a.approx_eq(&vec3(0.0, 0.0, -1.0)));
+    assert!(angle.get().approx_eq(&(PI / 2.0)));
+
+    let (a, angle) = Rotation3D::<f64>::identity().to_axis_angle();
+    assert_eq!(a, vec3(1.0, 0.0, 0.0));
+    assert_eq!(angle.get(), 0.0);
+}
+
+#[test]
+fn rotation_between() {
+    use crate::default::Rotation3D;
+
+    let pairs = [
+        (vec3(1.0, 0.0, 0.0), vec3(0.0, 1.0, 0.0)),
+        (vec3(1.0, 2.0, 3.0), vec3(-3.0, 0.5, 2.0)),
+        (vec3(0.0, 0.0, 2.0), vec3(0.0, 0.0, 5.0)),
+        // Antiparallel vectors.
+        (vec3(1.0, 0.0, 0.0), vec3(-1.0, 0.0, 0.0)),
+        (vec3(0.0, 3.0, 0.0), vec3(0.0, -1.0, 0.0)),
+        (vec3(1.0, 2.0, 3.0), vec3(-1.0, -2.0, -3.0)),
+    ];
+
+    for &(from, to) in &pairs {
+        let r: Rotation3D<f64> = Rotation3D::rotation_between(from, to);
+        assert!(r.is_normalized());
+        let mapped = r.transform_vector3d(from.normalize());
+        assert!(mapped.approx_eq(&to.normalize()), "{:?} -> {:?}: {:?}", from, to, mapped);
+    }
+
+    let r: Rotation3D<f64> = Rotation3D::rotation_between(vec3(1.0, 0.0, 0.0), vec3(0.0, 1.0, 0.0));
+    assert!(r.approx_eq(&Rotation3D::around_z(Angle::frac_pi_2())));
+}
+
+#[test]
+fn look_rotation() {
+    use crate::default::Rotation3D;
+
+    let r: Rotation3D<f64> = Rotation3D::look_rotation(vec3(0.0, 0.0, 1.0), vec3(0.0, 1.0, 0.0));
+    assert!(r.approx_eq(&Rotation3D::identity()));
+
+    let forward = vec3(1.0, 1.0, 0.0);
+    let r: Rotation3D<f64> = Rotation3D::look_rotation(forward, vec3(0.0, 0.0, 1.0));
+    assert!(r.transform_vector3d(vec3(0.0, 0.0, 1.0)).approx_eq(&forward.normalize()));
+    assert!(r.transform_vector3d(vec3(0.0, 1.0, 0.0)).approx_eq(&vec3(0.0, 0.0, 1.0)));
+
+    // The up vector doesn't need to be orthogonal to the forward vector.
+    let r: Rotation3D<f64> = Rotation3D::look_rotation(vec3(0.0, 0.0, -1.0), vec3(0.0, 1.0, 1.0));
+    assert!(r.transform_vector3d(vec3(0.0, 0.0, 1.0)).approx_eq(&vec3(0.0, 0.0, -1.0)));
+    assert!(r.transform_vector3d(vec3(0.0, 1.0, 0.0)).approx_eq(&vec3(0.0, 1.0, 0.0)));
+    assert!(r.transform_vector3d(vec3(1.0, 0.0, 0.0)).approx_eq(&vec3(-1.0, 0.0, 0.0)));
+
+    // Degenerate up vector.
+    let r: Rotation3D<f64> = Rotation3D::look_rotation(vec3(0.0, 2.0, 0.0), vec3(0.0, 1.0, 0.0));
+    assert!(r.transform_vector3d(vec3(0.0, 0.0, 1.0)).approx_eq(&vec3(0.0, 1.0, 0.0)));
+}
+
+#[test]
+fn from_transform() {
+    use crate::default::{Rotation3D, Transform3D};
+
+    let rotations = [
+        Rotation3D::identity(),
+        Rotation3D::around_x(Angle::radians(1.0)),
+        Rotation3D::around_axis(vec3(1.0, 2.0, 3.0), Angle::radians(2.5)),
+        // Half turns, for which the real part is zero.
+        Rotation3D::around_x(Angle::pi()),
+        Rotation3D::around_y(Angle::pi()),
+        Rotation3D::around_z(Angle::pi()),
+        Rotation3D::around_axis(vec3(1.0, -1.0, 0.0), Angle::pi()),
+        Rotation3D::around_axis(vec3(-0.3, 0.2, 1.0), Angle::radians(-3.1)),
+    ];
+
+    for r in &rotations {
+        let r2 = Rotation3D::from_transform(&r.to_transform());
+        assert!(r2.approx_eq(r), "{:?} -> {:?}", r, r2);
+
+        // Positive scale and translation are ignored.
+        let m: Transform3D<f64> = r
+            .to_transform()
+            .pre_scale(2.0, 3.0, 0.5)
+            .then_translate(vec3(1.0, 2.0, 3.0));
+        assert!(Rotation3D::from_transform(&m).approx_eq(r));
+    }
+}
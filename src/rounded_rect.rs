@@ -0,0 +1,674 @@
+// Copyright 2013 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::UnknownUnit;
+use crate::angle::Angle;
+use crate::approxeq::ApproxEq;
+use crate::box2d::Box2D;
+use crate::ellipse::Arc;
+use crate::num::*;
+use crate::point::{point2, Point2D};
+use crate::side_offsets::SideOffsets2D;
+use crate::size::{size2, Size2D};
+use crate::vector::{vec2, Vector2D};
+
+use num_traits::real::Real;
+use num_traits::{FloatConst, NumCast};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "bytemuck")]
+use bytemuck::{Zeroable, Pod};
+
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::iter;
+use core::ops::Add;
+
+/// A rectangle with rounded corners, like the borders of CSS boxes.
+///
+/// Each corner is a quarter of an ellipse, with its horizontal radius in the
+/// `width` of the corner's size and its vertical radius in its `height`. The top
+/// is the side with the smallest y coordinate, like for `SideOffsets2D`.
+///
+/// The radii can be larger than what fits in the rectangle, in which case they are
+/// scaled down like in CSS (see `normalized`) before any computation. Corners
+/// with a zero or negative radius are not rounded.
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Serialize", deserialize = "T: Deserialize<'de>"))
+)]
+pub struct RoundedRect<T, U> {
+    pub rect: Box2D<T, U>,
+    pub top_left: Size2D<T, U>,
+    pub top_right: Size2D<T, U>,
+    pub bottom_right: Size2D<T, U>,
+    pub bottom_left: Size2D<T, U>,
+}
+
+impl<T: Hash, U> Hash for RoundedRect<T, U> {
+    fn hash<H: Hasher>(&self, h: &mut H) {
+        self.rect.hash(h);
+        self.top_left.hash(h);
+        self.top_right.hash(h);
+        self.bottom_right.hash(h);
+        self.bottom_left.hash(h);
+    }
+}
+
+impl<T: Copy, U> Copy for RoundedRect<T, U> {}
+
+impl<T: Clone, U> Clone for RoundedRect<T, U> {
+    fn clone(&self) -> Self {
+        Self::new(
+            self.rect.clone(),
+            self.top_left.clone(),
+            self.top_right.clone(),
+            self.bottom_right.clone(),
+            self.bottom_left.clone(),
+        )
+    }
+}
+
+impl<T: PartialEq, U> PartialEq for RoundedRect<T, U> {
+    fn eq(&self, other: &Self) -> bool {
+        self.rect.eq(&other.rect)
+            && self.top_left.eq(&other.top_left)
+            && self.top_right.eq(&other.top_right)
+            && self.bottom_right.eq(&other.bottom_right)
+            && self.bottom_left.eq(&other.bottom_left)
+    }
+}
+
+impl<T: Eq, U> Eq for RoundedRect<T, U> {}
+
+impl<T: fmt::Debug, U> fmt::Debug for RoundedRect<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("RoundedRect")
+            .field(&self.rect)
+            .field(&self.top_left)
+            .field(&self.top_right)
+            .field(&self.bottom_right)
+            .field(&self.bottom_left)
+            .finish()
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, T, U> arbitrary::Arbitrary<'a> for RoundedRect<T, U>
+where
+    T: arbitrary::Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self>
+    {
+        let (min, max, top_left, top_right, bottom_right, bottom_left) =
+            arbitrary::Arbitrary::arbitrary(u)?;
+        Ok(RoundedRect {
+            rect: Box2D::new(min, max),
+            top_left,
+            top_right,
+            bottom_right,
+            bottom_left,
+        })
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: Zeroable, U> Zeroable for RoundedRect<T, U> {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: Pod, U: 'static> Pod for RoundedRect<T, U> {}
+
+impl<T, U> RoundedRect<T, U> {
+    /// Constructor, taking the radii of the corners in clockwise order on screen.
+    #[inline]
+    pub const fn new(
+        rect: Box2D<T, U>,
+        top_left: Size2D<T, U>,
+        top_right: Size2D<T, U>,
+        bottom_right: Size2D<T, U>,
+        bottom_left: Size2D<T, U>,
+    ) -> Self {
+        RoundedRect {
+            rect,
+            top_left,
+            top_right,
+            bottom_right,
+            bottom_left,
+        }
+    }
+}
+
+impl<T: Copy, U> RoundedRect<T, U> {
+    /// Constructor with the same radii for all of the corners.
+    #[inline]
+    pub fn uniform(rect: Box2D<T, U>, radii: Size2D<T, U>) -> Self {
+        RoundedRect::new(rect, radii, radii, radii, radii)
+    }
+
+    /// Returns the radii of the corners, starting from the top left one in
+    /// clockwise order on screen.
+    #[inline]
+    pub fn radii(&self) -> [Size2D<T, U>; 4] {
+        [self.top_left, self.top_right, self.bottom_right, self.bottom_left]
+    }
+
+    /// Drop the units, preserving only the numeric value.
+    #[inline]
+    pub fn to_untyped(&self) -> RoundedRect<T, UnknownUnit> {
+        self.cast_unit()
+    }
+
+    /// Tag a unitless value with units.
+    #[inline]
+    pub fn from_untyped(r: &RoundedRect<T, UnknownUnit>) -> Self {
+        r.cast_unit()
+    }
+
+    /// Cast the unit
+    #[inline]
+    pub fn cast_unit<V>(&self) -> RoundedRect<T, V> {
+        RoundedRect::new(
+            self.rect.cast_unit(),
+            self.top_left.cast_unit(),
+            self.top_right.cast_unit(),
+            self.bottom_right.cast_unit(),
+            self.bottom_left.cast_unit(),
+        )
+    }
+}
+
+impl<T: Copy + Zero, U> RoundedRect<T, U> {
+    /// Constructor for a rectangle without rounded corners.
+    #[inline]
+    pub fn from_box(rect: Box2D<T, U>) -> Self {
+        RoundedRect::uniform(rect, Size2D::zero())
+    }
+}
+
+impl<T, U> RoundedRect<T, U>
+where
+    T: Copy + Add<T, Output = T>,
+{
+    /// Translate the rounded rectangle by a vector.
+    #[inline]
+    #[must_use]
+    pub fn translate(&self, by: Vector2D<T, U>) -> Self {
+        RoundedRect {
+            rect: self.rect.translate(by),
+            ..*self
+        }
+    }
+}
+
+/// The directions in which the corners are from the centers of their ellipses,
+/// in the order of `RoundedRect::radii`.
+const CORNER_SIGNS: [(bool, bool); 4] = [
+    (false, false),
+    (true, false),
+    (true, true),
+    (false, true),
+];
+
+impl<T: Real, U> RoundedRect<T, U> {
+    /// Returns `true` if the rectangle has no area.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.rect.is_empty()
+    }
+
+    /// Returns the same rounded rectangle with radii that fit in the rectangle,
+    /// following the CSS rules for overlapping curves.
+    ///
+    /// If the radii of two corners on the same side add up to more than the
+    /// length of the side, all of the radii are scaled down by the same factor
+    /// until they fit, which keeps the shapes of the corners. Corners with a zero
+    /// or negative radius on either axis become square, with zero radii.
+    pub fn normalized(&self) -> Self {
+        let zero = T::zero();
+        if self.rect.is_empty() {
+            return RoundedRect::from_box(self.rect);
+        }
+
+        let [top_left, top_right, bottom_right, bottom_left] = self.radii().map(|r| {
+            if r.width > zero && r.height > zero {
+                r
+            } else {
+                Size2D::zero()
+            }
+        });
+
+        let size = self.rect.size();
+        let mut factor = T::one();
+        let mut fit = |length: T, radii: T| {
+            if radii > length {
+                factor = factor.min(length / radii);
+            }
+        };
+        fit(size.width, top_left.width + top_right.width);
+        fit(size.width, bottom_left.width + bottom_right.width);
+        fit(size.height, top_left.height + bottom_left.height);
+        fit(size.height, top_right.height + bottom_right.height);
+
+        RoundedRect::new(
+            self.rect,
+            top_left * factor,
+            top_right * factor,
+            bottom_right * factor,
+            bottom_left * factor,
+        )
+    }
+
+    /// Returns the centers of the ellipses of the corners, in the order of `radii`,
+    /// assuming normalized radii.
+    fn corner_centers(&self) -> [Point2D<T, U>; 4] {
+        let (min, max) = (self.rect.min, self.rect.max);
+        [
+            point2(min.x + self.top_left.width, min.y + self.top_left.height),
+            point2(max.x - self.top_right.width, min.y + self.top_right.height),
+            point2(max.x - self.bottom_right.width, max.y - self.bottom_right.height),
+            point2(min.x + self.bottom_left.width, max.y - self.bottom_left.height),
+        ]
+    }
+
+    /// Returns `true` if the point is inside of the rounded rectangle.
+    ///
+    /// Like for `Box2D::contains`, the points on the top and left sides are inside
+    /// while the points on the bottom and right sides are not. The points on the
+    /// curves of the corners are inside.
+    pub fn contains(&self, p: Point2D<T, U>) -> bool {
+        if !self.rect.contains(p) {
+            return false;
+        }
+
+        let r = self.normalized();
+        let zero = T::zero();
+        let centers = r.corner_centers();
+        let radii = r.radii();
+        for k in 0..4 {
+            let d = p - centers[k];
+            let (right, bottom) = CORNER_SIGNS[k];
+            let beyond_x = if right { d.x > zero } else { d.x < zero };
+            let beyond_y = if bottom { d.y > zero } else { d.y < zero };
+            if beyond_x && beyond_y && !inside_ellipse(d, radii[k]) {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// Returns the rounded rectangle inside of borders of the given widths.
+    ///
+    /// Like for the padding edge of CSS boxes, the radii of each corner are reduced
+    /// by the widths of the borders it joins, down to zero. For example the
+    /// horizontal radius of the top left corner is reduced by the width of the left
+    /// border.
+    pub fn inner_rounded_rect(&self, widths: SideOffsets2D<T, U>) -> Self {
+        let r = self.normalized();
+        let zero = T::zero();
+        let shrink = |radii: Size2D<T, U>, x: T, y: T| {
+            size2((radii.width - x).max(zero), (radii.height - y).max(zero))
+        };
+
+        RoundedRect::new(
+            r.rect.inner_box(widths),
+            shrink(r.top_left, widths.left, widths.top),
+            shrink(r.top_right, widths.right, widths.top),
+            shrink(r.bottom_right, widths.right, widths.bottom),
+            shrink(r.bottom_left, widths.left, widths.bottom),
+        )
+    }
+
+    /// Returns the rounded rectangle outside of borders of the given widths.
+    ///
+    /// The radii of the rounded corners grow by the widths of the borders they
+    /// join, while square corners stay square. This is the inverse of
+    /// `inner_rounded_rect` for corners which stay rounded.
+    pub fn outer_rounded_rect(&self, widths: SideOffsets2D<T, U>) -> Self {
+        let r = self.normalized();
+        let zero = T::zero();
+        let grow = |radii: Size2D<T, U>, x: T, y: T| {
+            if radii.width > zero {
+                size2(radii.width + x, radii.height + y)
+            } else {
+                radii
+            }
+        };
+
+        RoundedRect::new(
+            r.rect.outer_box(widths),
+            grow(r.top_left, widths.left, widths.top),
+            grow(r.top_right, widths.right, widths.top),
+            grow(r.bottom_right, widths.right, widths.bottom),
+            grow(r.bottom_left, widths.left, widths.bottom),
+        )
+    }
+
+    /// Computes the intersection with a box.
+    ///
+    /// Returns `None` if the intersection is empty, or if it is not a rounded
+    /// rectangle, which happens when a side of the box cuts through the curve of a
+    /// corner. The corners which are inside of the box keep their radii, and the
+    /// other corners of the intersection are square.
+    pub fn intersection(&self, b: &Box2D<T, U>) -> Option<Self> {
+        let r = self.normalized();
+        let rect = r.rect.intersection(b)?;
+
+        let zero = T::zero();
+        let mut radii = r.radii();
+        let centers = r.corner_centers();
+        for k in 0..4 {
+            if radii[k].width == zero {
+                continue;
+            }
+
+            let (right, bottom) = CORNER_SIGNS[k];
+            let corner = point2(
+                if right { r.rect.max.x } else { r.rect.min.x },
+                if bottom { r.rect.max.y } else { r.rect.min.y },
+            );
+            let corner_box = Box2D::from_points(&[corner, centers[k]]);
+            if b.contains_box(&corner_box) {
+                continue;
+            }
+
+            // The box can still remove a part of the corner without reaching its
+            // curve.
+            let clipped = corner_box.intersection_unchecked(b);
+            if !clipped.is_empty() {
+                let nearest = point2(
+                    if right { clipped.max.x } else { clipped.min.x },
+                    if bottom { clipped.max.y } else { clipped.min.y },
+                );
+                if !inside_ellipse(nearest - centers[k], radii[k]) {
+                    return None;
+                }
+            }
+
+            radii[k] = Size2D::zero();
+        }
+
+        let [top_left, top_right, bottom_right, bottom_left] = radii;
+        Some(RoundedRect::new(rect, top_left, top_right, bottom_right, bottom_left))
+    }
+}
+
+impl<T: Real + FloatConst, U> RoundedRect<T, U> {
+    /// Returns the curves of the corners, starting from the top left one in the
+    /// direction of increasing angles (clockwise on screen).
+    ///
+    /// The outline of the rounded rectangle goes through each arc, then along a
+    /// straight line from its end to the start of the next one. The arcs of
+    /// square corners have zero radii, and start and end on the corner.
+    pub fn to_arcs(&self) -> [Arc<T, U>; 4] {
+        let r = self.normalized();
+        let centers = r.corner_centers();
+        let radii = r.radii();
+        let quarter = Angle::frac_pi_2();
+        let zero = Angle::radians(T::zero());
+        let arc = |k: usize, start: Angle<T>| {
+            let radii = vec2(radii[k].width, radii[k].height);
+            Arc::new(centers[k], radii, start, quarter, zero)
+        };
+
+        [arc(0, Angle::pi()), arc(1, -quarter), arc(2, zero), arc(3, quarter)]
+    }
+
+    /// Returns the points of an approximation of the outline with straight lines,
+    /// which is at most `tolerance` away from it.
+    ///
+    /// The points start at the beginning of the curve of the top left corner, and
+    /// go in the same direction as `to_arcs`.
+    pub fn flattened(&self, tolerance: T) -> impl Iterator<Item = Point2D<T, U>> {
+        let zero = T::zero();
+        IntoIterator::into_iter(self.to_arcs()).flat_map(move |arc| {
+            let rounded = arc.radii.x > zero;
+            iter::once(arc.from())
+                .filter(move |_| rounded)
+                .chain(arc.flattened(tolerance))
+        })
+    }
+}
+
+impl<T: NumCast + Copy, U> RoundedRect<T, U> {
+    /// Cast from one numeric representation to another, preserving the units.
+    #[inline]
+    pub fn cast<NewT: NumCast>(&self) -> RoundedRect<NewT, U> {
+        RoundedRect::new(
+            self.rect.cast(),
+            self.top_left.cast(),
+            self.top_right.cast(),
+            self.bottom_right.cast(),
+            self.bottom_left.cast(),
+        )
+    }
+
+    /// Fallible cast from one numeric representation to another, preserving the units.
+    pub fn try_cast<NewT: NumCast>(&self) -> Option<RoundedRect<NewT, U>> {
+        match (
+            self.rect.try_cast(),
+            self.top_left.try_cast(),
+            self.top_right.try_cast(),
+            self.bottom_right.try_cast(),
+            self.bottom_left.try_cast(),
+        ) {
+            (Some(rect), Some(tl), Some(tr), Some(br), Some(bl)) => {
+                Some(RoundedRect::new(rect, tl, tr, br, bl))
+            }
+            _ => None,
+        }
+    }
+
+    // Convenience functions for common casts
+
+    /// Cast into an `f32` rounded rectangle.
+    #[inline]
+    pub fn to_f32(&self) -> RoundedRect<f32, U> {
+        self.cast()
+    }
+
+    /// Cast into an `f64` rounded rectangle.
+    #[inline]
+    pub fn to_f64(&self) -> RoundedRect<f64, U> {
+        self.cast()
+    }
+}
+
+impl<T: ApproxEq<T>, U> ApproxEq<T> for RoundedRect<T, U> {
+    #[inline]
+    fn approx_epsilon() -> T {
+        T::approx_epsilon()
+    }
+
+    #[inline]
+    fn approx_eq_eps(&self, other: &Self, eps: &T) -> bool {
+        let size_eq = |a: &Size2D<T, U>, b: &Size2D<T, U>| {
+            a.width.approx_eq_eps(&b.width, eps) && a.height.approx_eq_eps(&b.height, eps)
+        };
+
+        self.rect.min.x.approx_eq_eps(&other.rect.min.x, eps)
+            && self.rect.min.y.approx_eq_eps(&other.rect.min.y, eps)
+            && self.rect.max.x.approx_eq_eps(&other.rect.max.x, eps)
+            && self.rect.max.y.approx_eq_eps(&other.rect.max.y, eps)
+            && size_eq(&self.top_left, &other.top_left)
+            && size_eq(&self.top_right, &other.top_right)
+            && size_eq(&self.bottom_right, &other.bottom_right)
+            && size_eq(&self.bottom_left, &other.bottom_left)
+    }
+}
+
+/// Returns `true` if the offset from the center of an ellipse with positive radii
+/// is inside of it or on its boundary.
+fn inside_ellipse<T: Real, U>(d: Vector2D<T, U>, radii: Size2D<T, U>) -> bool {
+    let x = d.x / radii.width;
+    let y = d.y / radii.height;
+    x * x + y * y <= T::one()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::approxeq::ApproxEq;
+    use crate::default::{Box2D, RoundedRect, SideOffsets2D};
+    use crate::{point2, size2, vec2};
+
+    fn rounded(radius: f64) -> RoundedRect<f64> {
+        let rect = Box2D::new(point2(0.0, 0.0), point2(100.0, 50.0));
+        RoundedRect::uniform(rect, size2(radius, radius))
+    }
+
+    #[test]
+    fn test_normalized() {
+        // Radii that fit are kept.
+        assert_eq!(rounded(10.0).normalized(), rounded(10.0));
+
+        // The largest overflow decides the scale, which is uniform.
+        let rect = Box2D::new(point2(0.0, 0.0), point2(100.0, 50.0));
+        let r = RoundedRect::new(
+            rect,
+            size2(60.0, 20.0),
+            size2(60.0, 20.0),
+            size2(10.0, 50.0),
+            size2(10.0, 10.0),
+        );
+        let n = r.normalized();
+        let factor = 50.0 / 70.0;
+        assert!(n.top_left.width.approx_eq(&(60.0 * factor)));
+        assert!(n.top_left.height.approx_eq(&(20.0 * factor)));
+        assert!(n.bottom_right.height.approx_eq(&(50.0 * factor)));
+        assert!(n.top_left.width + n.top_right.width <= 100.0);
+        assert!(n.top_right.height + n.bottom_right.height <= 50.0 + 1e-9);
+
+        // Corners flat on one axis are square.
+        let r = RoundedRect::new(
+            rect,
+            size2(10.0, 0.0),
+            size2(-5.0, 5.0),
+            size2(5.0, 5.0),
+            size2(5.0, 5.0),
+        );
+        let n = r.normalized();
+        assert_eq!(n.top_left, size2(0.0, 0.0));
+        assert_eq!(n.top_right, size2(0.0, 0.0));
+        assert_eq!(n.bottom_right, size2(5.0, 5.0));
+
+        // A circle.
+        let square = Box2D::new(point2(0.0, 0.0), point2(10.0, 10.0));
+        let circle = RoundedRect::uniform(square, size2(20.0, 20.0));
+        assert_eq!(circle.normalized().top_left, size2(5.0, 5.0));
+    }
+
+    #[test]
+    fn test_contains() {
+        let r = rounded(10.0);
+        assert!(r.contains(point2(50.0, 25.0)));
+        assert!(r.contains(point2(50.0, 0.0)));
+        assert!(r.contains(point2(0.0, 25.0)));
+        assert!(!r.contains(point2(100.0, 25.0)));
+
+        // The corners of the box are outside, the centers of the curves inside.
+        assert!(!r.contains(point2(1.0, 1.0)));
+        assert!(!r.contains(point2(99.0, 1.0)));
+        assert!(!r.contains(point2(99.0, 49.0)));
+        assert!(!r.contains(point2(1.0, 49.0)));
+        assert!(r.contains(point2(10.0, 10.0)));
+        assert!(r.contains(point2(3.0, 3.0)));
+        assert!(!r.contains(point2(2.9, 2.9)));
+
+        // Oversized radii are normalized.
+        let pill = rounded(100.0);
+        assert!(pill.contains(point2(25.0, 25.0)));
+        assert!(!pill.contains(point2(5.0, 5.0)));
+        assert!(pill.contains(point2(50.0, 1.0)));
+
+        let b = Box2D::new(point2(0.0, 0.0), point2(1.0, 1.0));
+        assert!(RoundedRect::from_box(b).contains(point2(0.0, 0.0)));
+    }
+
+    #[test]
+    fn test_inner_and_outer() {
+        let r = rounded(10.0);
+        let borders = SideOffsets2D::new(2.0, 4.0, 12.0, 6.0);
+        let inner = r.inner_rounded_rect(borders);
+        assert_eq!(inner.rect, Box2D::new(point2(6.0, 2.0), point2(96.0, 38.0)));
+        assert_eq!(inner.top_left, size2(4.0, 8.0));
+        assert_eq!(inner.top_right, size2(6.0, 8.0));
+        assert_eq!(inner.bottom_right, size2(6.0, 0.0));
+        assert_eq!(inner.bottom_left, size2(4.0, 0.0));
+        assert!(!inner.contains(point2(6.5, 2.5)));
+        assert!(inner.contains(point2(6.5, 37.5)));
+
+        let outer = inner.outer_rounded_rect(borders);
+        assert_eq!(outer.rect, r.rect);
+        assert_eq!(outer.top_left, r.top_left);
+        assert_eq!(outer.top_right, r.top_right);
+        assert_eq!(outer.bottom_right, size2(0.0, 0.0));
+    }
+
+    #[test]
+    fn test_intersection() {
+        let r = rounded(10.0);
+
+        // Boxes which don't reach the curves.
+        let i = r.intersection(&Box2D::new(point2(-10.0, -10.0), point2(50.0, 100.0))).unwrap();
+        assert_eq!(i.rect, Box2D::new(point2(0.0, 0.0), point2(50.0, 50.0)));
+        let (round, square) = (size2(10.0, 10.0), size2(0.0, 0.0));
+        assert_eq!(i.radii(), [round, square, square, round]);
+
+        let i = r.intersection(&Box2D::new(point2(10.0, 5.0), point2(90.0, 45.0))).unwrap();
+        assert_eq!(i, RoundedRect::from_box(Box2D::new(point2(10.0, 5.0), point2(90.0, 45.0))));
+
+        // Removing a part of a corner which is inside of its curve.
+        let b = Box2D::new(point2(5.0, 5.0), point2(50.0, 40.0));
+        assert_eq!(r.intersection(&b), Some(RoundedRect::from_box(b)));
+
+        // Cutting through a curve.
+        assert!(r.intersection(&Box2D::new(point2(1.0, 1.0), point2(200.0, 200.0))).is_none());
+        assert!(r.intersection(&Box2D::new(point2(-5.0, -5.0), point2(5.0, 60.0))).is_none());
+
+        // No intersection.
+        assert!(r.intersection(&Box2D::new(point2(200.0, 0.0), point2(300.0, 50.0))).is_none());
+    }
+
+    #[test]
+    fn test_arcs() {
+        let r = rounded(10.0);
+        let arcs = r.to_arcs();
+        assert!(arcs[0].from().approx_eq(&point2(0.0, 10.0)));
+        assert!(arcs[0].to().approx_eq(&point2(10.0, 0.0)));
+        assert!(arcs[1].from().approx_eq(&point2(90.0, 0.0)));
+        assert!(arcs[1].to().approx_eq(&point2(100.0, 10.0)));
+        assert!(arcs[2].from().approx_eq(&point2(100.0, 40.0)));
+        assert!(arcs[2].to().approx_eq(&point2(90.0, 50.0)));
+        assert!(arcs[3].from().approx_eq(&point2(10.0, 50.0)));
+        assert!(arcs[3].to().approx_eq(&point2(0.0, 40.0)));
+
+        let points: Vec<_> = r.flattened(0.1).collect();
+        for p in &points {
+            assert!(r.rect.inflate(1e-9, 1e-9).contains(*p));
+            // The points are on the outline: moving the rectangle slightly toward the
+            // corner of a point puts the point inside, and moving it away puts it outside.
+            let dx = if p.x < 50.0 { -1e-6 } else { 1e-6 };
+            let dy = if p.y < 25.0 { -1e-6 } else { 1e-6 };
+            assert!(r.translate(vec2(dx, dy)).contains(*p));
+            assert!(!r.translate(vec2(-dx, -dy)).contains(*p));
+        }
+        assert!(points.first().unwrap().approx_eq(&point2(0.0, 10.0)));
+        assert!(points.last().unwrap().approx_eq(&point2(0.0, 40.0)));
+
+        // Square corners give a single point.
+        let square = RoundedRect::from_box(r.rect);
+        let points: Vec<_> = square.flattened(0.1).collect();
+        assert_eq!(
+            points,
+            [point2(0.0, 0.0), point2(100.0, 0.0), point2(100.0, 50.0), point2(0.0, 50.0)]
+        );
+    }
+}
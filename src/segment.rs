@@ -0,0 +1,791 @@
+// Copyright 2013 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::UnknownUnit;
+use crate::approxeq::ApproxEq;
+use crate::box2d::Box2D;
+use crate::box3d::Box3D;
+use crate::num::*;
+use crate::point::{Point2D, Point3D};
+use crate::transform2d::Transform2D;
+use crate::transform3d::Transform3D;
+use crate::vector::{Vector2D, Vector3D};
+
+use num_traits::real::Real;
+use num_traits::NumCast;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "bytemuck")]
+use bytemuck::{Zeroable, Pod};
+
+use core::cmp::PartialOrd;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::{Add, Div, Mul, Sub};
+
+/// A 2d line segment between two points.
+///
+/// The segment is parametrized with `t` going from `0` at `from` to `1` at `to`.
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Serialize", deserialize = "T: Deserialize<'de>"))
+)]
+pub struct LineSegment2D<T, U> {
+    pub from: Point2D<T, U>,
+    pub to: Point2D<T, U>,
+}
+
+impl<T: Hash, U> Hash for LineSegment2D<T, U> {
+    fn hash<H: Hasher>(&self, h: &mut H) {
+        self.from.hash(h);
+        self.to.hash(h);
+    }
+}
+
+impl<T: Copy, U> Copy for LineSegment2D<T, U> {}
+
+impl<T: Clone, U> Clone for LineSegment2D<T, U> {
+    fn clone(&self) -> Self {
+        Self::new(self.from.clone(), self.to.clone())
+    }
+}
+
+impl<T: PartialEq, U> PartialEq for LineSegment2D<T, U> {
+    fn eq(&self, other: &Self) -> bool {
+        self.from.eq(&other.from) && self.to.eq(&other.to)
+    }
+}
+
+impl<T: Eq, U> Eq for LineSegment2D<T, U> {}
+
+impl<T: fmt::Debug, U> fmt::Debug for LineSegment2D<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("LineSegment2D")
+            .field(&self.from)
+            .field(&self.to)
+            .finish()
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, T, U> arbitrary::Arbitrary<'a> for LineSegment2D<T, U>
+where
+    T: arbitrary::Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self>
+    {
+        let (from, to) = arbitrary::Arbitrary::arbitrary(u)?;
+        Ok(LineSegment2D {
+            from,
+            to,
+        })
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: Zeroable, U> Zeroable for LineSegment2D<T, U> {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: Pod, U: 'static> Pod for LineSegment2D<T, U> {}
+
+impl<T, U> LineSegment2D<T, U> {
+    /// Constructor.
+    #[inline]
+    pub const fn new(from: Point2D<T, U>, to: Point2D<T, U>) -> Self {
+        LineSegment2D { from, to }
+    }
+}
+
+impl<T: Copy, U> LineSegment2D<T, U> {
+    /// Returns the same segment with the endpoints swapped.
+    #[inline]
+    #[must_use]
+    pub fn flip(&self) -> Self {
+        LineSegment2D::new(self.to, self.from)
+    }
+
+    /// Drop the units, preserving only the numeric value.
+    #[inline]
+    pub fn to_untyped(&self) -> LineSegment2D<T, UnknownUnit> {
+        LineSegment2D::new(self.from.to_untyped(), self.to.to_untyped())
+    }
+
+    /// Tag a unitless value with units.
+    #[inline]
+    pub fn from_untyped(s: &LineSegment2D<T, UnknownUnit>) -> Self {
+        LineSegment2D::new(Point2D::from_untyped(s.from), Point2D::from_untyped(s.to))
+    }
+
+    /// Cast the unit
+    #[inline]
+    pub fn cast_unit<V>(&self) -> LineSegment2D<T, V> {
+        LineSegment2D::new(self.from.cast_unit(), self.to.cast_unit())
+    }
+}
+
+impl<T, U> LineSegment2D<T, U>
+where
+    T: Copy + Sub<T, Output = T>,
+{
+    /// Returns the vector going from `from` to `to`.
+    #[inline]
+    pub fn to_vector(&self) -> Vector2D<T, U> {
+        self.to - self.from
+    }
+}
+
+impl<T, U> LineSegment2D<T, U>
+where
+    T: Copy + Add<T, Output = T>,
+{
+    /// Translate the segment by a vector.
+    #[inline]
+    #[must_use]
+    pub fn translate(&self, by: Vector2D<T, U>) -> Self {
+        LineSegment2D::new(self.from + by, self.to + by)
+    }
+}
+
+impl<T, U> LineSegment2D<T, U>
+where
+    T: Copy + Add<T, Output = T> + Sub<T, Output = T> + Mul<T, Output = T>,
+{
+    /// Returns the squared length of the segment.
+    #[inline]
+    pub fn square_length(&self) -> T {
+        self.to_vector().square_length()
+    }
+}
+
+impl<T, U> LineSegment2D<T, U>
+where
+    T: Copy + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    /// Returns the point at parameter `t`, `0` being `from` and `1` being `to`.
+    #[inline]
+    pub fn sample(&self, t: T) -> Point2D<T, U> {
+        self.from.lerp(self.to, t)
+    }
+
+    /// Splits the segment at parameter `t`.
+    #[inline]
+    pub fn split(&self, t: T) -> (Self, Self) {
+        let split_point = self.sample(t);
+        (
+            LineSegment2D::new(self.from, split_point),
+            LineSegment2D::new(split_point, self.to),
+        )
+    }
+}
+
+impl<T, U> LineSegment2D<T, U>
+where
+    T: Copy + Zero + PartialOrd,
+{
+    /// Returns the smallest box containing both endpoints.
+    #[inline]
+    pub fn bounding_box(&self) -> Box2D<T, U> {
+        Box2D::from_points(&[self.from, self.to])
+    }
+}
+
+impl<T, U> LineSegment2D<T, U>
+where
+    T: Copy + Add<Output = T> + Mul<Output = T>,
+{
+    /// Applies the transform to both endpoints of the segment.
+    #[inline]
+    pub fn transform<Dst>(&self, transform: &Transform2D<T, U, Dst>) -> LineSegment2D<T, Dst> {
+        LineSegment2D::new(
+            transform.transform_point(self.from),
+            transform.transform_point(self.to),
+        )
+    }
+}
+
+impl<T: Real, U> LineSegment2D<T, U> {
+    /// Returns the length of the segment.
+    #[inline]
+    pub fn length(&self) -> T {
+        self.to_vector().length()
+    }
+
+    /// Returns the parameter of the point of the segment that is closest to `p`.
+    ///
+    /// The result is always in the `[0, 1]` range.
+    pub fn closest_point_t(&self, p: Point2D<T, U>) -> T {
+        let v = self.to_vector();
+        let square_length = v.square_length();
+        if square_length == T::zero() {
+            return T::zero();
+        }
+
+        let t = (p - self.from).dot(v) / square_length;
+        t.max(T::zero()).min(T::one())
+    }
+
+    /// Returns the point of the segment that is closest to `p`.
+    #[inline]
+    pub fn closest_point(&self, p: Point2D<T, U>) -> Point2D<T, U> {
+        self.sample(self.closest_point_t(p))
+    }
+
+    /// Returns the squared distance between `p` and the segment.
+    #[inline]
+    pub fn square_distance_to_point(&self, p: Point2D<T, U>) -> T {
+        (self.closest_point(p) - p).square_length()
+    }
+
+    /// Returns the distance between `p` and the segment.
+    #[inline]
+    pub fn distance_to_point(&self, p: Point2D<T, U>) -> T {
+        self.square_distance_to_point(p).sqrt()
+    }
+
+    /// Computes the intersection of two segments, returning the parameter of the
+    /// intersection point on `self` and on `other` respectively.
+    ///
+    /// Parallel and collinear segments are considered as not intersecting.
+    pub fn intersection_t(&self, other: &Self) -> Option<(T, T)> {
+        let v1 = self.to_vector();
+        let v2 = other.to_vector();
+        let denom = v1.cross(v2);
+        if denom == T::zero() {
+            return None;
+        }
+
+        let v = other.from - self.from;
+        let t1 = v.cross(v2) / denom;
+        let t2 = v.cross(v1) / denom;
+
+        let zero = T::zero();
+        let one = T::one();
+        if t1 < zero || t1 > one || t2 < zero || t2 > one {
+            return None;
+        }
+
+        Some((t1, t2))
+    }
+
+    /// Computes the intersection point of two segments, if any.
+    ///
+    /// Parallel and collinear segments are considered as not intersecting.
+    #[inline]
+    pub fn intersection(&self, other: &Self) -> Option<Point2D<T, U>> {
+        self.intersection_t(other).map(|(t, _)| self.sample(t))
+    }
+
+    /// Returns `true` if the two segments intersect.
+    #[inline]
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.intersection_t(other).is_some()
+    }
+}
+
+impl<T: NumCast + Copy, U> LineSegment2D<T, U> {
+    /// Cast from one numeric representation to another, preserving the units.
+    #[inline]
+    pub fn cast<NewT: NumCast>(&self) -> LineSegment2D<NewT, U> {
+        LineSegment2D::new(self.from.cast(), self.to.cast())
+    }
+
+    /// Fallible cast from one numeric representation to another, preserving the units.
+    pub fn try_cast<NewT: NumCast>(&self) -> Option<LineSegment2D<NewT, U>> {
+        match (self.from.try_cast(), self.to.try_cast()) {
+            (Some(a), Some(b)) => Some(LineSegment2D::new(a, b)),
+            _ => None,
+        }
+    }
+
+    // Convenience functions for common casts
+
+    /// Cast into an `f32` segment.
+    #[inline]
+    pub fn to_f32(&self) -> LineSegment2D<f32, U> {
+        self.cast()
+    }
+
+    /// Cast into an `f64` segment.
+    #[inline]
+    pub fn to_f64(&self) -> LineSegment2D<f64, U> {
+        self.cast()
+    }
+}
+
+impl<T: ApproxEq<T>, U> ApproxEq<T> for LineSegment2D<T, U> {
+    #[inline]
+    fn approx_epsilon() -> T {
+        T::approx_epsilon()
+    }
+
+    #[inline]
+    fn approx_eq_eps(&self, other: &Self, eps: &T) -> bool {
+        self.from.x.approx_eq_eps(&other.from.x, eps)
+            && self.from.y.approx_eq_eps(&other.from.y, eps)
+            && self.to.x.approx_eq_eps(&other.to.x, eps)
+            && self.to.y.approx_eq_eps(&other.to.y, eps)
+    }
+}
+
+/// A 3d line segment between two points.
+///
+/// The segment is parametrized with `t` going from `0` at `from` to `1` at `to`.
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Serialize", deserialize = "T: Deserialize<'de>"))
+)]
+pub struct LineSegment3D<T, U> {
+    pub from: Point3D<T, U>,
+    pub to: Point3D<T, U>,
+}
+
+impl<T: Hash, U> Hash for LineSegment3D<T, U> {
+    fn hash<H: Hasher>(&self, h: &mut H) {
+        self.from.hash(h);
+        self.to.hash(h);
+    }
+}
+
+impl<T: Copy, U> Copy for LineSegment3D<T, U> {}
+
+impl<T: Clone, U> Clone for LineSegment3D<T, U> {
+    fn clone(&self) -> Self {
+        Self::new(self.from.clone(), self.to.clone())
+    }
+}
+
+impl<T: PartialEq, U> PartialEq for LineSegment3D<T, U> {
+    fn eq(&self, other: &Self) -> bool {
+        self.from.eq(&other.from) && self.to.eq(&other.to)
+    }
+}
+
+impl<T: Eq, U> Eq for LineSegment3D<T, U> {}
+
+impl<T: fmt::Debug, U> fmt::Debug for LineSegment3D<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("LineSegment3D")
+            .field(&self.from)
+            .field(&self.to)
+            .finish()
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, T, U> arbitrary::Arbitrary<'a> for LineSegment3D<T, U>
+where
+    T: arbitrary::Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self>
+    {
+        let (x0, y0, z0, x1, y1, z1) = arbitrary::Arbitrary::arbitrary(u)?;
+        Ok(LineSegment3D {
+            from: Point3D::new(x0, y0, z0),
+            to: Point3D::new(x1, y1, z1),
+        })
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: Zeroable, U> Zeroable for LineSegment3D<T, U> {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: Pod, U: 'static> Pod for LineSegment3D<T, U> {}
+
+impl<T, U> LineSegment3D<T, U> {
+    /// Constructor.
+    #[inline]
+    pub const fn new(from: Point3D<T, U>, to: Point3D<T, U>) -> Self {
+        LineSegment3D { from, to }
+    }
+}
+
+impl<T: Copy, U> LineSegment3D<T, U> {
+    /// Returns the same segment with the endpoints swapped.
+    #[inline]
+    #[must_use]
+    pub fn flip(&self) -> Self {
+        LineSegment3D::new(self.to, self.from)
+    }
+
+    /// Drop the units, preserving only the numeric value.
+    #[inline]
+    pub fn to_untyped(&self) -> LineSegment3D<T, UnknownUnit> {
+        LineSegment3D::new(self.from.to_untyped(), self.to.to_untyped())
+    }
+
+    /// Tag a unitless value with units.
+    #[inline]
+    pub fn from_untyped(s: &LineSegment3D<T, UnknownUnit>) -> Self {
+        LineSegment3D::new(Point3D::from_untyped(s.from), Point3D::from_untyped(s.to))
+    }
+
+    /// Cast the unit
+    #[inline]
+    pub fn cast_unit<V>(&self) -> LineSegment3D<T, V> {
+        LineSegment3D::new(self.from.cast_unit(), self.to.cast_unit())
+    }
+}
+
+impl<T, U> LineSegment3D<T, U>
+where
+    T: Copy + Sub<T, Output = T>,
+{
+    /// Returns the vector going from `from` to `to`.
+    #[inline]
+    pub fn to_vector(&self) -> Vector3D<T, U> {
+        self.to - self.from
+    }
+}
+
+impl<T, U> LineSegment3D<T, U>
+where
+    T: Copy + Add<T, Output = T>,
+{
+    /// Translate the segment by a vector.
+    #[inline]
+    #[must_use]
+    pub fn translate(&self, by: Vector3D<T, U>) -> Self {
+        LineSegment3D::new(self.from + by, self.to + by)
+    }
+}
+
+impl<T, U> LineSegment3D<T, U>
+where
+    T: Copy + Add<T, Output = T> + Sub<T, Output = T> + Mul<T, Output = T>,
+{
+    /// Returns the squared length of the segment.
+    #[inline]
+    pub fn square_length(&self) -> T {
+        self.to_vector().square_length()
+    }
+}
+
+impl<T, U> LineSegment3D<T, U>
+where
+    T: Copy + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    /// Returns the point at parameter `t`, `0` being `from` and `1` being `to`.
+    #[inline]
+    pub fn sample(&self, t: T) -> Point3D<T, U> {
+        self.from.lerp(self.to, t)
+    }
+
+    /// Splits the segment at parameter `t`.
+    #[inline]
+    pub fn split(&self, t: T) -> (Self, Self) {
+        let split_point = self.sample(t);
+        (
+            LineSegment3D::new(self.from, split_point),
+            LineSegment3D::new(split_point, self.to),
+        )
+    }
+}
+
+impl<T, U> LineSegment3D<T, U>
+where
+    T: Copy + Zero + PartialOrd,
+{
+    /// Returns the smallest box containing both endpoints.
+    #[inline]
+    pub fn bounding_box(&self) -> Box3D<T, U> {
+        Box3D::from_points(&[self.from, self.to])
+    }
+}
+
+impl<T, U> LineSegment3D<T, U>
+where
+    T: Copy + Zero + PartialOrd + Add<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    /// Applies the transform to both endpoints of the segment, returning `None` if
+    /// either endpoint ends up behind the projection plane (see
+    /// [`Transform3D::transform_point3d`]).
+    ///
+    /// [`Transform3D::transform_point3d`]: struct.Transform3D.html#method.transform_point3d
+    #[inline]
+    pub fn transform<Dst>(
+        &self,
+        transform: &Transform3D<T, U, Dst>,
+    ) -> Option<LineSegment3D<T, Dst>> {
+        Some(LineSegment3D::new(
+            transform.transform_point3d(self.from)?,
+            transform.transform_point3d(self.to)?,
+        ))
+    }
+}
+
+impl<T: Real, U> LineSegment3D<T, U> {
+    /// Returns the length of the segment.
+    #[inline]
+    pub fn length(&self) -> T {
+        self.to_vector().length()
+    }
+
+    /// Returns the parameter of the point of the segment that is closest to `p`.
+    ///
+    /// The result is always in the `[0, 1]` range.
+    pub fn closest_point_t(&self, p: Point3D<T, U>) -> T {
+        let v = self.to_vector();
+        let square_length = v.square_length();
+        if square_length == T::zero() {
+            return T::zero();
+        }
+
+        let t = (p - self.from).dot(v) / square_length;
+        t.max(T::zero()).min(T::one())
+    }
+
+    /// Returns the point of the segment that is closest to `p`.
+    #[inline]
+    pub fn closest_point(&self, p: Point3D<T, U>) -> Point3D<T, U> {
+        self.sample(self.closest_point_t(p))
+    }
+
+    /// Returns the squared distance between `p` and the segment.
+    #[inline]
+    pub fn square_distance_to_point(&self, p: Point3D<T, U>) -> T {
+        (self.closest_point(p) - p).square_length()
+    }
+
+    /// Returns the distance between `p` and the segment.
+    #[inline]
+    pub fn distance_to_point(&self, p: Point3D<T, U>) -> T {
+        self.square_distance_to_point(p).sqrt()
+    }
+
+    /// Returns the parameters of the closest pair of points between `self` and
+    /// `other`, respectively.
+    ///
+    /// Both parameters are in the `[0, 1]` range.
+    pub fn closest_points_t(&self, other: &Self) -> (T, T) {
+        let zero = T::zero();
+        let one = T::one();
+        let clamp = |x: T| x.max(zero).min(one);
+
+        let v1 = self.to_vector();
+        let v2 = other.to_vector();
+        let r = self.from - other.from;
+        let a = v1.square_length();
+        let e = v2.square_length();
+        let f = v2.dot(r);
+
+        if a == zero && e == zero {
+            return (zero, zero);
+        }
+        if a == zero {
+            return (zero, clamp(f / e));
+        }
+
+        let c = v1.dot(r);
+        if e == zero {
+            return (clamp(-c / a), zero);
+        }
+
+        let b = v1.dot(v2);
+        let denom = a * e - b * b;
+        let mut t1 = if denom != zero {
+            clamp((b * f - c * e) / denom)
+        } else {
+            zero
+        };
+        let mut t2 = (b * t1 + f) / e;
+        if t2 < zero {
+            t2 = zero;
+            t1 = clamp(-c / a);
+        } else if t2 > one {
+            t2 = one;
+            t1 = clamp((b - c) / a);
+        }
+
+        (t1, t2)
+    }
+
+    /// Returns the distance between the closest pair of points of the two segments.
+    #[inline]
+    pub fn distance_to_segment(&self, other: &Self) -> T {
+        let (t1, t2) = self.closest_points_t(other);
+        self.sample(t1).distance_to(other.sample(t2))
+    }
+}
+
+impl<T: Real + ApproxEq<T>, U> LineSegment3D<T, U> {
+    /// Computes the intersection of two segments, returning the parameter of the
+    /// intersection point on `self` and on `other` respectively.
+    ///
+    /// Two segments are considered intersecting if their closest points are
+    /// approximately equal.
+    pub fn intersection_t(&self, other: &Self) -> Option<(T, T)> {
+        let (t1, t2) = self.closest_points_t(other);
+        if self.sample(t1).approx_eq(&other.sample(t2)) {
+            Some((t1, t2))
+        } else {
+            None
+        }
+    }
+
+    /// Computes the intersection point of two segments, if any.
+    #[inline]
+    pub fn intersection(&self, other: &Self) -> Option<Point3D<T, U>> {
+        self.intersection_t(other).map(|(t, _)| self.sample(t))
+    }
+}
+
+impl<T: NumCast + Copy, U> LineSegment3D<T, U> {
+    /// Cast from one numeric representation to another, preserving the units.
+    #[inline]
+    pub fn cast<NewT: NumCast>(&self) -> LineSegment3D<NewT, U> {
+        LineSegment3D::new(self.from.cast(), self.to.cast())
+    }
+
+    /// Fallible cast from one numeric representation to another, preserving the units.
+    pub fn try_cast<NewT: NumCast>(&self) -> Option<LineSegment3D<NewT, U>> {
+        match (self.from.try_cast(), self.to.try_cast()) {
+            (Some(a), Some(b)) => Some(LineSegment3D::new(a, b)),
+            _ => None,
+        }
+    }
+
+    // Convenience functions for common casts
+
+    /// Cast into an `f32` segment.
+    #[inline]
+    pub fn to_f32(&self) -> LineSegment3D<f32, U> {
+        self.cast()
+    }
+
+    /// Cast into an `f64` segment.
+    #[inline]
+    pub fn to_f64(&self) -> LineSegment3D<f64, U> {
+        self.cast()
+    }
+}
+
+impl<T: ApproxEq<T>, U> ApproxEq<T> for LineSegment3D<T, U> {
+    #[inline]
+    fn approx_epsilon() -> T {
+        T::approx_epsilon()
+    }
+
+    #[inline]
+    fn approx_eq_eps(&self, other: &Self, eps: &T) -> bool {
+        self.from.x.approx_eq_eps(&other.from.x, eps)
+            && self.from.y.approx_eq_eps(&other.from.y, eps)
+            && self.from.z.approx_eq_eps(&other.from.z, eps)
+            && self.to.x.approx_eq_eps(&other.to.x, eps)
+            && self.to.y.approx_eq_eps(&other.to.y, eps)
+            && self.to.z.approx_eq_eps(&other.to.z, eps)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::approxeq::ApproxEq;
+    use crate::default::{LineSegment2D, LineSegment3D, Transform2D, Transform3D};
+    use crate::{point2, point3, vec2};
+
+    #[test]
+    fn test_sample_and_split() {
+        let s = LineSegment2D::new(point2(0.0, 0.0), point2(10.0, 20.0));
+        assert_eq!(s.sample(0.0), s.from);
+        assert_eq!(s.sample(1.0), s.to);
+        assert_eq!(s.sample(0.5), point2(5.0, 10.0));
+
+        let (a, b) = s.split(0.25);
+        assert_eq!(a, LineSegment2D::new(point2(0.0, 0.0), point2(2.5, 5.0)));
+        assert_eq!(b, LineSegment2D::new(point2(2.5, 5.0), point2(10.0, 20.0)));
+    }
+
+    #[test]
+    fn test_length() {
+        let s = LineSegment2D::new(point2(1.0, 1.0), point2(4.0, 5.0));
+        assert_eq!(s.length(), 5.0);
+        assert_eq!(s.square_length(), 25.0);
+
+        let s = LineSegment3D::new(point3(0.0, 0.0, 0.0), point3(2.0, 3.0, 6.0));
+        assert_eq!(s.length(), 7.0);
+    }
+
+    #[test]
+    fn test_closest_point() {
+        let s = LineSegment2D::new(point2(0.0, 0.0), point2(10.0, 0.0));
+        assert_eq!(s.closest_point(point2(5.0, 3.0)), point2(5.0, 0.0));
+        assert_eq!(s.closest_point(point2(-5.0, 3.0)), point2(0.0, 0.0));
+        assert_eq!(s.closest_point(point2(15.0, -3.0)), point2(10.0, 0.0));
+        assert_eq!(s.distance_to_point(point2(5.0, 3.0)), 3.0);
+        assert_eq!(s.distance_to_point(point2(13.0, 4.0)), 5.0);
+
+        let degenerate = LineSegment2D::new(point2(1.0, 1.0), point2(1.0, 1.0));
+        assert_eq!(degenerate.closest_point(point2(4.0, 5.0)), point2(1.0, 1.0));
+    }
+
+    #[test]
+    fn test_intersection_2d() {
+        let a = LineSegment2D::new(point2(0.0, 0.0), point2(10.0, 10.0));
+        let b = LineSegment2D::new(point2(0.0, 10.0), point2(10.0, 0.0));
+        assert_eq!(a.intersection_t(&b), Some((0.5, 0.5)));
+        assert_eq!(a.intersection(&b), Some(point2(5.0, 5.0)));
+
+        let c = LineSegment2D::new(point2(0.0, 10.0), point2(4.0, 6.0));
+        assert_eq!(a.intersection_t(&c), None);
+        assert!(!a.intersects(&c));
+
+        let parallel = a.translate(vec2(1.0, 0.0));
+        assert_eq!(a.intersection_t(&parallel), None);
+
+        // Touching at an endpoint.
+        let d = LineSegment2D::new(point2(10.0, 10.0), point2(20.0, 0.0));
+        assert_eq!(a.intersection_t(&d), Some((1.0, 0.0)));
+    }
+
+    #[test]
+    fn test_intersection_3d() {
+        let a = LineSegment3D::new(point3(0.0, 0.0, 0.0), point3(2.0, 0.0, 0.0));
+        let b = LineSegment3D::new(point3(1.0, -1.0, 0.0), point3(1.0, 1.0, 0.0));
+        assert_eq!(a.intersection_t(&b), Some((0.5, 0.5)));
+
+        let c = LineSegment3D::new(point3(1.0, -1.0, 1.0), point3(1.0, 1.0, 1.0));
+        assert_eq!(a.intersection_t(&c), None);
+        assert_eq!(a.closest_points_t(&c), (0.5, 0.5));
+        assert_eq!(a.distance_to_segment(&c), 1.0);
+    }
+
+    #[test]
+    fn test_bounding_box() {
+        let s = LineSegment2D::new(point2(5.0, -1.0), point2(-3.0, 4.0));
+        let b = s.bounding_box();
+        assert_eq!(b.min, point2(-3.0, -1.0));
+        assert_eq!(b.max, point2(5.0, 4.0));
+
+        let s = LineSegment3D::new(point3(5.0, -1.0, 2.0), point3(-3.0, 4.0, -2.0));
+        let b = s.bounding_box();
+        assert_eq!(b.min, point3(-3.0, -1.0, -2.0));
+        assert_eq!(b.max, point3(5.0, 4.0, 2.0));
+    }
+
+    #[test]
+    fn test_transform() {
+        let s = LineSegment2D::new(point2(1.0, 0.0), point2(2.0, 0.0));
+        let t = Transform2D::scale(2.0, 2.0).then_translate(vec2(1.0, 1.0));
+        assert_eq!(
+            s.transform(&t),
+            LineSegment2D::new(point2(3.0, 1.0), point2(5.0, 1.0))
+        );
+
+        let s = LineSegment3D::new(point3(1.0, 0.0, 0.0), point3(2.0, 0.0, 0.0));
+        let t = Transform3D::translation(0.0, 1.0, 2.0);
+        assert!(s.transform(&t).unwrap().approx_eq(&LineSegment3D::new(
+            point3(1.0, 1.0, 2.0),
+            point3(2.0, 1.0, 2.0)
+        )));
+    }
+}
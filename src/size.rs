@@ -285,6 +285,42 @@ impl<T: Copy, U> Size2D<T, U> {
         self.width * self.height
     }
 
+    /// Returns the ratio of the width to the height.
+    #[inline]
+    pub fn aspect_ratio(self) -> T::Output
+    where
+        T: Div,
+    {
+        self.width / self.height
+    }
+
+    /// Returns the largest size with the given ratio of the width to the height that
+    /// fits in this size.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use euclid::size2;
+    /// use euclid::default::Size2D;
+    ///
+    /// let size: Size2D<_> = size2(100.0, 100.0);
+    ///
+    /// assert_eq!(size.with_aspect_ratio(2.0), size2(100.0, 50.0));
+    /// assert_eq!(size.with_aspect_ratio(0.5), size2(50.0, 100.0));
+    /// ```
+    #[must_use]
+    pub fn with_aspect_ratio(self, ratio: T) -> Self
+    where
+        T: PartialOrd + Mul<Output = T> + Div<Output = T>,
+    {
+        let height = self.width / ratio;
+        if height <= self.height {
+            size2(self.width, height)
+        } else {
+            size2(self.height * ratio, self.height)
+        }
+    }
+
     /// Linearly interpolate each component between this size and another size.
     ///
     /// # Example
@@ -735,6 +771,16 @@ mod size2d {
         assert_eq!(p.area(), 3.0);
     }
 
+    #[test]
+    pub fn test_aspect_ratio() {
+        let s = Size2D::new(4.0, 2.0);
+        assert_eq!(s.aspect_ratio(), 2.0);
+        assert_eq!(s.with_aspect_ratio(2.0), s);
+        assert_eq!(s.with_aspect_ratio(1.0), Size2D::new(2.0, 2.0));
+        assert_eq!(s.with_aspect_ratio(4.0), Size2D::new(4.0, 1.0));
+        assert_eq!(Size2D::new(9, 10).with_aspect_ratio(3), Size2D::new(9, 3));
+    }
+
     #[cfg(feature = "mint")]
     #[test]
     pub fn test_mint() {
@@ -0,0 +1,496 @@
+// Copyright 2013 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::UnknownUnit;
+use crate::approxeq::ApproxEq;
+use crate::box3d::Box3D;
+use crate::num::*;
+use crate::point::Point3D;
+use crate::ray::Ray3D;
+use crate::vector::{vec3, Vector3D};
+
+use num_traits::real::Real;
+use num_traits::NumCast;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "bytemuck")]
+use bytemuck::{Zeroable, Pod};
+
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::{Add, Mul, Sub};
+
+/// A sphere, represented by its center and its radius.
+///
+/// Spheres are closed: the points on the sphere itself are considered to be inside.
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Serialize", deserialize = "T: Deserialize<'de>"))
+)]
+pub struct Sphere<T, U> {
+    pub center: Point3D<T, U>,
+    pub radius: T,
+}
+
+impl<T: Hash, U> Hash for Sphere<T, U> {
+    fn hash<H: Hasher>(&self, h: &mut H) {
+        self.center.hash(h);
+        self.radius.hash(h);
+    }
+}
+
+impl<T: Copy, U> Copy for Sphere<T, U> {}
+
+impl<T: Clone, U> Clone for Sphere<T, U> {
+    fn clone(&self) -> Self {
+        Self::new(self.center.clone(), self.radius.clone())
+    }
+}
+
+impl<T: PartialEq, U> PartialEq for Sphere<T, U> {
+    fn eq(&self, other: &Self) -> bool {
+        self.center.eq(&other.center) && self.radius.eq(&other.radius)
+    }
+}
+
+impl<T: Eq, U> Eq for Sphere<T, U> {}
+
+impl<T: fmt::Debug, U> fmt::Debug for Sphere<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Sphere")
+            .field(&self.center)
+            .field(&self.radius)
+            .finish()
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, T, U> arbitrary::Arbitrary<'a> for Sphere<T, U>
+where
+    T: arbitrary::Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self>
+    {
+        let (x, y, z, radius) = arbitrary::Arbitrary::arbitrary(u)?;
+        Ok(Sphere {
+            center: Point3D::new(x, y, z),
+            radius,
+        })
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: Zeroable, U> Zeroable for Sphere<T, U> {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: Pod, U: 'static> Pod for Sphere<T, U> {}
+
+impl<T, U> Sphere<T, U> {
+    /// Constructor.
+    #[inline]
+    pub const fn new(center: Point3D<T, U>, radius: T) -> Self {
+        Sphere { center, radius }
+    }
+}
+
+impl<T: Copy, U> Sphere<T, U> {
+    /// Drop the units, preserving only the numeric value.
+    #[inline]
+    pub fn to_untyped(&self) -> Sphere<T, UnknownUnit> {
+        Sphere::new(self.center.to_untyped(), self.radius)
+    }
+
+    /// Tag a unitless value with units.
+    #[inline]
+    pub fn from_untyped(s: &Sphere<T, UnknownUnit>) -> Self {
+        Sphere::new(Point3D::from_untyped(s.center), s.radius)
+    }
+
+    /// Cast the unit
+    #[inline]
+    pub fn cast_unit<V>(&self) -> Sphere<T, V> {
+        Sphere::new(self.center.cast_unit(), self.radius)
+    }
+}
+
+impl<T, U> Sphere<T, U>
+where
+    T: Copy + Zero + PartialOrd + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    /// Returns `true` if the point is inside of the sphere or on its boundary.
+    #[inline]
+    pub fn contains(&self, p: Point3D<T, U>) -> bool {
+        (p - self.center).square_length() <= self.radius * self.radius
+    }
+
+    /// Returns `true` if the two spheres overlap or touch.
+    #[inline]
+    pub fn intersects(&self, other: &Self) -> bool {
+        let r = self.radius + other.radius;
+        (other.center - self.center).square_length() <= r * r
+    }
+
+    /// Returns `true` if the sphere and the box overlap or touch.
+    ///
+    /// Empty boxes never intersect.
+    pub fn intersects_box(&self, b: &Box3D<T, U>) -> bool {
+        if b.is_empty() {
+            return false;
+        }
+
+        self.contains(self.center.clamp(b.min, b.max))
+    }
+
+    /// Returns the smallest box containing the sphere.
+    #[inline]
+    pub fn bounding_box(&self) -> Box3D<T, U> {
+        let r = vec3(self.radius, self.radius, self.radius);
+        Box3D::new(self.center - r, self.center + r)
+    }
+}
+
+impl<T: Real, U> Sphere<T, U> {
+    /// Computes the parameter range `(t_enter, t_exit)` over which the ray is inside
+    /// of the sphere, if the ray hits it.
+    ///
+    /// `t_enter` is zero if the origin of the ray is inside of the sphere.
+    pub fn intersect_ray(&self, ray: &Ray3D<T, U>) -> Option<(T, T)> {
+        let zero = T::zero();
+        let a = ray.direction.square_length();
+        if a == zero {
+            return None;
+        }
+
+        let oc = ray.origin - self.center;
+        let b = ray.direction.dot(oc);
+        let c = oc.square_length() - self.radius * self.radius;
+        let discriminant = b * b - a * c;
+        if discriminant < zero {
+            return None;
+        }
+
+        let sqrt = discriminant.sqrt();
+        let t_exit = (-b + sqrt) / a;
+        if t_exit < zero {
+            return None;
+        }
+
+        Some((((-b - sqrt) / a).max(zero), t_exit))
+    }
+}
+
+impl<T: Real + ApproxEq<T>, U> Sphere<T, U> {
+    /// Returns the smallest sphere containing all of the points, or `None` if there
+    /// are no points.
+    ///
+    /// This uses the iterative form of Welzl's algorithm, which runs in expected linear
+    /// time if the points are in a random order. Inputs in a pathological order should
+    /// be shuffled first.
+    pub fn minimal_enclosing(points: &[Point3D<T, U>]) -> Option<Self> {
+        let (first, rest) = points.split_first()?;
+        let mut sphere = Sphere::new(*first, T::zero());
+
+        for (i, &p) in rest.iter().enumerate() {
+            if sphere.contains_approx(p) {
+                continue;
+            }
+
+            // p is on the boundary of the minimal sphere of points[..i + 2].
+            sphere = Sphere::new(p, T::zero());
+            for (j, &q) in points[..i + 1].iter().enumerate() {
+                if sphere.contains_approx(q) {
+                    continue;
+                }
+
+                // p and q are on the boundary.
+                sphere = Sphere::from_diameter(p, q);
+                for (k, &r) in points[..j].iter().enumerate() {
+                    if sphere.contains_approx(r) {
+                        continue;
+                    }
+
+                    // p, q and r are on the boundary.
+                    sphere = Sphere::from_three_points(p, q, r);
+                    for &s in &points[..k] {
+                        if !sphere.contains_approx(s) {
+                            sphere = Sphere::from_four_points(p, q, r, s);
+                        }
+                    }
+                }
+            }
+        }
+
+        Some(sphere)
+    }
+
+    /// Like `contains`, with some tolerance for the points computed to be on the sphere.
+    fn contains_approx(&self, p: Point3D<T, U>) -> bool {
+        let eps = T::approx_epsilon();
+        (p - self.center).length() <= self.radius + eps * self.radius.max(T::one())
+    }
+
+    /// Returns the sphere centered on `center` going through the furthest of the points.
+    fn around(center: Point3D<T, U>, points: &[Point3D<T, U>]) -> Self {
+        let radius = points
+            .iter()
+            .fold(T::zero(), |r, p| r.max((*p - center).length()));
+        Sphere::new(center, radius)
+    }
+
+    /// The smallest sphere that has both points on its boundary.
+    fn from_diameter(a: Point3D<T, U>, b: Point3D<T, U>) -> Self {
+        let center = a.lerp(b, T::one() / (T::one() + T::one()));
+        Sphere::around(center, &[a, b])
+    }
+
+    /// The smallest sphere that has all three points on its boundary, or the smallest
+    /// one containing them if they are aligned.
+    fn from_three_points(a: Point3D<T, U>, b: Point3D<T, U>, c: Point3D<T, U>) -> Self {
+        let u = b - a;
+        let v = c - a;
+        let w = u.cross(v);
+        let ww = w.square_length();
+
+        let scale = u.square_length().max(v.square_length());
+        if ww <= T::epsilon() * scale * scale {
+            // Aligned points, the two furthest apart points define the sphere.
+            let candidates = [(a, b), (a, c), (b, c)];
+            let mut best = (a, b);
+            for &(p, q) in &candidates[1..] {
+                if (q - p).square_length() > (best.1 - best.0).square_length() {
+                    best = (p, q);
+                }
+            }
+            return Sphere::from_diameter(best.0, best.1);
+        }
+
+        let two = T::one() + T::one();
+        let offset: Vector3D<T, U> =
+            (v.cross(w) * u.square_length() + w.cross(u) * v.square_length()) / (two * ww);
+        Sphere::around(a + offset, &[a, b, c])
+    }
+
+    /// The smallest sphere that has all four points on its boundary, or the smallest
+    /// one containing them if they are coplanar.
+    fn from_four_points(
+        a: Point3D<T, U>,
+        b: Point3D<T, U>,
+        c: Point3D<T, U>,
+        d: Point3D<T, U>,
+    ) -> Self {
+        let u = b - a;
+        let v = c - a;
+        let w = d - a;
+        let det = u.dot(v.cross(w));
+
+        let scale = u.length().max(v.length()).max(w.length());
+        if det.abs() <= T::epsilon() * scale * scale * scale {
+            // Coplanar points, pick the smallest sphere defined by a subset of the
+            // points that contains all of them.
+            let points = [a, b, c, d];
+            let candidates = [
+                Sphere::from_three_points(a, b, c),
+                Sphere::from_three_points(a, b, d),
+                Sphere::from_three_points(a, c, d),
+                Sphere::from_three_points(b, c, d),
+                Sphere::from_diameter(a, b),
+                Sphere::from_diameter(a, c),
+                Sphere::from_diameter(a, d),
+                Sphere::from_diameter(b, c),
+                Sphere::from_diameter(b, d),
+                Sphere::from_diameter(c, d),
+            ];
+            let mut best = Sphere::around(a, &points);
+            for candidate in &candidates {
+                if candidate.radius < best.radius
+                    && points.iter().all(|p| candidate.contains_approx(*p))
+                {
+                    best = *candidate;
+                }
+            }
+            return best;
+        }
+
+        let two = T::one() + T::one();
+        let offset: Vector3D<T, U> = (v.cross(w) * u.square_length()
+            + w.cross(u) * v.square_length()
+            + u.cross(v) * w.square_length())
+            / (two * det);
+        Sphere::around(a + offset, &[a, b, c, d])
+    }
+}
+
+impl<T: NumCast + Copy, U> Sphere<T, U> {
+    /// Cast from one numeric representation to another, preserving the units.
+    #[inline]
+    pub fn cast<NewT: NumCast>(&self) -> Sphere<NewT, U> {
+        Sphere::new(self.center.cast(), NumCast::from(self.radius).unwrap())
+    }
+
+    /// Fallible cast from one numeric representation to another, preserving the units.
+    pub fn try_cast<NewT: NumCast>(&self) -> Option<Sphere<NewT, U>> {
+        match (self.center.try_cast(), NumCast::from(self.radius)) {
+            (Some(center), Some(radius)) => Some(Sphere::new(center, radius)),
+            _ => None,
+        }
+    }
+
+    // Convenience functions for common casts
+
+    /// Cast into an `f32` sphere.
+    #[inline]
+    pub fn to_f32(&self) -> Sphere<f32, U> {
+        self.cast()
+    }
+
+    /// Cast into an `f64` sphere.
+    #[inline]
+    pub fn to_f64(&self) -> Sphere<f64, U> {
+        self.cast()
+    }
+}
+
+impl<T: ApproxEq<T>, U> ApproxEq<T> for Sphere<T, U> {
+    #[inline]
+    fn approx_epsilon() -> T {
+        T::approx_epsilon()
+    }
+
+    #[inline]
+    fn approx_eq_eps(&self, other: &Self, eps: &T) -> bool {
+        self.center.x.approx_eq_eps(&other.center.x, eps)
+            && self.center.y.approx_eq_eps(&other.center.y, eps)
+            && self.center.z.approx_eq_eps(&other.center.z, eps)
+            && self.radius.approx_eq_eps(&other.radius, eps)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::approxeq::ApproxEq;
+    use crate::default::{Box3D, Point3D, Ray3D, Sphere};
+    use crate::{point3, vec3};
+
+    #[test]
+    fn test_contains_and_intersects() {
+        let s = Sphere::new(point3(1.0, 1.0, 1.0), 2.0);
+        assert!(s.contains(point3(1.0, 1.0, 1.0)));
+        assert!(s.contains(point3(1.0, 1.0, 3.0)));
+        assert!(!s.contains(point3(2.5, 2.5, 1.0)));
+
+        assert!(s.intersects(&Sphere::new(point3(1.0, 5.0, 1.0), 2.0)));
+        assert!(!s.intersects(&Sphere::new(point3(1.0, 5.0, 1.0), 1.5)));
+
+        assert_eq!(
+            s.bounding_box(),
+            Box3D::new(point3(-1.0, -1.0, -1.0), point3(3.0, 3.0, 3.0))
+        );
+
+        assert!(s.intersects_box(&Box3D::new(point3(2.0, 2.0, 0.0), point3(4.0, 4.0, 4.0))));
+        assert!(s.intersects_box(&Box3D::new(point3(-5.0, -5.0, -5.0), point3(5.0, 5.0, 5.0))));
+        // The box overlaps the bounding box but not the sphere.
+        assert!(!s.intersects_box(&Box3D::new(point3(2.2, 2.2, 2.2), point3(4.0, 4.0, 4.0))));
+        assert!(!s.intersects_box(&Box3D::new(point3(2.0, 2.0, 2.0), point3(1.0, 1.0, 1.0))));
+        assert!(!s.intersects_box(&Box3D::new(s.center, s.center)));
+    }
+
+    #[test]
+    fn test_intersect_ray() {
+        let s = Sphere::new(point3(0.0, 0.0, 5.0), 2.0);
+        let ray = |o: Point3D<f64>, d| Ray3D::new(o, d);
+        assert_eq!(s.intersect_ray(&ray(point3(0.0, 0.0, 0.0), vec3(0.0, 0.0, 1.0))), Some((3.0, 7.0)));
+        assert_eq!(s.intersect_ray(&ray(point3(0.0, 0.0, 5.0), vec3(0.0, 2.0, 0.0))), Some((0.0, 1.0)));
+        assert_eq!(s.intersect_ray(&ray(point3(0.0, 0.0, 0.0), vec3(0.0, 0.0, -1.0))), None);
+        assert_eq!(s.intersect_ray(&ray(point3(3.0, 0.0, 0.0), vec3(0.0, 0.0, 1.0))), None);
+    }
+
+    fn check_minimal_enclosing(points: &[Point3D<f64>]) {
+        let sphere = Sphere::minimal_enclosing(points).unwrap();
+        for p in points {
+            assert!((*p - sphere.center).length() <= sphere.radius + 1e-9);
+        }
+
+        // Compare with the brute force solution.
+        let mut best: Option<Sphere<f64>> = None;
+        let mut consider = |s: Sphere<f64>| {
+            if points.iter().all(|p| (*p - s.center).length() <= s.radius + 1e-9)
+                && best.map(|b| b.radius).unwrap_or(f64::INFINITY) > s.radius
+            {
+                best = Some(s);
+            }
+        };
+        for (i, &a) in points.iter().enumerate() {
+            consider(Sphere::new(a, 0.0));
+            for (j, &b) in points[..i].iter().enumerate() {
+                consider(Sphere::from_diameter(a, b));
+                for (k, &c) in points[..j].iter().enumerate() {
+                    consider(Sphere::from_three_points(a, b, c));
+                    for &d in &points[..k] {
+                        consider(Sphere::from_four_points(a, b, c, d));
+                    }
+                }
+            }
+        }
+
+        let best = best.unwrap();
+        assert!(sphere.radius.approx_eq(&best.radius), "{:?} {:?}", sphere, best);
+        assert!(sphere.center.approx_eq(&best.center), "{:?} {:?}", sphere, best);
+    }
+
+    #[test]
+    fn test_minimal_enclosing() {
+        assert_eq!(Sphere::<f64>::minimal_enclosing(&[]), None);
+
+        check_minimal_enclosing(&[point3(0.0, 0.0, 0.0), point3(2.0, 0.0, 0.0)]);
+        check_minimal_enclosing(&[
+            point3(0.0, 0.0, 0.0),
+            point3(4.0, 0.0, 0.0),
+            point3(2.0, 3.0, 0.0),
+        ]);
+        // A regular tetrahedron.
+        check_minimal_enclosing(&[
+            point3(1.0, 1.0, 1.0),
+            point3(1.0, -1.0, -1.0),
+            point3(-1.0, 1.0, -1.0),
+            point3(-1.0, -1.0, 1.0),
+        ]);
+        // Coplanar, aligned and duplicate points.
+        check_minimal_enclosing(&[
+            point3(0.0, 0.0, 0.0),
+            point3(1.0, 0.0, 0.0),
+            point3(0.0, 1.0, 0.0),
+            point3(1.0, 1.0, 0.0),
+            point3(1.0, 1.0, 0.0),
+            point3(2.0, 2.0, 0.0),
+        ]);
+        // The corners of a box.
+        let b = Box3D::new(point3(-1.0, 2.0, 3.0), point3(4.0, 5.0, 6.0));
+        let mut corners = Vec::new();
+        for &x in &[b.min.x, b.max.x] {
+            for &y in &[b.min.y, b.max.y] {
+                for &z in &[b.min.z, b.max.z] {
+                    corners.push(point3(x, y, z));
+                }
+            }
+        }
+        check_minimal_enclosing(&corners);
+        let s = Sphere::minimal_enclosing(&corners).unwrap();
+        assert!(s.center.approx_eq(&b.center()));
+        // Pseudo-random points.
+        let mut seed = 54321u32;
+        let mut next = || {
+            seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+            (seed >> 8) as f64 / (1 << 24) as f64 * 10.0
+        };
+        let points: Vec<_> = (0..20).map(|_| point3(next(), next(), next())).collect();
+        check_minimal_enclosing(&points);
+    }
+}
@@ -0,0 +1,149 @@
+// Copyright 2013 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Splitting of a range into fixed-size tiles along one axis, shared by the tiling of
+//! boxes.
+
+use crate::approxord::{max, min};
+use crate::num::Floor;
+use num_traits::NumCast;
+
+/// The largest index of a tile, so that the index past it still fits in an `i32`.
+const MAX_INDEX: i32 = i32::MAX - 1;
+
+/// The tiles of a range, starting at its minimum and clipped at its maximum.
+///
+/// Tile `i` starts at `min + i * size` and ends where the next one starts, so
+/// that the tiles always share their edges exactly, even with floating point
+/// coordinates. The positions of the tiles are computed with `f64`, so that they
+/// can't overflow integer coordinates, and tiles past `i32::MAX` are dropped.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct TileAxis<T> {
+    min: T,
+    max: T,
+    size: T,
+}
+
+impl<T: Copy + NumCast + PartialOrd> TileAxis<T> {
+    pub(crate) fn new(min: T, max: T, size: T) -> Self {
+        TileAxis { min, max, size }
+    }
+
+    /// Returns the start of a tile before clipping, or `None` if it is past the
+    /// maximum of the range.
+    fn start(&self, index: i32) -> Option<T> {
+        let start = self.min.to_f64().unwrap() + self.size.to_f64().unwrap() * index as f64;
+        T::from(start).filter(|start| *start <= self.max)
+    }
+
+    /// Returns the start and end of a tile, clipped to the range.
+    pub(crate) fn tile(&self, index: i32) -> (T, T) {
+        let end = self.start(index + 1).unwrap_or(self.max);
+        (self.start(index).unwrap_or(self.max), end)
+    }
+
+    /// Returns the index of the tile containing a coordinate of the range, up to
+    /// `MAX_INDEX`.
+    fn index_of(&self, x: T) -> i32 {
+        let offset = x.to_f64().unwrap() - self.min.to_f64().unwrap();
+        let estimate = Floor::floor(offset / self.size.to_f64().unwrap());
+        // The conversion saturates, and maps NaN to 0.
+        let mut index = estimate.min(MAX_INDEX as f64) as i32;
+        // Correct rounding errors of the division, so that the index agrees with
+        // the edges of the tiles.
+        while index < MAX_INDEX && matches!(self.start(index + 1), Some(s) if s <= x) {
+            index += 1;
+        }
+        while index > 0 && !matches!(self.start(index), Some(s) if s <= x) {
+            index -= 1;
+        }
+        index
+    }
+
+    /// Returns the range of the indices of the tiles overlapping a range, which is
+    /// empty if the ranges don't overlap or if the size of the tiles isn't positive.
+    pub(crate) fn range(&self, start: T, end: T) -> (i32, i32) {
+        let start = max(start, self.min);
+        let end = min(end, self.max);
+        let size = self.size.to_f64().unwrap();
+        if end <= start || size.is_nan() || size <= 0.0 {
+            return (0, 0);
+        }
+
+        let first = self.index_of(start);
+        let last = self.index_of(end);
+        if matches!(self.start(last), Some(s) if s < end) {
+            (first, last + 1)
+        } else {
+            (first, last)
+        }
+    }
+
+    /// Returns the number of tiles in the range.
+    pub(crate) fn count(&self) -> i32 {
+        self.range(self.min, self.max).1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TileAxis;
+
+    #[test]
+    fn test_int() {
+        let axis = TileAxis::new(-5, 20, 10);
+        assert_eq!(axis.count(), 3);
+        assert_eq!(axis.tile(0), (-5, 5));
+        assert_eq!(axis.tile(2), (15, 20));
+        assert_eq!(axis.range(5, 15), (1, 2));
+        assert_eq!(axis.range(4, 16), (0, 3));
+        assert_eq!(axis.range(-100, -5), (0, 0));
+        assert_eq!(axis.range(20, 100), (0, 0));
+        assert_eq!(TileAxis::new(0, 20, 10).count(), 2);
+        assert_eq!(TileAxis::new(0, 0, 10).count(), 0);
+        assert_eq!(TileAxis::new(0, 20, 0).count(), 0);
+        assert_eq!(TileAxis::new(0, 20, -10).range(5, 15), (0, 0));
+    }
+
+    #[test]
+    fn test_float() {
+        // 0.1 * 3 is slightly more than 0.3, so that 0.3 is in the third tile.
+        let axis = TileAxis::new(0.0, 1.0, 0.1);
+        assert_eq!(axis.count(), 10);
+        assert_eq!(axis.tile(9).1, 1.0);
+        let (first, end) = axis.range(0.3, 0.7);
+        for i in first..end {
+            let (start, end) = axis.tile(i);
+            assert!(start < 0.7 && end > 0.3);
+        }
+        assert!(axis.tile(first - 1).1 <= 0.3);
+        assert!(axis.tile(end).0 >= 0.7);
+
+        let axis = TileAxis::new(0.5, 2.0, 0.5);
+        assert_eq!(axis.count(), 3);
+        assert_eq!(axis.range(1.0, 1.5), (1, 2));
+        assert_eq!(axis.range(0.9, 1.6), (0, 3));
+        assert_eq!(TileAxis::new(0.0, 1.0, f64::NAN).count(), 0);
+    }
+
+    #[test]
+    fn test_large() {
+        // The coordinates of the tiles don't fit in an i32.
+        let axis = TileAxis::new(-2_000_000_000, 2_000_000_000, 256);
+        assert_eq!(axis.count(), 15_625_000);
+        assert_eq!(axis.tile(15_624_999), (1_999_999_744, 2_000_000_000));
+        assert_eq!(axis.range(-1, 1), (7_812_499, 7_812_501));
+
+        // The tiles past i32::MAX are dropped.
+        let axis = TileAxis::new(0.0, 1e12, 256.0);
+        assert_eq!(axis.count(), i32::MAX);
+        assert_eq!(axis.range(1e11, 1e12), (390_625_000, i32::MAX));
+        assert_eq!(axis.tile(i32::MAX - 1), (549_755_813_376.0, 549_755_813_632.0));
+    }
+}
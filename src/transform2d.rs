@@ -18,6 +18,7 @@ use crate::vector::{Vector2D, vec2};
 use crate::rect::Rect;
 use crate::box2d::Box2D;
 use crate::transform3d::Transform3D;
+use crate::decomposition::Decomposed2D;
 use core::ops::{Add, Mul, Div, Sub};
 use core::marker::PhantomData;
 use core::cmp::{Eq, PartialEq};
@@ -26,6 +27,7 @@ use crate::approxeq::ApproxEq;
 use crate::trig::Trig;
 use core::fmt;
 use num_traits::NumCast;
+use num_traits::real::Real;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 #[cfg(feature = "bytemuck")]
@@ -612,6 +614,83 @@ where
     }
 }
 
+impl<T: Real, Src, Dst> Transform2D<T, Src, Dst> {
+    /// Decomposes the transform into a translation, a rotation, a non-uniform scale
+    /// and a skew along the x axis.
+    ///
+    /// The scale is applied first, followed by the skew, the rotation and finally the
+    /// translation, see [`Decomposed2D`](struct.Decomposed2D.html).
+    ///
+    /// The decomposition is unique with the following conventions:
+    ///
+    /// - `scale.x` is always positive and the angle is in the `]-PI, PI]` range.
+    /// - Transforms with a negative determinant contain a reflection, which is
+    ///   represented by a negative `scale.y`. For example a `scale(-1.0, 1.0)`
+    ///   transform decomposes into a rotation of `PI` and a scale of `(1.0, -1.0)`.
+    ///
+    /// Returns `None` if the transform is not invertible.
+    pub fn decompose(&self) -> Option<Decomposed2D<T, Src, Dst>> {
+        let zero = T::zero();
+
+        let row0 = Vector2D::<T, UnknownUnit>::new(self.m11, self.m12);
+        let row1 = Vector2D::<T, UnknownUnit>::new(self.m21, self.m22);
+
+        let sx = row0.length();
+        if sx == zero {
+            return None;
+        }
+        let row0 = row0 / sx;
+
+        // Make the second row orthogonal to the first one, the removed part is the skew.
+        let shear = row0.dot(row1);
+        let row1 = row1 - row0 * shear;
+        let sy = row1.length();
+        if sy == zero {
+            return None;
+        }
+
+        // The rows of a rotation matrix have a positive cross product, otherwise
+        // the transform is a reflection.
+        let sy = if row0.cross(row1) < zero { -sy } else { sy };
+
+        Some(Decomposed2D::new(
+            vec2(self.m31, self.m32),
+            Angle::radians(self.m12.atan2(self.m11)),
+            vec2(sx, sy),
+            shear / sy,
+        ))
+    }
+
+    /// Builds a transform from its decomposed components.
+    ///
+    /// This is the inverse of [`decompose`](#method.decompose).
+    pub fn from_decomposed(decomposed: &Decomposed2D<T, Src, Dst>) -> Self {
+        let (sin, cos) = decomposed.angle.sin_cos();
+        let scale = decomposed.scale;
+        let skew = decomposed.skew;
+        let translation = decomposed.translation;
+
+        Transform2D::new(
+            scale.x * cos,
+            scale.x * sin,
+            scale.y * (skew * cos - sin),
+            scale.y * (skew * sin + cos),
+            translation.x,
+            translation.y,
+        )
+    }
+}
+
+impl<T: Real + ApproxEq<T>, Src, Dst> Transform2D<T, Src, Dst> {
+    /// Interpolates between two transforms, following the CSS rules for matrix
+    /// interpolation.
+    ///
+    /// See [`Transform3D::interpolate`](struct.Transform3D.html#method.interpolate).
+    pub fn interpolate(&self, other: &Self, t: T) -> Self {
+        self.to_3d().interpolate(&other.to_3d(), t).to_2d()
+    }
+}
+
 impl <T, Src, Dst> Default for Transform2D<T, Src, Dst>
     where T: Zero + One
 {
@@ -797,6 +876,24 @@ mod test {
         assert_eq!(v1, m1.transform_vector(v1));
     }
 
+    #[test]
+    pub fn test_interpolate() {
+        let from = Mat::scale(2.0, 2.0);
+        let to = Mat::rotation(rad(FRAC_PI_2)).then_translate(vec2(10.0, 0.0));
+
+        assert!(from.interpolate(&to, 0.0).approx_eq(&from));
+        assert!(from.interpolate(&to, 1.0).approx_eq(&to));
+
+        let expected = Mat::scale(1.5, 1.5)
+            .then_rotate(rad(FRAC_PI_2 / 2.0))
+            .then_translate(vec2(5.0, 0.0));
+        assert!(from.interpolate(&to, 0.5).approx_eq(&expected));
+
+        let singular = Mat::scale(0.0, 0.0);
+        assert_eq!(from.interpolate(&singular, 0.4), from);
+        assert_eq!(from.interpolate(&singular, 0.6), singular);
+    }
+
     #[cfg(feature = "mint")]
     #[test]
     pub fn test_mint() {
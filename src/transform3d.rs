@@ -11,13 +11,21 @@
 
 use super::{UnknownUnit, Angle};
 use crate::approxeq::ApproxEq;
+use crate::decomposition::Decomposed3D;
 use crate::homogen::HomogeneousVector;
+#[cfg(feature = "alloc")]
+use crate::polygon::{clip_polygon, Polygon2D};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 #[cfg(feature = "mint")]
 use mint;
 use crate::trig::Trig;
+use crate::rotation::Rotation3D;
 use crate::point::{Point2D, point2, Point3D, point3};
 use crate::vector::{Vector2D, Vector3D, vec2, vec3};
 use crate::rect::Rect;
+use crate::plane::Plane3D;
+use crate::ray::Ray3D;
 use crate::box2d::Box2D;
 use crate::box3d::Box3D;
 use crate::transform2d::Transform2D;
@@ -29,6 +37,7 @@ use core::fmt;
 use core::cmp::{Eq, PartialEq};
 use core::hash::{Hash};
 use num_traits::NumCast;
+use num_traits::real::Real;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 #[cfg(feature = "bytemuck")]
@@ -772,6 +781,22 @@ where
         )
     }
 
+    /// Returns the given ray transformed by this transform, if the transform makes sense,
+    /// or `None` otherwise.
+    ///
+    /// The direction of the resulting ray is computed from the transformed origin and
+    /// the transformed point at `origin + direction`, so the parametrization of the ray
+    /// is only preserved by affine transforms.
+    #[inline]
+    pub fn transform_ray(&self, ray: &Ray3D<T, Src>) -> Option<Ray3D<T, Dst>>
+    where
+        T: Sub<Output = T> + Div<Output = T> + Zero + PartialOrd,
+    {
+        let origin = self.transform_point3d(ray.origin)?;
+        let to = self.transform_point3d(ray.origin + ray.direction)?;
+        Some(Ray3D::new(origin, to - origin))
+    }
+
     /// Returns a rectangle that encompasses the result of transforming the given rectangle by this
     /// transform, if the transform makes sense for it, or `None` otherwise.
     pub fn outer_transformed_rect(&self, rect: &Rect<T, Src>) -> Option<Rect<T, Dst>>
@@ -819,6 +844,107 @@ where
             self.transform_point3d(point3(b.max.x, b.max.y, b.max.z))?,
         ]))
     }
+
+    /// Transforms the given 2d box as a polygon, clipping it in homogeneous space
+    /// against the `w = epsilon` plane before the perspective divide.
+    ///
+    /// Unlike [`outer_transformed_box2d`], this works when the box is only partially
+    /// in front of the projection plane. Returns `None` if the box is entirely behind it.
+    ///
+    /// [`outer_transformed_box2d`]: #method.outer_transformed_box2d
+    pub fn transform_box2d_clipped(&self, b: &Box2D<T, Src>) -> Option<ClippedQuad<T, Dst>>
+    where
+        T: Sub<Output = T> + Div<Output = T> + Zero + PartialOrd + ApproxEq<T>,
+    {
+        let epsilon = T::approx_epsilon();
+        let corners = [
+            self.transform_point2d_homogeneous(b.min),
+            self.transform_point2d_homogeneous(point2(b.max.x, b.min.y)),
+            self.transform_point2d_homogeneous(b.max),
+            self.transform_point2d_homogeneous(point2(b.min.x, b.max.y)),
+        ];
+
+        let mut result = ClippedQuad {
+            points: [Point2D::new(T::zero(), T::zero()); 5],
+            len: 0,
+        };
+        for i in 0..4 {
+            let current = corners[i];
+            let next = corners[(i + 1) % 4];
+            let current_inside = current.w >= epsilon;
+            if current_inside {
+                result.push(point2(current.x / current.w, current.y / current.w));
+            }
+            if current_inside != (next.w >= epsilon) {
+                let t = (epsilon - current.w) / (next.w - current.w);
+                let x = current.x + (next.x - current.x) * t;
+                let y = current.y + (next.y - current.y) * t;
+                result.push(point2(x / epsilon, y / epsilon));
+            }
+        }
+
+        if result.len == 0 {
+            return None;
+        }
+
+        Some(result)
+    }
+
+    /// Returns a 2d box that encompasses the result of transforming the given box by this
+    /// transform, clipping the parts that are behind the projection plane instead of
+    /// giving up. Returns `None` if the box is entirely behind the projection plane.
+    ///
+    /// See [`transform_box2d_clipped`](#method.transform_box2d_clipped).
+    #[inline]
+    pub fn outer_transformed_box2d_clipped(&self, b: &Box2D<T, Src>) -> Option<Box2D<T, Dst>>
+    where
+        T: Sub<Output = T> + Div<Output = T> + Zero + PartialOrd + ApproxEq<T>,
+    {
+        self.transform_box2d_clipped(b).map(|quad| quad.bounding_box())
+    }
+
+    /// Transforms the vertices of a 2d polygon into homogeneous coordinates and clips
+    /// the parts that are behind the projection plane, before the perspective divide.
+    ///
+    /// All of the returned vertices have a positive `w`, and can be clipped further
+    /// in homogeneous space (for example against the sides of the view volume) before
+    /// being divided. The result is empty if the polygon is entirely behind the
+    /// projection plane.
+    #[cfg(feature = "alloc")]
+    pub fn transform_polygon_homogeneous_clipped(
+        &self,
+        polygon: &Polygon2D<T, Src>,
+    ) -> Vec<HomogeneousVector<T, Dst>>
+    where
+        T: Sub<Output = T> + Div<Output = T> + Zero + PartialOrd + ApproxEq<T>,
+    {
+        let epsilon = T::approx_epsilon();
+        let points: Vec<_> = polygon
+            .points
+            .iter()
+            .map(|&p| self.transform_point2d_homogeneous(p))
+            .collect();
+
+        clip_polygon(&points, |v| v.w - epsilon, |a, b, t| {
+            let lerp = |a: T, b: T| a + (b - a) * t;
+            HomogeneousVector::new(lerp(a.x, b.x), lerp(a.y, b.y), lerp(a.z, b.z), epsilon)
+        })
+    }
+
+    /// Transforms a 2d polygon by this transform, clipping the parts that are behind
+    /// the projection plane instead of giving up.
+    ///
+    /// See [`transform_polygon_homogeneous_clipped`](#method.transform_polygon_homogeneous_clipped).
+    #[cfg(feature = "alloc")]
+    pub fn transform_polygon_clipped(&self, polygon: &Polygon2D<T, Src>) -> Polygon2D<T, Dst>
+    where
+        T: Sub<Output = T> + Div<Output = T> + Zero + PartialOrd + ApproxEq<T>,
+    {
+        self.transform_polygon_homogeneous_clipped(polygon)
+            .into_iter()
+            .filter_map(|v| v.to_point2d())
+            .collect()
+    }
 }
 
 
@@ -948,6 +1074,143 @@ where T: Copy +
         Some(m.mul_s(_1 / det))
     }
 
+    /// Returns the given plane transformed by this transform, or `None` if the
+    /// transform is not invertible.
+    ///
+    /// Planes are transformed by the inverse-transpose of the matrix, which keeps
+    /// the normal orthogonal to the plane under non-uniform scales and skews. The
+    /// normal of the resulting plane is normalized.
+    pub fn transform_plane(&self, plane: &Plane3D<T, Src>) -> Option<Plane3D<T, Dst>>
+    where
+        T: Real,
+    {
+        let inv = self.inverse()?;
+        let (a, b, c, d) = (plane.normal.x, plane.normal.y, plane.normal.z, plane.offset);
+        Plane3D::new(
+            vec3(
+                inv.m11 * a + inv.m12 * b + inv.m13 * c + inv.m14 * d,
+                inv.m21 * a + inv.m22 * b + inv.m23 * c + inv.m24 * d,
+                inv.m31 * a + inv.m32 * b + inv.m33 * c + inv.m34 * d,
+            ),
+            inv.m41 * a + inv.m42 * b + inv.m43 * c + inv.m44 * d,
+        )
+        .try_normalize()
+    }
+
+    /// Decomposes the transform into scale, skew, rotation, translation and perspective
+    /// components, following the [CSS Transforms Level 2] specification.
+    ///
+    /// Returns `None` if the transform can't be decomposed, which is the case when its
+    /// upper 3x3 part is not invertible or when `m44` is zero.
+    ///
+    /// [CSS Transforms Level 2]: https://drafts.csswg.org/css-transforms-2/#decomposing-a-3d-matrix
+    pub fn decompose(&self) -> Option<Decomposed3D<T, Src, Dst>>
+    where
+        T: Real,
+    {
+        let zero: T = Zero::zero();
+        let one: T = One::one();
+
+        if self.m44 == zero {
+            return None;
+        }
+        let m = self.mul_s(one / self.m44);
+
+        // The perspective-free part of the matrix must be invertible.
+        let mut perspective_matrix = m;
+        perspective_matrix.m14 = zero;
+        perspective_matrix.m24 = zero;
+        perspective_matrix.m34 = zero;
+        perspective_matrix.m44 = one;
+        if perspective_matrix.determinant() == zero {
+            return None;
+        }
+
+        let perspective = if m.m14 != zero || m.m24 != zero || m.m34 != zero {
+            // Solve `perspective_matrix * perspective = last column of m`.
+            let inv = perspective_matrix.inverse()?;
+            let (x, y, z, w) = (m.m14, m.m24, m.m34, m.m44);
+            HomogeneousVector::new(
+                inv.m11 * x + inv.m12 * y + inv.m13 * z + inv.m14 * w,
+                inv.m21 * x + inv.m22 * y + inv.m23 * z + inv.m24 * w,
+                inv.m31 * x + inv.m32 * y + inv.m33 * z + inv.m34 * w,
+                inv.m41 * x + inv.m42 * y + inv.m43 * z + inv.m44 * w,
+            )
+        } else {
+            HomogeneousVector::new(zero, zero, zero, one)
+        };
+
+        let translation = vec3(m.m41, m.m42, m.m43);
+
+        let row0: Vector3D<T, UnknownUnit> = vec3(m.m11, m.m12, m.m13);
+        let row1: Vector3D<T, UnknownUnit> = vec3(m.m21, m.m22, m.m23);
+        let row2: Vector3D<T, UnknownUnit> = vec3(m.m31, m.m32, m.m33);
+
+        // Orthonormalize the rows, extracting the scale and skew factors on the way.
+        let scale_x = row0.length();
+        let row0 = row0 / scale_x;
+
+        let skew_xy = row0.dot(row1);
+        let row1 = row1 - row0 * skew_xy;
+        let scale_y = row1.length();
+        let row1 = row1 / scale_y;
+
+        let skew_xz = row0.dot(row2);
+        let row2 = row2 - row0 * skew_xz;
+        let skew_yz = row1.dot(row2);
+        let row2 = row2 - row1 * skew_yz;
+        let scale_z = row2.length();
+        let row2 = row2 / scale_z;
+
+        let mut scale = vec3(scale_x, scale_y, scale_z);
+        let skew = vec3(skew_xy / scale_y, skew_xz / scale_z, skew_yz / scale_z);
+
+        // If the coordinate system is flipped, negate the matrix and the scale factors.
+        let (row0, row1, row2) = if row0.dot(row1.cross(row2)) < zero {
+            scale = -scale;
+            (-row0, -row1, -row2)
+        } else {
+            (row0, row1, row2)
+        };
+
+        let rotation = Rotation3D::from_orthonormal_rows(row0, row1, row2);
+
+        Some(Decomposed3D {
+            scale,
+            skew,
+            rotation,
+            translation,
+            perspective,
+        })
+    }
+
+    /// Interpolates between two transforms, following the [CSS Transforms Level 2]
+    /// rules for matrix interpolation.
+    ///
+    /// Both transforms are decomposed (see [`decompose`]), their components are
+    /// interpolated and the result is recomposed. If either transform can't be
+    /// decomposed, the interpolation is discrete: `self` is returned if `t` is less
+    /// than one half, and `other` otherwise.
+    ///
+    /// [CSS Transforms Level 2]: https://drafts.csswg.org/css-transforms-2/#interpolation-of-3d-matrices
+    /// [`decompose`]: #method.decompose
+    pub fn interpolate(&self, other: &Self, t: T) -> Self
+    where
+        T: Real + ApproxEq<T>,
+    {
+        match (self.decompose(), other.decompose()) {
+            (Some(from), Some(to)) => from.lerp(&to, t).recompose(),
+            _ => {
+                let one: T = One::one();
+                if t < one / (one + one) {
+                    *self
+                } else {
+                    *other
+                }
+            }
+        }
+    }
+
     /// Compute the determinant of the transform.
     pub fn determinant(&self) -> T {
         self.m14 * self.m23 * self.m32 * self.m41 -
@@ -993,6 +1256,187 @@ where T: Copy +
     }
 }
 
+/// The handedness of a view space, used by the camera helpers of [`Transform3D`].
+///
+/// [`Transform3D`]: struct.Transform3D.html
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Handedness {
+    /// The camera looks towards negative z, with x pointing right and y pointing up.
+    ///
+    /// This is the OpenGL convention.
+    RightHanded,
+    /// The camera looks towards positive z, with x pointing right and y pointing up.
+    ///
+    /// This is the Direct3D convention.
+    LeftHanded,
+}
+
+/// The depth range of normalized device coordinates produced by the projections of
+/// [`Transform3D`].
+///
+/// [`Transform3D`]: struct.Transform3D.html
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DepthRange {
+    /// Depth goes from zero to one, as in Direct3D, Metal and Vulkan.
+    ZeroToOne,
+    /// Depth goes from minus one to one, as in OpenGL.
+    NegativeOneToOne,
+}
+
+impl<T: Real, Src, Dst> Transform3D<T, Src, Dst> {
+    /// Create a view transform for a camera at `eye` looking at `target`.
+    ///
+    /// The resulting view space has the camera at its origin, with y pointing in the
+    /// direction closest to `up` and the camera looking towards negative z for right
+    /// handed view spaces or positive z for left handed ones.
+    ///
+    /// `up` must not be parallel to the viewing direction.
+    pub fn look_at(
+        eye: Point3D<T, Src>,
+        target: Point3D<T, Src>,
+        up: Vector3D<T, Src>,
+        handedness: Handedness,
+    ) -> Self {
+        let forward = (target - eye).normalize();
+        let z = match handedness {
+            Handedness::RightHanded => -forward,
+            Handedness::LeftHanded => forward,
+        };
+        let x = up.cross(z).normalize();
+        let y = z.cross(x);
+        let eye = eye.to_vector();
+
+        let zero: T = Zero::zero();
+        let one: T = One::one();
+        Transform3D::new(
+            x.x, y.x, z.x, zero,
+            x.y, y.y, z.y, zero,
+            x.z, y.z, z.z, zero,
+            -eye.dot(x), -eye.dot(y), -eye.dot(z), one,
+        )
+    }
+
+    /// Create a perspective projection transform from a vertical field of view.
+    ///
+    /// `aspect` is the ratio of the width over the height of the viewport, `near` and
+    /// `far` are the positive distances to the clipping planes. Points on the near
+    /// plane are mapped to the lower end of `depth_range` and points on the far plane
+    /// to the upper end.
+    pub fn perspective_fov(
+        fovy: Angle<T>,
+        aspect: T,
+        near: T,
+        far: T,
+        handedness: Handedness,
+        depth_range: DepthRange,
+    ) -> Self {
+        let (low, high) = depth_range.bounds();
+        Self::perspective_fov_with_depth(fovy, aspect, near, Some(far), handedness, low, high)
+    }
+
+    /// Create a perspective projection transform with the far plane at infinity.
+    ///
+    /// See [`perspective_fov`](#method.perspective_fov).
+    pub fn perspective_fov_infinite(
+        fovy: Angle<T>,
+        aspect: T,
+        near: T,
+        handedness: Handedness,
+        depth_range: DepthRange,
+    ) -> Self {
+        let (low, high) = depth_range.bounds();
+        Self::perspective_fov_with_depth(fovy, aspect, near, None, handedness, low, high)
+    }
+
+    /// Create a perspective projection transform with a reversed depth: points on the
+    /// near plane are mapped to the upper end of `depth_range` and points on the far
+    /// plane to the lower end.
+    ///
+    /// Combined with [`DepthRange::ZeroToOne`] and a floating point depth buffer, this
+    /// distributes the depth precision much more evenly.
+    ///
+    /// See [`perspective_fov`](#method.perspective_fov).
+    ///
+    /// [`DepthRange::ZeroToOne`]: enum.DepthRange.html#variant.ZeroToOne
+    pub fn perspective_fov_reversed_z(
+        fovy: Angle<T>,
+        aspect: T,
+        near: T,
+        far: T,
+        handedness: Handedness,
+        depth_range: DepthRange,
+    ) -> Self {
+        let (low, high) = depth_range.bounds();
+        Self::perspective_fov_with_depth(fovy, aspect, near, Some(far), handedness, high, low)
+    }
+
+    /// Create a perspective projection transform with a reversed depth and the far
+    /// plane at infinity.
+    ///
+    /// See [`perspective_fov_reversed_z`](#method.perspective_fov_reversed_z).
+    pub fn perspective_fov_infinite_reversed_z(
+        fovy: Angle<T>,
+        aspect: T,
+        near: T,
+        handedness: Handedness,
+        depth_range: DepthRange,
+    ) -> Self {
+        let (low, high) = depth_range.bounds();
+        Self::perspective_fov_with_depth(fovy, aspect, near, None, handedness, high, low)
+    }
+
+    /// Perspective projection mapping the near plane to the depth `depth_near` and the
+    /// far plane (at infinity if `None`) to the depth `depth_far`.
+    fn perspective_fov_with_depth(
+        fovy: Angle<T>,
+        aspect: T,
+        near: T,
+        far: Option<T>,
+        handedness: Handedness,
+        depth_near: T,
+        depth_far: T,
+    ) -> Self {
+        let zero: T = Zero::zero();
+        let one: T = One::one();
+        let two = one + one;
+
+        let f = one / (fovy.get() / two).tan();
+
+        // With `d` the distance to the camera, the normalized depth is `a + b / d`.
+        let (a, b) = match far {
+            Some(far) => (
+                (depth_far * far - depth_near * near) / (far - near),
+                (depth_near - depth_far) * near * far / (far - near),
+            ),
+            None => (depth_far, (depth_near - depth_far) * near),
+        };
+
+        // The distance to the camera is `-z` for right handed view spaces.
+        let sign = match handedness {
+            Handedness::RightHanded => -one,
+            Handedness::LeftHanded => one,
+        };
+
+        Transform3D::new(
+            f / aspect, zero, zero, zero,
+            zero, f, zero, zero,
+            zero, zero, a * sign, sign,
+            zero, zero, b, zero,
+        )
+    }
+}
+
+impl DepthRange {
+    fn bounds<T: Real>(self) -> (T, T) {
+        match self {
+            DepthRange::ZeroToOne => (T::zero(), T::one()),
+            DepthRange::NegativeOneToOne => (-T::one(), T::one()),
+        }
+    }
+}
+
 impl <T, Src, Dst> Transform3D<T, Src, Dst>
 where
     T: Copy + Mul<Output = T> + Div<Output = T> + Zero + One + PartialEq,
@@ -1153,6 +1597,72 @@ impl<T, Src, Dst> From<Transform3D<T, Src, Dst>> for mint::RowMatrix4<T> {
 }
 
 
+/// A 2d box transformed by a `Transform3D` and clipped against the projection plane.
+///
+/// Clipping a quadrilateral against a plane can add one vertex, so this holds up
+/// to five points. See [`Transform3D::transform_box2d_clipped`].
+///
+/// [`Transform3D::transform_box2d_clipped`]: struct.Transform3D.html#method.transform_box2d_clipped
+pub struct ClippedQuad<T, U> {
+    points: [Point2D<T, U>; 5],
+    len: usize,
+}
+
+impl<T: Copy, U> Copy for ClippedQuad<T, U> {}
+
+impl<T: Copy, U> Clone for ClippedQuad<T, U> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: PartialEq, U> PartialEq for ClippedQuad<T, U> {
+    fn eq(&self, other: &Self) -> bool {
+        self.points() == other.points()
+    }
+}
+
+impl<T: fmt::Debug, U> fmt::Debug for ClippedQuad<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.points()).finish()
+    }
+}
+
+impl<T, U> ClippedQuad<T, U> {
+    /// The vertices of the clipped polygon, in the same winding order as the
+    /// corners of the original box.
+    #[inline]
+    pub fn points(&self) -> &[Point2D<T, U>] {
+        &self.points[..self.len]
+    }
+
+    /// The number of vertices, between 3 and 5.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Always `false`: a box that is entirely clipped produces no `ClippedQuad`.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline]
+    fn push(&mut self, p: Point2D<T, U>) {
+        self.points[self.len] = p;
+        self.len += 1;
+    }
+}
+
+impl<T: Copy + Zero + PartialOrd, U> ClippedQuad<T, U> {
+    /// Returns the smallest box containing all of the vertices.
+    #[inline]
+    pub fn bounding_box(&self) -> Box2D<T, U> {
+        Box2D::from_points(self.points())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::approxeq::ApproxEq;
@@ -1424,6 +1934,230 @@ mod tests {
         assert_eq!(None, m.transform_point2d(p));
     }
 
+    #[test]
+    pub fn test_transform_box2d_clipped() {
+        let b = default::Box2D::new(point2(-10.0, -10.0), point2(10.0, 10.0));
+
+        // Entirely in front of the projection plane: same as the unclipped version.
+        let m = Mf32::rotation(0.0, 1.0, 0.0, rad(0.5)).then(&Mf32::perspective(100.0));
+        assert_eq!(
+            m.outer_transformed_box2d_clipped(&b),
+            m.outer_transformed_box2d(&b),
+        );
+        assert_eq!(m.transform_box2d_clipped(&b).unwrap().len(), 4);
+
+        // Half of the box is behind the projection plane.
+        let m = Mf32::rotation(0.0, 1.0, 0.0, rad(FRAC_PI_2))
+            .then_translate(vec3(0.0, 0.0, 95.0))
+            .then(&Mf32::perspective(100.0));
+        assert_eq!(m.outer_transformed_box2d(&b), None);
+        let quad = m.transform_box2d_clipped(&b).unwrap();
+        assert_eq!(quad.len(), 4);
+        let clipped = quad.bounding_box();
+        // The box is seen edge-on, and the part of it that is close to the
+        // projection plane is projected very far away.
+        assert!(clipped.min.x.abs() < 1.0 && clipped.max.x.abs() < 1.0);
+        assert!(clipped.max.y > 1000.0);
+        assert!(clipped.min.y < -1000.0);
+        // The corners that are in front of the projection plane are preserved.
+        assert!(quad.points().iter().any(|p| p.y.approx_eq_eps(&(-10.0 / 0.15), &0.01)));
+        assert!(quad.points().iter().any(|p| p.y.approx_eq_eps(&(10.0 / 0.15), &0.01)));
+
+        // Only one corner is behind the projection plane, so clipping cuts it off
+        // and replaces it with two points.
+        let m = Mf32::rotation(0.0, 1.0, 1.0, rad(FRAC_PI_2))
+            .then_translate(vec3(0.0, 0.0, 92.0))
+            .then(&Mf32::perspective(100.0));
+        let quad = m.transform_box2d_clipped(&b).unwrap();
+        assert_eq!(quad.len(), 5);
+
+        // Entirely behind the projection plane.
+        let m = Mf32::translation(0.0, 0.0, 200.0).then(&Mf32::perspective(100.0));
+        assert_eq!(m.transform_box2d_clipped(&b), None);
+        assert_eq!(m.outer_transformed_box2d_clipped(&b), None);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    pub fn test_transform_polygon_clipped() {
+        let b = default::Box2D::new(point2(-10.0, -10.0), point2(10.0, 10.0));
+        let polygon: default::Polygon2D<f32> = vec![
+            b.min,
+            point2(b.max.x, b.min.y),
+            b.max,
+            point2(b.min.x, b.max.y),
+        ].into();
+
+        // Same results as the specialized version for boxes.
+        let transforms = [
+            Mf32::rotation(0.0, 1.0, 0.0, rad(0.5)).then(&Mf32::perspective(100.0)),
+            Mf32::rotation(0.0, 1.0, 0.0, rad(FRAC_PI_2))
+                .then_translate(vec3(0.0, 0.0, 95.0))
+                .then(&Mf32::perspective(100.0)),
+            Mf32::rotation(0.0, 1.0, 1.0, rad(FRAC_PI_2))
+                .then_translate(vec3(0.0, 0.0, 92.0))
+                .then(&Mf32::perspective(100.0)),
+        ];
+        for m in &transforms {
+            let homogeneous = m.transform_polygon_homogeneous_clipped(&polygon);
+            assert!(homogeneous.iter().all(|v| v.w > 0.0));
+
+            let clipped = m.transform_polygon_clipped(&polygon);
+            let quad = m.transform_box2d_clipped(&b).unwrap();
+            assert_eq!(clipped.points.len(), quad.len());
+            for (a, b) in clipped.points.iter().zip(quad.points()) {
+                assert!(a.approx_eq(b));
+            }
+        }
+
+        // Entirely behind the projection plane.
+        let m = Mf32::translation(0.0, 0.0, 200.0).then(&Mf32::perspective(100.0));
+        assert!(m.transform_polygon_clipped(&polygon).is_empty());
+    }
+
+    #[test]
+    pub fn test_interpolate() {
+        let from = Mf32::translation(10.0, 0.0, 0.0);
+        let to = Mf32::rotation(0.0, 0.0, 1.0, rad(FRAC_PI_2)).then_translate(vec3(0.0, 20.0, 0.0));
+
+        assert!(from.interpolate(&to, 0.0).approx_eq(&from));
+        assert!(from.interpolate(&to, 1.0).approx_eq(&to));
+
+        // The rotation is interpolated as a rotation instead of collapsing the matrix.
+        let expected = Mf32::rotation(0.0, 0.0, 1.0, rad(FRAC_PI_2 / 2.0))
+            .then_translate(vec3(5.0, 10.0, 0.0));
+        assert!(from.interpolate(&to, 0.5).approx_eq(&expected));
+
+        // Singular matrices can't be decomposed, the interpolation is discrete.
+        let singular = Mf32::scale(0.0, 1.0, 1.0);
+        assert_eq!(from.interpolate(&singular, 0.25), from);
+        assert_eq!(from.interpolate(&singular, 0.75), singular);
+        assert_eq!(singular.interpolate(&from, 0.5), from);
+    }
+
+    #[test]
+    pub fn test_transform_ray() {
+        let ray = default::Ray3D::new(point3(1.0, 2.0, 3.0), vec3(0.0, 0.0, 2.0));
+
+        let m = Mf32::scale(2.0, 2.0, 2.0).then_translate(vec3(1.0, 0.0, 0.0));
+        let r = m.transform_ray(&ray).unwrap();
+        assert_eq!(r.origin, point3(3.0, 4.0, 6.0));
+        assert_eq!(r.direction, vec3(0.0, 0.0, 4.0));
+
+        let m = Mf32::perspective(10.0);
+        let r = m.transform_ray(&ray).unwrap();
+        assert!(r.origin.approx_eq(&m.transform_point3d(ray.origin).unwrap()));
+        assert!(r.sample(1.0).approx_eq(&m.transform_point3d(ray.sample(1.0)).unwrap()));
+
+        // The origin is behind the projection plane.
+        let ray = default::Ray3D::new(point3(0.0, 0.0, 20.0), vec3(0.0, 0.0, -1.0));
+        assert!(m.transform_ray(&ray).is_none());
+    }
+
+    #[test]
+    pub fn test_transform_plane() {
+        let plane = default::Plane3D::from_point_and_normal(point3(0.0, 0.0, 1.0), vec3(1.0, 1.0, 0.0));
+        let points = [point3(1.0, -1.0, 0.0), point3(0.0, 0.0, 5.0), point3(3.0, 2.0, 1.0)];
+
+        let transforms = [
+            Mf32::translation(1.0, 2.0, 3.0),
+            Mf32::scale(2.0, 0.5, 3.0),
+            Mf32::skew(rad(0.3), rad(-0.2)).then_rotate(0.0, 1.0, 1.0, rad(1.2)),
+            Mf32::perspective(100.0),
+        ];
+        for m in &transforms {
+            let transformed = m.transform_plane(&plane).unwrap();
+            assert!(transformed.normal.length().approx_eq(&1.0));
+            for p in &points {
+                let d = plane.signed_distance_to_point(*p);
+                let p2 = m.transform_point3d(*p).unwrap();
+                let d2 = transformed.signed_distance_to_point(p2);
+                // The sign (and zero-ness) of the distance is preserved.
+                assert_eq!(d == 0.0, d2.abs() < 0.0001);
+                assert_eq!(d > 0.0, d2 > 0.0001);
+            }
+        }
+
+        let singular = Mf32::scale(1.0, 0.0, 1.0);
+        assert!(singular.transform_plane(&plane).is_none());
+    }
+
+    #[test]
+    pub fn test_look_at() {
+        let eye = point3(1.0, 2.0, 3.0);
+        let target = point3(1.0, 2.0, -7.0);
+        let up = vec3(0.0, 1.0, 0.0);
+
+        let rh = Mf32::look_at(eye, target, up, Handedness::RightHanded);
+        assert!(rh.approx_eq(&Mf32::translation(-1.0, -2.0, -3.0)));
+        assert!(rh.transform_point3d(target).unwrap().approx_eq(&point3(0.0, 0.0, -10.0)));
+
+        let lh = Mf32::look_at(eye, target, up, Handedness::LeftHanded);
+        assert!(lh.transform_point3d(eye).unwrap().approx_eq(&point3(0.0, 0.0, 0.0)));
+        assert!(lh.transform_point3d(target).unwrap().approx_eq(&point3(0.0, 0.0, 10.0)));
+        // Looking towards negative z, the world's x axis points to the left.
+        assert!(lh.transform_vector3d(vec3(1.0, 0.0, 0.0)).approx_eq(&vec3(-1.0, 0.0, 0.0)));
+
+        let eye = point3(5.0, 5.0, 5.0);
+        let target = point3(0.0, 0.0, 0.0);
+        let distance = 75.0f32.sqrt();
+        for &(handedness, z) in &[(Handedness::RightHanded, -distance), (Handedness::LeftHanded, distance)] {
+            let m = Mf32::look_at(eye, target, up, handedness);
+            assert!(m.transform_point3d(target).unwrap().approx_eq(&point3(0.0, 0.0, z)));
+            // The up vector points up in view space and the transform is rigid.
+            let v = m.transform_vector3d(up);
+            assert!(v.x.approx_eq(&0.0) && v.y > 0.0);
+            assert!(m.determinant().approx_eq(&1.0));
+        }
+    }
+
+    #[test]
+    pub fn test_perspective_fov() {
+        let (near, far) = (0.5, 100.0);
+        let aspect = 2.0;
+        let fovy = rad(FRAC_PI_2);
+
+        // Matches the classic OpenGL projection.
+        let gl = Mf32::perspective_fov(fovy, aspect, near, far, Handedness::RightHanded, DepthRange::NegativeOneToOne);
+        let expected = Mf32::new(
+            0.5, 0.0, 0.0, 0.0,
+            0.0, 1.0, 0.0, 0.0,
+            0.0, 0.0, (far + near) / (near - far), -1.0,
+            0.0, 0.0, 2.0 * far * near / (near - far), 0.0,
+        );
+        assert!(gl.approx_eq(&expected));
+
+        for &handedness in &[Handedness::RightHanded, Handedness::LeftHanded] {
+            let sign = if handedness == Handedness::RightHanded { -1.0 } else { 1.0 };
+            let point = |x: f32, y: f32, distance: f32| point3(x, y, distance * sign);
+
+            for &(range, low) in &[(DepthRange::ZeroToOne, 0.0), (DepthRange::NegativeOneToOne, -1.0)] {
+                let check = |m: Mf32, near_depth: f32, far_depth: f32| {
+                    let p = m.transform_point3d(point(0.0, 0.0, near)).unwrap();
+                    assert!(p.z.approx_eq(&near_depth), "{:?} {:?} {:?}", handedness, range, p);
+                    let p = m.transform_point3d(point(0.0, 0.0, far)).unwrap();
+                    assert!(p.z.approx_eq_eps(&far_depth, &1e-4), "{:?} {:?} {:?}", handedness, range, p);
+                    // The corners of the viewport.
+                    let p = m.transform_point3d(point(near * aspect, near, near)).unwrap();
+                    assert!(p.x.approx_eq(&1.0) && p.y.approx_eq(&1.0));
+                    let p = m.transform_point3d(point(-far * aspect, -far, far)).unwrap();
+                    assert!(p.x.approx_eq(&-1.0) && p.y.approx_eq(&-1.0));
+                    // Points behind the camera are rejected.
+                    assert!(m.transform_point3d(point(0.0, 0.0, -1.0)).is_none());
+                };
+
+                check(Mf32::perspective_fov(fovy, aspect, near, far, handedness, range), low, 1.0);
+                check(Mf32::perspective_fov_reversed_z(fovy, aspect, near, far, handedness, range), 1.0, low);
+
+                // With an infinite far plane, the far plane is at a finite depth close to the limit.
+                let inf = Mf32::perspective_fov_infinite(fovy, aspect, near, handedness, range);
+                check(inf, low, 1.0 - (1.0 - low) * near / far);
+                let inf = Mf32::perspective_fov_infinite_reversed_z(fovy, aspect, near, handedness, range);
+                check(inf, 1.0, low + (1.0 - low) * near / far);
+            }
+        }
+    }
+
     #[cfg(feature = "mint")]
     #[test]
     pub fn test_mint() {